@@ -0,0 +1,150 @@
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solmap::{
+    Auction, Commitment, Config, FeeSplit, ReferralStats, Reservation, Season, SolmapRecord,
+};
+
+use crate::commands::instructions::find_season_key;
+
+pub fn find_config_key() -> Pubkey {
+    Pubkey::find_program_address(&[b"config"], &solmap::ID).0
+}
+
+pub fn find_solmap_record_key(solmap_number: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"solmap", &solmap_number.to_le_bytes()], &solmap::ID).0
+}
+
+/// Mint of a Solmap minted with `mint_pda`.
+pub fn find_solmap_mint_key(solmap_number: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"solmap_mint", &solmap_number.to_le_bytes()], &solmap::ID).0
+}
+
+pub fn find_reservation_key(start: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"reservation", &start.to_le_bytes()], &solmap::ID).0
+}
+
+/// Reservation holding the pending random draw of `owner`.
+pub fn find_random_draw_key(owner: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"random_draw", owner.as_ref()], &solmap::ID).0
+}
+
+pub fn find_wallet_mints_key(season_id: u16, wallet: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"wallet_mints", &season_id.to_le_bytes(), wallet.as_ref()],
+        &solmap::ID,
+    )
+    .0
+}
+
+pub fn find_commitment_key(owner: Pubkey, hash: [u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[b"commitment", owner.as_ref(), &hash], &solmap::ID).0
+}
+
+pub fn find_fee_split_key() -> Pubkey {
+    Pubkey::find_program_address(&[b"fee_split"], &solmap::ID).0
+}
+
+pub fn find_fee_token_key(mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"fee_token", mint.as_ref()], &solmap::ID).0
+}
+
+pub fn find_referral_stats_key(referrer: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"referral", referrer.as_ref()], &solmap::ID).0
+}
+
+pub fn find_auction_key(solmap_number: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"auction", &solmap_number.to_le_bytes()], &solmap::ID).0
+}
+
+pub fn fetch_config(client: &RpcClient) -> Result<Config> {
+    let account = client.get_account(&find_config_key())?;
+    let config = Config::try_deserialize(&mut account.data.as_slice())?;
+
+    Ok(config)
+}
+
+pub fn fetch_season(client: &RpcClient, season_id: u16) -> Result<Season> {
+    let account = client.get_account(&find_season_key(season_id))?;
+    let season = Season::try_deserialize(&mut account.data.as_slice())?;
+
+    Ok(season)
+}
+
+pub fn fetch_solmap_record(client: &RpcClient, solmap_number: u64) -> Result<SolmapRecord> {
+    let account = client.get_account(&find_solmap_record_key(solmap_number))?;
+    let record = SolmapRecord::try_deserialize(&mut account.data.as_slice())?;
+
+    Ok(record)
+}
+
+/// Reservation of the run starting at `start`, if there is one.
+pub fn fetch_reservation(client: &RpcClient, start: u64) -> Result<Option<Reservation>> {
+    let account = client
+        .get_account_with_commitment(&find_reservation_key(start), client.commitment())?
+        .value;
+
+    match account {
+        Some(account) => Ok(Some(Reservation::try_deserialize(
+            &mut account.data.as_slice(),
+        )?)),
+        None => Ok(None),
+    }
+}
+
+/// Random draw `owner` hasn't finalized yet, if any.
+pub fn fetch_random_draw(client: &RpcClient, owner: Pubkey) -> Result<Option<Reservation>> {
+    let account = client
+        .get_account_with_commitment(&find_random_draw_key(owner), client.commitment())?
+        .value;
+
+    match account {
+        Some(account) => Ok(Some(Reservation::try_deserialize(
+            &mut account.data.as_slice(),
+        )?)),
+        None => Ok(None),
+    }
+}
+
+pub fn fetch_auction(client: &RpcClient, solmap_number: u64) -> Result<Auction> {
+    let account = client.get_account(&find_auction_key(solmap_number))?;
+    let auction = Auction::try_deserialize(&mut account.data.as_slice())?;
+
+    Ok(auction)
+}
+
+pub fn fetch_commitment(client: &RpcClient, owner: Pubkey, hash: [u8; 32]) -> Result<Commitment> {
+    let account = client.get_account(&find_commitment_key(owner, hash))?;
+    let commitment = Commitment::try_deserialize(&mut account.data.as_slice())?;
+
+    Ok(commitment)
+}
+
+/// The fee split, if the admin has set one.
+pub fn fetch_fee_split(client: &RpcClient) -> Result<Option<FeeSplit>> {
+    let account = client
+        .get_account_with_commitment(&find_fee_split_key(), client.commitment())?
+        .value;
+
+    match account {
+        Some(account) => Ok(Some(FeeSplit::try_deserialize(
+            &mut account.data.as_slice(),
+        )?)),
+        None => Ok(None),
+    }
+}
+
+/// Referral stats of a wallet, if it has referred any mint.
+pub fn fetch_referral_stats(client: &RpcClient, referrer: Pubkey) -> Result<Option<ReferralStats>> {
+    let account = client
+        .get_account_with_commitment(&find_referral_stats_key(referrer), client.commitment())?
+        .value;
+
+    match account {
+        Some(account) => Ok(Some(ReferralStats::try_deserialize(
+            &mut account.data.as_slice(),
+        )?)),
+        None => Ok(None),
+    }
+}
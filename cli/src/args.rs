@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use clap::{Parser, Subcommand};
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
 
 #[derive(Parser)]
 #[clap(author, version, about)]
@@ -20,14 +21,195 @@ pub struct Args {
 #[derive(Clone, Subcommand)]
 pub enum Commands {
     TotalMinted {},
-    InitSlotIndex {},
+    InitSlotIndex {
+        /// Slot index page to create.
+        page: u32,
+    },
+    GrowSlotIndex {
+        /// Solmap number the slot index must be able to track.
+        solmap: u64,
+    },
+    SweepFees {},
     CheckMinted {
         solmap: u64,
     },
+    Lookup {
+        solmap: u64,
+    },
+    /// Show how many mints a wallet referred and the rewards it earned.
+    Referrals {
+        /// Referrer wallet, defaults to the keypair.
+        wallet: Option<Pubkey>,
+    },
+    /// Show the community grant fee a mint of the solmap would pay now.
+    Quote {
+        solmap: u64,
+
+        /// Season the solmap number belongs to.
+        #[clap(long, default_value_t = 1)]
+        season: u16,
+    },
     Mint {
         solmap: u64,
 
+        /// Season the solmap number belongs to.
+        #[clap(long, default_value_t = 1)]
+        season: u16,
+
+        /// Mint to the PDA mint derived from the solmap number instead of a new keypair.
+        #[clap(long)]
+        pda: bool,
+
+        /// Pay the grant fee in this accepted SPL token instead of SOL.
+        #[clap(long)]
+        fee_mint: Option<Pubkey>,
+
+        /// Wallet that receives the solmap, defaults to the keypair paying for the mint.
+        #[clap(long)]
+        recipient: Option<Pubkey>,
+
+        /// Wallet that referred the mint, paid a share of the grant fee.
+        #[clap(long)]
+        referrer: Option<Pubkey>,
+
+        #[clap(long)]
+        boost: bool,
+    },
+    /// Mint a random solmap that is not minted yet.
+    MintRandom {
+        /// Season to draw the solmap from.
+        #[clap(long, default_value_t = 1)]
+        season: u16,
+
+        #[clap(long)]
+        boost: bool,
+    },
+    /// Commit to a solmap number, then reveal and mint it once the reveal delay has passed.
+    CommitMint {
+        solmap: u64,
+
+        /// Season the solmap number belongs to.
+        #[clap(long, default_value_t = 1)]
+        season: u16,
+
+        /// Salt printed by an earlier run, to resume its commitment.
+        #[clap(long)]
+        salt: Option<Hash>,
+
+        #[clap(long)]
+        boost: bool,
+    },
+    /// Cancel a commitment that won't be revealed and get its rent back.
+    CancelCommit {
+        solmap: u64,
+
+        /// Salt the commitment was made with.
+        #[clap(long)]
+        salt: Hash,
+
+        #[clap(long)]
+        boost: bool,
+    },
+    /// Hold solmaps back from the public mint for a claimant. Admin only.
+    Hold {
+        /// Solmap numbers and ranges to hold, like 1000,2000-2099.
+        numbers: String,
+
+        /// Wallet that can claim the held solmaps.
+        #[clap(long)]
+        claimant: Pubkey,
+
+        /// Season the solmap numbers belong to.
+        #[clap(long, default_value_t = 1)]
+        season: u16,
+    },
+    /// Mint the solmaps of a run the admin held for the keypair.
+    ClaimReserved {
+        /// First solmap number of the held run.
+        start: u64,
+
+        #[clap(long)]
+        boost: bool,
+    },
+    /// Auction a premium solmap. Admin only.
+    CreateAuction {
+        solmap: u64,
+
+        /// Lowest price in lamports the solmap sells for.
+        #[clap(long)]
+        reserve: u64,
+
+        /// Price in lamports a Dutch auction starts at and declines from.
+        #[clap(long)]
+        start_price: Option<u64>,
+
+        /// Lamports each bid of an English auction must add to the highest bid.
+        #[clap(long)]
+        min_increment: Option<u64>,
+
+        /// Unix timestamp bids open at, defaults to now.
+        #[clap(long)]
+        start: Option<i64>,
+
+        /// Seconds the auction runs for.
+        #[clap(long)]
+        duration: i64,
+
+        /// Season the solmap number belongs to.
+        #[clap(long, default_value_t = 1)]
+        season: u16,
+    },
+    /// Bid on an auctioned solmap. In a Dutch auction this is the most you pay.
+    Bid {
+        solmap: u64,
+
+        /// Bid in lamports.
+        amount: u64,
+
+        #[clap(long)]
+        boost: bool,
+    },
+    /// Settle an auction that is over, and mint the solmap if you won it.
+    SettleAuction {
+        solmap: u64,
+
+        #[clap(long)]
+        boost: bool,
+    },
+    /// Cancel an auction without bids. Admin only.
+    CancelAuction {
+        solmap: u64,
+    },
+    /// Release the solmaps of an expired reservation that were never minted.
+    ReleaseReservation {
+        /// First solmap number of the reservation.
+        start: u64,
+    },
+    /// Reserve a run of adjacent solmaps in one transaction, then mint each of them.
+    BatchMint {
+        /// First solmap number of the run.
+        start: u64,
+
+        /// Number of solmaps to mint, at most 64.
+        count: u8,
+
+        /// Season the solmap numbers belong to.
+        #[clap(long, default_value_t = 1)]
+        season: u16,
+
+        /// Allowlist file written by `allowlist`, to mint in the season's presale.
+        #[clap(long)]
+        allowlist: Option<PathBuf>,
+
         #[clap(long)]
         boost: bool,
     },
+    /// Build the allowlist Merkle tree and proofs from a CSV of wallet,quota lines.
+    Allowlist {
+        csv: PathBuf,
+
+        /// Where to write the root and proofs.
+        #[clap(long, default_value = "allowlist.json")]
+        out: PathBuf,
+    },
 }
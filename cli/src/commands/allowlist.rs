@@ -0,0 +1,150 @@
+use std::{collections::HashSet, fs, path::PathBuf, str::FromStr};
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+use solmap::merkle::{allowlist_leaf, hash_pair};
+
+pub struct AllowlistArgs {
+    pub csv_path: PathBuf,
+    pub out: PathBuf,
+}
+
+/// Allowlist tree written by `solmap allowlist`, with the proof of every wallet.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AllowlistFile {
+    pub root: String,
+    pub entries: IndexMap<String, AllowlistEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AllowlistEntry {
+    pub quota: u32,
+    pub proof: Vec<String>,
+}
+
+impl AllowlistFile {
+    pub fn load(path: &PathBuf) -> Result<Self> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Quota and proof of `wallet`.
+    pub fn entry(&self, wallet: &Pubkey) -> Result<(u32, Vec<[u8; 32]>)> {
+        let entry = self
+            .entries
+            .get(&wallet.to_string())
+            .ok_or_else(|| anyhow!("{} is not in the allowlist", wallet))?;
+        let proof = entry
+            .proof
+            .iter()
+            .map(|node| Ok(Hash::from_str(node)?.to_bytes()))
+            .collect::<Result<_>>()?;
+
+        Ok((entry.quota, proof))
+    }
+}
+
+// Reads `wallet,quota` lines, skipping blank lines and a header, and writes the root and
+// proofs as JSON. The root is then set on the season with `set_season_allowlist`.
+pub fn allowlist(args: AllowlistArgs) -> Result<()> {
+    let csv = fs::read_to_string(&args.csv_path)?;
+
+    let mut entries = Vec::new();
+    let mut wallets = HashSet::new();
+    for (i, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (i == 0 && line.starts_with("wallet")) {
+            continue;
+        }
+
+        let (wallet, quota) = line
+            .split_once(',')
+            .ok_or_else(|| anyhow!("Line {}: expected wallet,quota", i + 1))?;
+        let wallet = Pubkey::from_str(wallet.trim())?;
+        let quota: u32 = quota.trim().parse()?;
+
+        if !wallets.insert(wallet) {
+            bail!("Line {}: {} is listed twice", i + 1, wallet);
+        }
+        entries.push((wallet, quota));
+    }
+    if entries.is_empty() {
+        bail!("Allowlist is empty");
+    }
+
+    let leaves: Vec<[u8; 32]> = entries
+        .iter()
+        .map(|(wallet, quota)| allowlist_leaf(wallet, *quota))
+        .collect();
+    let levels = build_levels(leaves);
+    let root = levels.last().unwrap()[0];
+
+    let entries = entries
+        .iter()
+        .enumerate()
+        .map(|(i, (wallet, quota))| {
+            let proof = proof_for(&levels, i)
+                .iter()
+                .map(|node| Hash::new_from_array(*node).to_string())
+                .collect();
+
+            (
+                wallet.to_string(),
+                AllowlistEntry {
+                    quota: *quota,
+                    proof,
+                },
+            )
+        })
+        .collect();
+
+    let file = AllowlistFile {
+        root: Hash::new_from_array(root).to_string(),
+        entries,
+    };
+    fs::write(&args.out, serde_json::to_string_pretty(&file)?)?;
+
+    println!("Allowlist root: {}", file.root);
+    println!(
+        "Wrote proofs for {} wallets to {}",
+        file.entries.len(),
+        args.out.display()
+    );
+
+    Ok(())
+}
+
+// Each level pairs up the nodes of the one below it. An odd node out is carried up as is.
+fn build_levels(leaves: Vec<[u8; 32]>) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves];
+
+    while levels.last().unwrap().len() > 1 {
+        let level = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => hash_pair(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(level);
+    }
+
+    levels
+}
+
+fn proof_for(levels: &[Vec<[u8; 32]>], mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = Vec::new();
+
+    for level in &levels[..levels.len() - 1] {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        index /= 2;
+    }
+
+    proof
+}
@@ -0,0 +1,165 @@
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail, Result};
+use solana_sdk::signer::Signer;
+use solmap::{AuctionArgs, AuctionKind};
+
+use crate::{
+    accounts::{fetch_auction, fetch_config, fetch_season, find_solmap_mint_key},
+    commands::{
+        batch_mint::send,
+        instructions::{
+            create_bid_ix, create_cancel_auction_ix, create_claim_reserved_ix,
+            create_create_auction_ix, create_settle_auction_ix,
+        },
+    },
+    setup::CliConfig,
+};
+
+pub struct CreateAuctionArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+    pub season: u16,
+    pub reserve_price: u64,
+    pub start_price: Option<u64>,
+    pub min_increment: Option<u64>,
+    pub start_time: Option<i64>,
+    pub duration: i64,
+}
+
+// A start price makes a Dutch auction, a minimum increment an English one.
+pub fn create_auction(args: CreateAuctionArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let admin = config.keypair.pubkey();
+
+    let kind = match (args.start_price, args.min_increment) {
+        (Some(start_price), None) => AuctionKind::Dutch { start_price },
+        (None, Some(min_increment)) => AuctionKind::English { min_increment },
+        _ => bail!(
+            "Pass either --start-price for a Dutch auction or --min-increment for an English one"
+        ),
+    };
+    let start_time = match args.start_time {
+        Some(start_time) => start_time,
+        None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+    };
+    let auction_args = AuctionArgs {
+        kind,
+        reserve_price: args.reserve_price,
+        start_time,
+        end_time: start_time + args.duration,
+    };
+
+    let ix = create_create_auction_ix(admin, admin, args.season, args.solmap_number, &auction_args);
+    let sig = send(&config, ix, false)?;
+
+    println!(
+        "Auctioning solmap number {} until {} with signature {}",
+        args.solmap_number, auction_args.end_time, sig
+    );
+
+    Ok(())
+}
+
+pub struct BidArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+    pub amount: u64,
+    pub boost: bool,
+}
+
+pub fn bid(args: BidArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let auction = fetch_auction(&config.client, args.solmap_number)
+        .map_err(|_| anyhow!("Solmap {} is not auctioned", args.solmap_number))?;
+
+    let ix = create_bid_ix(
+        config.keypair.pubkey(),
+        args.solmap_number,
+        args.amount,
+        auction.bidder,
+    );
+    let sig = send(&config, ix, args.boost)?;
+
+    println!(
+        "Bid on solmap number {} with signature {}",
+        args.solmap_number, sig
+    );
+
+    Ok(())
+}
+
+pub struct SettleAuctionArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+    pub boost: bool,
+}
+
+// Settles an auction that is over, then mints the solmap when the keypair won it.
+pub fn settle_auction(args: SettleAuctionArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let solmap_config = fetch_config(&config.client)?;
+    let payer = config.keypair.pubkey();
+
+    let auction = fetch_auction(&config.client, args.solmap_number)
+        .map_err(|_| anyhow!("Solmap {} is not auctioned", args.solmap_number))?;
+    let winner = auction
+        .bidder
+        .ok_or_else(|| anyhow!("Auction of solmap {} has no bids", args.solmap_number))?;
+
+    let ix = create_settle_auction_ix(payer, solmap_config.treasury, args.solmap_number);
+    let sig = send(&config, ix, args.boost)?;
+    println!(
+        "Settled solmap number {} to {} for {} lamports with signature {}",
+        args.solmap_number, winner, auction.bid, sig
+    );
+
+    if winner != payer {
+        return Ok(());
+    }
+
+    let season = fetch_season(&config.client, auction.season)?;
+    let ix = create_claim_reserved_ix(
+        payer,
+        solmap_config.mcc,
+        &season,
+        args.solmap_number,
+        args.solmap_number,
+    );
+    let sig = send(&config, ix, args.boost)?;
+
+    println!(
+        "Minted solmap number {} to mint {} with signature {}",
+        args.solmap_number,
+        find_solmap_mint_key(args.solmap_number),
+        sig
+    );
+
+    Ok(())
+}
+
+pub struct CancelAuctionArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+}
+
+pub fn cancel_auction(args: CancelAuctionArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+
+    let ix = create_cancel_auction_ix(config.keypair.pubkey(), args.solmap_number);
+    let sig = send(&config, ix, false)?;
+
+    println!(
+        "Cancelled the auction of solmap number {} with signature {}",
+        args.solmap_number, sig
+    );
+
+    Ok(())
+}
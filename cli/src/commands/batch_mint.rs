@@ -0,0 +1,126 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::{
+    accounts::{
+        fetch_config, fetch_reservation, fetch_season, find_reservation_key, find_solmap_mint_key,
+    },
+    commands::{
+        allowlist::AllowlistFile,
+        instructions::{
+            create_finalize_solmap_ix, create_reserve_allowlist_ix, create_reserve_solmaps_ix,
+        },
+    },
+    constants::PRIORITY_FEE_RATE,
+    setup::CliConfig,
+};
+
+pub struct BatchMintArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub start: u64,
+    pub count: u8,
+    pub season: u16,
+    pub allowlist: Option<PathBuf>,
+    pub boost: bool,
+}
+
+// Reserves the whole run in one transaction, then finalizes each NFT in its own. Running
+// it again for the same run picks up the Solmaps that are not finalized yet.
+pub fn batch_mint(args: BatchMintArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let solmap_config = fetch_config(&config.client)?;
+    let owner = config.keypair.pubkey();
+
+    // Only a missing reservation means the run isn't reserved yet, RPC and decoding
+    // errors are reported as they are.
+    let reservation = match fetch_reservation(&config.client, args.start)? {
+        Some(reservation) => {
+            println!("Resuming reservation of solmaps from {}", args.start);
+            reservation
+        }
+        None => {
+            println!(
+                "Reserving {} solmaps from number {}",
+                args.count, args.start
+            );
+            let season = fetch_season(&config.client, args.season)?;
+            let ix = match &args.allowlist {
+                Some(path) => {
+                    let (quota, proof) = AllowlistFile::load(path)?.entry(&owner)?;
+                    create_reserve_allowlist_ix(
+                        owner, &season, args.start, args.count, quota, &proof,
+                    )
+                }
+                None => create_reserve_solmaps_ix(owner, &season, args.start, args.count),
+            };
+            let sig = send(&config, ix, args.boost)?;
+            println!("Reserved with signature {}", sig);
+
+            fetch_reservation(&config.client, args.start)?
+                .ok_or_else(|| anyhow!("Reservation of solmaps from {} not found", args.start))?
+        }
+    };
+
+    if reservation.owner != owner {
+        bail!(
+            "Solmaps from {} are reserved by {}",
+            args.start,
+            reservation.owner
+        );
+    }
+
+    for solmap_number in reservation.start..reservation.start + reservation.count as u64 {
+        if reservation.is_finalized(solmap_number) {
+            continue;
+        }
+
+        let ix = create_finalize_solmap_ix(
+            owner,
+            solmap_config.mcc,
+            reservation.season,
+            find_reservation_key(reservation.start),
+            solmap_number,
+        );
+        let sig = send(&config, ix, args.boost)?;
+
+        println!(
+            "Minted solmap number {} to mint {} with signature {}",
+            solmap_number,
+            find_solmap_mint_key(solmap_number),
+            sig
+        );
+    }
+
+    Ok(())
+}
+
+pub(crate) fn send(config: &CliConfig, ix: Instruction, boost: bool) -> Result<String> {
+    let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(400_000)];
+
+    if boost {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            PRIORITY_FEE_RATE,
+        ));
+    }
+    instructions.push(ix);
+
+    let blockhash = config.client.get_latest_blockhash()?;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&config.keypair.pubkey()),
+        &[&config.keypair],
+        blockhash,
+    );
+    let sig = config
+        .client
+        .send_and_confirm_transaction_with_spinner(&tx)?;
+
+    Ok(sig.to_string())
+}
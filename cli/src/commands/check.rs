@@ -1,9 +1,9 @@
 use std::path::PathBuf;
 
-use crate::setup::CliConfig;
+use crate::{commands::instructions::find_slot_index_page_key, setup::CliConfig};
 use anyhow::Result;
 use bitvec::prelude::*;
-use solana_program::pubkey::Pubkey;
+use solmap::{slot_index_offset, slot_index_page, SlotIndex};
 
 pub struct CheckArgs {
     pub keypair_path: Option<PathBuf>,
@@ -14,22 +14,28 @@ pub struct CheckArgs {
 pub fn check_if_minted(args: CheckArgs) -> Result<()> {
     let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
 
-    let slot_index = Pubkey::find_program_address(&[b"slot_index"], &solmap::ID).0;
+    let page = slot_index_page(args.solmap_number);
+    let slot_index = find_slot_index_page_key(page);
     let slot_index_account = config.client.get_account(&slot_index)?;
 
-    let slot_index_bits = slot_index_account.data.view_bits::<Lsb0>();
+    let slot_index_bits = slot_index_account.data[SlotIndex::BITMAP_OFFSET..].view_bits::<Lsb0>();
+    let held_bits = slot_index_account.data
+        [SlotIndex::HELD_BITMAP_OFFSET..SlotIndex::BITMAP_OFFSET]
+        .view_bits::<Lsb0>();
 
-    let slot_index_bit = slot_index_bits
-        .get(args.solmap_number as usize)
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "Solmap number {} is out of range for the current slot",
-                args.solmap_number
-            )
-        })?;
+    let offset = slot_index_offset(args.solmap_number);
+    let slot_index_bit = slot_index_bits.get(offset).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Solmap number {} is out of range for slot index page {}",
+            args.solmap_number,
+            page
+        )
+    })?;
 
     let msg = format!("Solmap number {} is", args.solmap_number);
-    if !slot_index_bit {
+    if held_bits[offset] {
+        println!("{msg} held for a claimant");
+    } else if !slot_index_bit {
         println!("{msg} not minted");
     } else {
         println!("{msg} minted");
@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use solana_sdk::signer::Signer;
+
+use crate::{
+    accounts::{fetch_config, fetch_reservation, fetch_season, find_solmap_mint_key},
+    commands::{batch_mint::send, instructions::create_claim_reserved_ix},
+    setup::CliConfig,
+};
+
+pub struct ClaimReservedArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub start: u64,
+    pub boost: bool,
+}
+
+// Mints every solmap of a held run that was not claimed yet.
+pub fn claim_reserved(args: ClaimReservedArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let solmap_config = fetch_config(&config.client)?;
+    let owner = config.keypair.pubkey();
+
+    let reservation = match fetch_reservation(&config.client, args.start)? {
+        Some(reservation) if reservation.is_held() && reservation.owner == owner => reservation,
+        _ => bail!("Solmaps from {} are not held for {}", args.start, owner),
+    };
+    let season = fetch_season(&config.client, reservation.season)?;
+
+    for solmap_number in reservation.start..reservation.start + reservation.count as u64 {
+        if reservation.is_finalized(solmap_number) {
+            continue;
+        }
+
+        let ix = create_claim_reserved_ix(
+            owner,
+            solmap_config.mcc,
+            &season,
+            reservation.start,
+            solmap_number,
+        );
+        let sig = send(&config, ix, args.boost)?;
+
+        println!(
+            "Claimed solmap number {} to mint {} with signature {}",
+            solmap_number,
+            find_solmap_mint_key(solmap_number),
+            sig
+        );
+    }
+
+    Ok(())
+}
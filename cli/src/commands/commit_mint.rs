@@ -0,0 +1,121 @@
+use std::{path::PathBuf, thread::sleep, time::Duration};
+
+use anyhow::Result;
+use solana_sdk::{hash::Hash, signature::Keypair, signer::Signer};
+use solmap::commitment_hash;
+
+use crate::{
+    accounts::{
+        fetch_commitment, fetch_config, fetch_season, find_reservation_key, find_solmap_mint_key,
+    },
+    commands::{
+        batch_mint::send,
+        instructions::{
+            create_cancel_commitment_ix, create_commit_solmap_ix, create_finalize_solmap_ix,
+            create_reveal_solmap_ix,
+        },
+    },
+    setup::CliConfig,
+};
+
+pub struct CommitMintArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+    pub season: u16,
+    pub salt: Option<Hash>,
+    pub boost: bool,
+}
+
+// Commits to the number, waits out the reveal delay, then reveals and mints it. Passing
+// the printed salt again resumes a commitment that was not revealed yet.
+pub fn commit_mint(args: CommitMintArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let solmap_config = fetch_config(&config.client)?;
+    let owner = config.keypair.pubkey();
+
+    // The secret of a throwaway keypair is 32 random bytes.
+    let salt = args
+        .salt
+        .unwrap_or_else(|| Hash::new_from_array(Keypair::new().secret().to_bytes()));
+    let hash = commitment_hash(args.solmap_number, &salt.to_bytes(), &owner);
+
+    let commitment = match fetch_commitment(&config.client, owner, hash) {
+        Ok(commitment) => {
+            println!(
+                "Resuming commitment to solmap number {}",
+                args.solmap_number
+            );
+            commitment
+        }
+        Err(_) => {
+            println!(
+                "Committing to solmap number {} with salt {}",
+                args.solmap_number, salt
+            );
+            let sig = send(&config, create_commit_solmap_ix(owner, hash), args.boost)?;
+            println!("Committed with signature {}", sig);
+
+            fetch_commitment(&config.client, owner, hash)?
+        }
+    };
+
+    let reveal_slot = commitment.committed_slot + solmap_config.reveal_delay_slots;
+    while config.client.get_slot()? < reveal_slot {
+        sleep(Duration::from_millis(400));
+    }
+
+    let season = fetch_season(&config.client, args.season)?;
+    let ix = create_reveal_solmap_ix(owner, &season, args.solmap_number, salt.to_bytes());
+    let sig = send(&config, ix, args.boost)?;
+    println!("Revealed with signature {}", sig);
+
+    let ix = create_finalize_solmap_ix(
+        owner,
+        solmap_config.mcc,
+        args.season,
+        find_reservation_key(args.solmap_number),
+        args.solmap_number,
+    );
+    let sig = send(&config, ix, args.boost)?;
+
+    println!(
+        "Minted solmap number {} to mint {} with signature {}",
+        args.solmap_number,
+        find_solmap_mint_key(args.solmap_number),
+        sig
+    );
+
+    Ok(())
+}
+
+pub struct CancelCommitArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+    pub salt: Hash,
+    pub boost: bool,
+}
+
+// Closes a commitment that won't be revealed, for example because its number was minted
+// by someone else, and gets its rent back.
+pub fn cancel_commit(args: CancelCommitArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let owner = config.keypair.pubkey();
+
+    let hash = commitment_hash(args.solmap_number, &args.salt.to_bytes(), &owner);
+    fetch_commitment(&config.client, owner, hash)?;
+
+    println!(
+        "Cancelling commitment to solmap number {}",
+        args.solmap_number
+    );
+    let sig = send(
+        &config,
+        create_cancel_commitment_ix(owner, hash),
+        args.boost,
+    )?;
+    println!("Cancelled with signature {}", sig);
+
+    Ok(())
+}
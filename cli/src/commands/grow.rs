@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::{commands::instructions::create_grow_index_ix, setup::CliConfig};
+
+pub struct GrowArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+}
+
+pub fn grow(args: GrowArgs) -> Result<()> {
+    println!(
+        "Growing the slot index to fit solmap number {}",
+        args.solmap_number
+    );
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+
+    let ix = create_grow_index_ix(
+        config.keypair.pubkey(),
+        config.keypair.pubkey(),
+        args.solmap_number,
+    );
+
+    let blockhash = config.client.get_latest_blockhash()?;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&config.keypair.pubkey()),
+        &[&config.keypair],
+        blockhash,
+    );
+
+    let sig = config
+        .client
+        .send_and_confirm_transaction_with_spinner(&tx)?;
+
+    println!("Successful with signature {}", sig);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use solmap::{slot_index_page, MAX_RESERVATION_SIZE};
+
+use crate::{
+    commands::{batch_mint::send, instructions::create_hold_solmaps_ix},
+    setup::CliConfig,
+};
+
+pub struct HoldArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub numbers: String,
+    pub claimant: Pubkey,
+    pub season: u16,
+}
+
+// Holds every listed number for the claimant, one transaction per run the program accepts.
+pub fn hold(args: HoldArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let admin = config.keypair.pubkey();
+
+    for (start, count) in parse_runs(&args.numbers)? {
+        let ix = create_hold_solmaps_ix(admin, admin, args.season, start, count, args.claimant);
+        let sig = send(&config, ix, false)?;
+
+        println!(
+            "Held {} solmaps from number {} for {} with signature {}",
+            count, start, args.claimant, sig
+        );
+    }
+
+    Ok(())
+}
+
+/// Splits a list like `1000,2000-2099` into runs of up to 64 adjacent numbers tracked by
+/// the same slot index page.
+fn parse_runs(numbers: &str) -> Result<Vec<(u64, u8)>> {
+    let mut runs = vec![];
+
+    for part in numbers.split(',').map(str::trim) {
+        let parse = |n: &str| {
+            n.trim()
+                .parse::<u64>()
+                .map_err(|_| anyhow!("Invalid solmap number {}", n))
+        };
+        let (first, last) = match part.split_once('-') {
+            Some((first, last)) => (parse(first)?, parse(last)?),
+            None => (parse(part)?, parse(part)?),
+        };
+        if first > last {
+            bail!("Invalid solmap range {}", part);
+        }
+
+        let mut start = first;
+        while start <= last {
+            let mut count = 1;
+            while count < MAX_RESERVATION_SIZE
+                && start + (count as u64) <= last
+                && slot_index_page(start + count as u64) == slot_index_page(start)
+            {
+                count += 1;
+            }
+            runs.push((start, count));
+            start += count as u64;
+        }
+    }
+
+    Ok(runs)
+}
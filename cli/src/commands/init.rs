@@ -8,13 +8,14 @@ use crate::{commands::instructions::create_init_index_ix, setup::CliConfig};
 pub struct InitArgs {
     pub keypair_path: Option<PathBuf>,
     pub rpc_url: Option<String>,
+    pub page: u32,
 }
 
 pub fn init(args: InitArgs) -> Result<()> {
-    println!("Adding bytes to the slot index solmap number");
+    println!("Initializing slot index page {}", args.page);
     let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
 
-    let ix = create_init_index_ix(config.keypair.pubkey());
+    let ix = create_init_index_ix(config.keypair.pubkey(), config.keypair.pubkey(), args.page);
 
     let blockhash = config.client.get_latest_blockhash()?;
 
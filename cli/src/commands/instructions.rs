@@ -1,3 +1,4 @@
+use anchor_lang::AnchorSerialize;
 use metaboss_lib::derive::*;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
@@ -5,75 +6,637 @@ use solana_program::{
     system_program, sysvar,
 };
 
-use crate::constants::*;
+use solmap::{
+    commitment_hash, fee_vault_shard, random_draw_fee_vault_shard, slot_index_page, AuctionArgs,
+    FeeSplit, Season,
+};
+
+use crate::{
+    accounts::{
+        find_auction_key, find_commitment_key, find_config_key, find_fee_split_key,
+        find_fee_token_key, find_random_draw_key, find_referral_stats_key, find_reservation_key,
+        find_solmap_mint_key, find_solmap_record_key, find_wallet_mints_key,
+    },
+    constants::*,
+};
+
+pub fn create_init_index_ix(admin: Pubkey, payer: Pubkey, page: u32) -> Instruction {
+    let mut data = INIT_INDEX_DISC.to_vec();
+    data.extend(page.to_le_bytes());
+
+    let index = find_slot_index_page_key(page);
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            AccountMeta::new(index, false),
+            AccountMeta::new_readonly(find_legacy_slot_index_key(), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
 
-pub fn create_init_index_ix(payer: Pubkey) -> Instruction {
-    let data = INIT_INDEX_DISC.to_vec();
+pub fn create_grow_index_ix(admin: Pubkey, payer: Pubkey, solmap_number: u64) -> Instruction {
+    let mut data = GROW_INDEX_DISC.to_vec();
+    data.extend(solmap_number.to_le_bytes());
 
-    let index = Pubkey::find_program_address(&["slot_index".as_ref()], &SOLMAP_PROGRAM_ID).0;
+    let index = find_slot_index_page_key(slot_index_page(solmap_number));
 
     Instruction {
         program_id: SOLMAP_PROGRAM_ID,
         accounts: vec![
+            AccountMeta::new_readonly(admin, true),
             AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(find_config_key(), false),
             AccountMeta::new(index, false),
+            AccountMeta::new_readonly(find_legacy_slot_index_key(), false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data,
     }
 }
 
-pub fn create_mint_solmap_ix(authority: Pubkey, mint: Pubkey, solmap_number: u64) -> Instruction {
+/// Mints a Solmap to a new mint keypair and sends the NFT to `recipient`. With `fee_mint`,
+/// the grant fee is paid in that accepted SPL token into the treasury's associated token
+/// account. With `referrer`, that wallet is paid its referral share of a lamport fee.
+#[allow(clippy::too_many_arguments)]
+pub fn create_mint_solmap_ix(
+    authority: Pubkey,
+    recipient: Pubkey,
+    mint: Pubkey,
+    mcc: Pubkey,
+    season: &Season,
+    solmap_number: u64,
+    fee_mint: Option<Pubkey>,
+    treasury: Pubkey,
+    referrer: Option<Pubkey>,
+) -> Instruction {
     let mut data = MINT_SOLMAP_DISC.to_vec();
     data.extend(solmap_number.to_le_bytes());
 
+    let mut accounts =
+        mint_solmap_accounts(authority, recipient, mint, true, mcc, season, solmap_number);
+    match fee_mint {
+        Some(fee_mint) => accounts.extend([
+            AccountMeta::new_readonly(find_fee_token_key(fee_mint), false),
+            AccountMeta::new(find_associated_token_key(authority, fee_mint), false),
+            AccountMeta::new(find_associated_token_key(treasury, fee_mint), false),
+        ]),
+        // Anchor reads the program id in place of an optional account as none.
+        None => accounts.extend(vec![AccountMeta::new_readonly(SOLMAP_PROGRAM_ID, false); 3]),
+    }
+    match referrer {
+        Some(referrer) => accounts.extend([
+            AccountMeta::new(referrer, false),
+            AccountMeta::new(find_referral_stats_key(referrer), false),
+        ]),
+        None => accounts.extend(vec![AccountMeta::new_readonly(SOLMAP_PROGRAM_ID, false); 2]),
+    }
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+/// Mints a Solmap to the PDA mint derived from its number, so no mint keypair has to sign.
+pub fn create_mint_solmap_pda_ix(
+    authority: Pubkey,
+    recipient: Pubkey,
+    mcc: Pubkey,
+    season: &Season,
+    solmap_number: u64,
+) -> Instruction {
+    let mut data = MINT_SOLMAP_PDA_DISC.to_vec();
+    data.extend(solmap_number.to_le_bytes());
+
+    let mint = find_solmap_mint_key(solmap_number);
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: mint_solmap_accounts(
+            authority,
+            recipient,
+            mint,
+            false,
+            mcc,
+            season,
+            solmap_number,
+        ),
+        data,
+    }
+}
+
+fn mint_solmap_accounts(
+    authority: Pubkey,
+    recipient: Pubkey,
+    mint: Pubkey,
+    mint_is_signer: bool,
+    mcc: Pubkey,
+    season: &Season,
+    solmap_number: u64,
+) -> Vec<AccountMeta> {
+    let slot_index = find_slot_index_page_key(slot_index_page(solmap_number));
+    let fee_vault = find_fee_vault_key(fee_vault_shard(solmap_number));
+
+    let mut accounts = vec![
+        AccountMeta::new(authority, true),
+        AccountMeta::new_readonly(recipient, false),
+        AccountMeta::new_readonly(find_config_key(), false),
+        season_meta(season),
+        AccountMeta::new(slot_index, false),
+        AccountMeta::new(fee_vault, false),
+        AccountMeta::new(find_wallet_mints_key(season.id, recipient), false),
+        AccountMeta::new(mint, mint_is_signer),
+        AccountMeta::new(find_solmap_record_key(solmap_number), false),
+    ];
+    accounts.extend(solmap_nft_accounts(recipient, mint, mcc));
+
+    accounts
+}
+
+/// Reserves `count` adjacent Solmaps from `start` without creating their NFTs.
+pub fn create_reserve_solmaps_ix(
+    owner: Pubkey,
+    season: &Season,
+    start: u64,
+    count: u8,
+) -> Instruction {
+    let mut data = RESERVE_SOLMAPS_DISC.to_vec();
+    data.extend(start.to_le_bytes());
+    data.push(count);
+
+    let slot_index = find_slot_index_page_key(slot_index_page(start));
+    let fee_vault = find_fee_vault_key(fee_vault_shard(start));
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            season_meta(season),
+            AccountMeta::new(slot_index, false),
+            AccountMeta::new(fee_vault, false),
+            AccountMeta::new(find_wallet_mints_key(season.id, owner), false),
+            AccountMeta::new(find_reservation_key(start), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+/// Reserves `count` adjacent Solmaps from `start` in the season's presale.
+pub fn create_reserve_allowlist_ix(
+    owner: Pubkey,
+    season: &Season,
+    start: u64,
+    count: u8,
+    quota: u32,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    let mut data = RESERVE_ALLOWLIST_DISC.to_vec();
+    data.extend(start.to_le_bytes());
+    data.push(count);
+    data.extend(quota.to_le_bytes());
+    data.extend((proof.len() as u32).to_le_bytes());
+    data.extend(proof.iter().flatten());
+
+    let slot_index = find_slot_index_page_key(slot_index_page(start));
+    let fee_vault = find_fee_vault_key(fee_vault_shard(start));
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            season_meta(season),
+            AccountMeta::new(slot_index, false),
+            AccountMeta::new(fee_vault, false),
+            AccountMeta::new(find_wallet_mints_key(season.id, owner), false),
+            AccountMeta::new(find_reservation_key(start), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+/// Commits to a Solmap number without revealing it.
+pub fn create_commit_solmap_ix(owner: Pubkey, hash: [u8; 32]) -> Instruction {
+    let mut data = COMMIT_SOLMAP_DISC.to_vec();
+    data.extend(hash);
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(find_commitment_key(owner, hash), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+/// Closes the commitment `owner` made to `hash`, returning its rent.
+pub fn create_cancel_commitment_ix(owner: Pubkey, hash: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(find_commitment_key(owner, hash), false),
+        ],
+        data: CANCEL_COMMITMENT_DISC.to_vec(),
+    }
+}
+
+/// Reveals a committed Solmap number and reserves it.
+pub fn create_reveal_solmap_ix(
+    owner: Pubkey,
+    season: &Season,
+    solmap_number: u64,
+    salt: [u8; 32],
+) -> Instruction {
+    let mut data = REVEAL_SOLMAP_DISC.to_vec();
+    data.extend(solmap_number.to_le_bytes());
+    data.extend(salt);
+
+    let hash = commitment_hash(solmap_number, &salt, &owner);
+    let slot_index = find_slot_index_page_key(slot_index_page(solmap_number));
+    let fee_vault = find_fee_vault_key(fee_vault_shard(solmap_number));
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            season_meta(season),
+            AccountMeta::new(slot_index, false),
+            AccountMeta::new(fee_vault, false),
+            AccountMeta::new(find_wallet_mints_key(season.id, owner), false),
+            AccountMeta::new(find_commitment_key(owner, hash), false),
+            AccountMeta::new(find_reservation_key(solmap_number), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+/// Draws a random Solmap of the season for `owner` and reserves it in the owner's random
+/// draw reservation. `pages` are the slot index pages the draw can land in.
+pub fn create_mint_random_ix(owner: Pubkey, season: &Season, pages: &[u32]) -> Instruction {
+    let fee_vault = find_fee_vault_key(random_draw_fee_vault_shard(&owner));
+
+    let mut accounts = vec![
+        AccountMeta::new(owner, true),
+        AccountMeta::new_readonly(find_config_key(), false),
+        season_meta(season),
+        AccountMeta::new(fee_vault, false),
+        AccountMeta::new(find_wallet_mints_key(season.id, owner), false),
+        AccountMeta::new(find_random_draw_key(owner), false),
+        AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    accounts.extend(
+        pages
+            .iter()
+            .map(|page| AccountMeta::new(find_slot_index_page_key(*page), false)),
+    );
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts,
+        data: MINT_RANDOM_DISC.to_vec(),
+    }
+}
+
+/// Creates the NFT and inscription of a Solmap held by `reservation` on its PDA mint.
+pub fn create_finalize_solmap_ix(
+    owner: Pubkey,
+    mcc: Pubkey,
+    season_id: u16,
+    reservation: Pubkey,
+    solmap_number: u64,
+) -> Instruction {
+    reserved_solmap_ix(
+        FINALIZE_SOLMAP_DISC,
+        owner,
+        mcc,
+        AccountMeta::new_readonly(find_season_key(season_id), false),
+        reservation,
+        solmap_number,
+    )
+}
+
+/// Mints a Solmap the admin held for `owner`, from the held run starting at `start`.
+pub fn create_claim_reserved_ix(
+    owner: Pubkey,
+    mcc: Pubkey,
+    season: &Season,
+    start: u64,
+    solmap_number: u64,
+) -> Instruction {
+    reserved_solmap_ix(
+        CLAIM_RESERVED_DISC,
+        owner,
+        mcc,
+        season_meta(season),
+        find_reservation_key(start),
+        solmap_number,
+    )
+}
+
+fn reserved_solmap_ix(
+    discriminator: [u8; 8],
+    owner: Pubkey,
+    mcc: Pubkey,
+    season: AccountMeta,
+    reservation: Pubkey,
+    solmap_number: u64,
+) -> Instruction {
+    let mut data = discriminator.to_vec();
+    data.extend(solmap_number.to_le_bytes());
+
+    let mint = find_solmap_mint_key(solmap_number);
+
+    let mut accounts = vec![
+        AccountMeta::new(owner, true),
+        AccountMeta::new_readonly(find_config_key(), false),
+        AccountMeta::new(reservation, false),
+        season,
+        AccountMeta::new(
+            find_slot_index_page_key(slot_index_page(solmap_number)),
+            false,
+        ),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(find_solmap_record_key(solmap_number), false),
+    ];
+    accounts.extend(solmap_nft_accounts(owner, mint, mcc));
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+/// Holds `count` Solmaps from `start` back from the public mint for `claimant`.
+pub fn create_hold_solmaps_ix(
+    admin: Pubkey,
+    payer: Pubkey,
+    season_id: u16,
+    start: u64,
+    count: u8,
+    claimant: Pubkey,
+) -> Instruction {
+    let mut data = HOLD_SOLMAPS_DISC.to_vec();
+    data.extend(start.to_le_bytes());
+    data.push(count);
+    data.extend(claimant.to_bytes());
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            AccountMeta::new_readonly(find_season_key(season_id), false),
+            AccountMeta::new(find_slot_index_page_key(slot_index_page(start)), false),
+            AccountMeta::new(find_reservation_key(start), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+/// Auctions Solmap `solmap_number` of the season, taking it out of the public mint.
+pub fn create_create_auction_ix(
+    admin: Pubkey,
+    payer: Pubkey,
+    season_id: u16,
+    solmap_number: u64,
+    args: &AuctionArgs,
+) -> Instruction {
+    let mut data = CREATE_AUCTION_DISC.to_vec();
+    data.extend(solmap_number.to_le_bytes());
+    args.serialize(&mut data).unwrap();
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            AccountMeta::new_readonly(find_season_key(season_id), false),
+            AccountMeta::new(
+                find_slot_index_page_key(slot_index_page(solmap_number)),
+                false,
+            ),
+            AccountMeta::new(find_auction_key(solmap_number), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+/// Bids `amount` lamports on an auction, refunding `previous_bidder` when outbid.
+pub fn create_bid_ix(
+    bidder: Pubkey,
+    solmap_number: u64,
+    amount: u64,
+    previous_bidder: Option<Pubkey>,
+) -> Instruction {
+    let mut data = BID_DISC.to_vec();
+    data.extend(amount.to_le_bytes());
+
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(bidder, true),
+            AccountMeta::new(find_auction_key(solmap_number), false),
+            // Anchor reads the program id in place of an optional account as none.
+            AccountMeta::new(previous_bidder.unwrap_or(SOLMAP_PROGRAM_ID), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+/// Pays the winning bid to the treasury and holds the Solmap for the winner.
+pub fn create_settle_auction_ix(
+    payer: Pubkey,
+    treasury: Pubkey,
+    solmap_number: u64,
+) -> Instruction {
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            AccountMeta::new(find_auction_key(solmap_number), false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(find_reservation_key(solmap_number), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SETTLE_AUCTION_DISC.to_vec(),
+    }
+}
+
+/// Cancels an auction without bids, releasing its Solmap to the public mint.
+pub fn create_cancel_auction_ix(admin: Pubkey, solmap_number: u64) -> Instruction {
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(find_config_key(), false),
+            AccountMeta::new(find_auction_key(solmap_number), false),
+            AccountMeta::new(
+                find_slot_index_page_key(slot_index_page(solmap_number)),
+                false,
+            ),
+        ],
+        data: CANCEL_AUCTION_DISC.to_vec(),
+    }
+}
+
+/// Releases the Solmaps of an expired reservation that were never finalized.
+pub fn create_release_reservation_ix(owner: Pubkey, season: &Season, start: u64) -> Instruction {
+    Instruction {
+        program_id: SOLMAP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(owner, false),
+            AccountMeta::new(find_reservation_key(start), false),
+            AccountMeta::new(find_slot_index_page_key(slot_index_page(start)), false),
+            season_meta(season),
+        ],
+        data: RELEASE_RESERVATION_DISC.to_vec(),
+    }
+}
+
+/// Accounts shared by every instruction that creates a Solmap NFT, from the token account on.
+fn solmap_nft_accounts(owner: Pubkey, mint: Pubkey, mcc: Pubkey) -> Vec<AccountMeta> {
     let metadata = derive_metadata_pda(&mint);
     let master_edition = derive_edition_pda(&mint);
-    let token = Pubkey::find_program_address(
-        &[authority.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
-        &ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
-    )
-    .0;
+    let token = find_associated_token_key(owner, mint);
 
-    let slot_index = Pubkey::find_program_address(&["slot_index".as_ref()], &SOLMAP_PROGRAM_ID).0;
     let fvca = Pubkey::find_program_address(&["fvca".as_bytes()], &SOLMAP_PROGRAM_ID).0;
+    let collection_metadata = derive_metadata_pda(&mcc);
+    let collection_master_edition = derive_edition_pda(&mcc);
 
     let inscription_summary = find_inscription_summary_key();
     let inscription_v3 = find_inscription_v3_key(mint);
     let inscription_data = find_inscription_data_key(mint);
 
+    vec![
+        AccountMeta::new(token, false),
+        AccountMeta::new(metadata, false),
+        AccountMeta::new(master_edition, false),
+        AccountMeta::new_readonly(fvca, false),
+        AccountMeta::new_readonly(mcc, false),
+        AccountMeta::new(collection_metadata, false),
+        AccountMeta::new_readonly(collection_master_edition, false),
+        AccountMeta::new(inscription_v3, false),
+        AccountMeta::new(inscription_data, false),
+        AccountMeta::new(inscription_summary, false),
+        AccountMeta::new_readonly(INSCRIPTION_PROGRAM_ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+        AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        AccountMeta::new_readonly(ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID, false),
+    ]
+}
+
+/// Sweeps a fee vault to the treasury, or to the recipients of `fee_split` when set.
+pub fn create_sweep_fee_vault_ix(
+    treasury: Pubkey,
+    shard: u8,
+    fee_split: Option<&FeeSplit>,
+) -> Instruction {
+    let mut data = SWEEP_FEE_VAULT_DISC.to_vec();
+    data.push(shard);
+
+    let config = Pubkey::find_program_address(&["config".as_ref()], &SOLMAP_PROGRAM_ID).0;
+    let fee_vault = find_fee_vault_key(shard);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new(fee_vault, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new_readonly(find_fee_split_key(), false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    accounts.extend(
+        fee_split
+            .iter()
+            .flat_map(|fee_split| &fee_split.recipients)
+            .map(|recipient| AccountMeta::new(recipient.address, false)),
+    );
+
     Instruction {
         program_id: SOLMAP_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(authority, true),
-            AccountMeta::new(slot_index, false),
-            AccountMeta::new(TREASURY, false),
-            AccountMeta::new(mint, true),
-            AccountMeta::new(token, false),
-            AccountMeta::new(metadata, false),
-            AccountMeta::new(master_edition, false),
-            AccountMeta::new_readonly(fvca, false),
-            AccountMeta::new(inscription_v3, false),
-            AccountMeta::new(inscription_data, false),
-            AccountMeta::new(inscription_summary, false),
-            AccountMeta::new_readonly(INSCRIPTION_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(sysvar::instructions::ID, false),
-            AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID, false),
-        ],
+        accounts,
         data,
     }
 }
 
-fn find_inscription_summary_key() -> Pubkey {
+pub fn find_associated_token_key(owner: Pubkey, mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn find_slot_index_page_key(page: u32) -> Pubkey {
+    let (key, _) = Pubkey::find_program_address(
+        &["slot_index".as_ref(), &page.to_le_bytes()],
+        &SOLMAP_PROGRAM_ID,
+    );
+    key
+}
+
+/// The single slot index account used before the index was paged.
+pub fn find_legacy_slot_index_key() -> Pubkey {
+    let (key, _) = Pubkey::find_program_address(&["slot_index".as_ref()], &SOLMAP_PROGRAM_ID);
+    key
+}
+
+pub fn find_season_key(season_id: u16) -> Pubkey {
+    let (key, _) = Pubkey::find_program_address(
+        &["season".as_ref(), &season_id.to_le_bytes()],
+        &SOLMAP_PROGRAM_ID,
+    );
+    key
+}
+
+/// The season is only written by mints when its pricing counts them, so it is passed
+/// read-only otherwise and mints of the season don't lock it.
+fn season_meta(season: &Season) -> AccountMeta {
+    let key = find_season_key(season.id);
+    if season.pricing.counts_mints() {
+        AccountMeta::new(key, false)
+    } else {
+        AccountMeta::new_readonly(key, false)
+    }
+}
+
+pub fn find_fee_vault_key(shard: u8) -> Pubkey {
+    let (key, _) =
+        Pubkey::find_program_address(&["fee_vault".as_ref(), &[shard]], &SOLMAP_PROGRAM_ID);
+    key
+}
+
+pub fn find_inscription_summary_key() -> Pubkey {
     let (pubkey, _) =
         Pubkey::find_program_address(&[b"inscription_summary"], &INSCRIPTION_PROGRAM_ID);
     pubkey
 }
 
-fn find_inscription_v3_key(mint: Pubkey) -> Pubkey {
+pub fn find_inscription_v3_key(mint: Pubkey) -> Pubkey {
     let (key, _) = Pubkey::find_program_address(
         &[b"inscription_v3", &mint.to_bytes()],
         &INSCRIPTION_PROGRAM_ID,
@@ -81,7 +644,7 @@ fn find_inscription_v3_key(mint: Pubkey) -> Pubkey {
     key
 }
 
-fn find_inscription_data_key(mint: Pubkey) -> Pubkey {
+pub fn find_inscription_data_key(mint: Pubkey) -> Pubkey {
     let (key, _) = Pubkey::find_program_address(
         &[b"inscription_data", &mint.to_bytes()],
         &INSCRIPTION_PROGRAM_ID,
@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::{accounts::fetch_solmap_record, setup::CliConfig};
+
+pub struct LookupArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+}
+
+pub fn lookup(args: LookupArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+
+    let record = fetch_solmap_record(&config.client, args.solmap_number)
+        .map_err(|_| anyhow::anyhow!("No record found for solmap number {}", args.solmap_number))?;
+
+    println!("Solmap number {}", record.solmap_number);
+    println!("Mint: {}", record.mint);
+    println!("Owner: {}", record.owner);
+    println!("Paid by: {}", record.payer);
+    println!("Minted at slot {} ({})", record.slot, record.timestamp);
+    println!("Inscription: {}", record.inscription);
+
+    Ok(())
+}
@@ -1,19 +1,27 @@
 use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, signature::Keypair, signer::Signer,
+    compute_budget::ComputeBudgetInstruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
     transaction::Transaction,
 };
 
 use crate::{
-    commands::instructions::create_mint_solmap_ix, constants::PRIORITY_FEE_RATE, setup::CliConfig,
+    accounts::{fetch_config, fetch_season, find_solmap_mint_key},
+    commands::instructions::{create_mint_solmap_ix, create_mint_solmap_pda_ix},
+    constants::PRIORITY_FEE_RATE,
+    setup::CliConfig,
 };
 
 pub struct MintArgs {
     pub keypair_path: Option<PathBuf>,
     pub rpc_url: Option<String>,
     pub solmap_number: u64,
+    pub season: u16,
+    pub pda: bool,
+    pub fee_mint: Option<Pubkey>,
+    pub recipient: Option<Pubkey>,
+    pub referrer: Option<Pubkey>,
     pub boost: bool,
 }
 
@@ -21,7 +29,16 @@ pub fn mint(args: MintArgs) -> Result<()> {
     println!("Minting solmap number {}", args.solmap_number);
     let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
 
-    let mint = Keypair::new();
+    let solmap_config = fetch_config(&config.client)?;
+    let season = fetch_season(&config.client, args.season)?;
+    let recipient = args.recipient.unwrap_or(config.keypair.pubkey());
+
+    if args.pda && args.fee_mint.is_some() {
+        bail!("Fees can only be paid in tokens when minting to a new mint keypair");
+    }
+    if args.pda && args.referrer.is_some() {
+        bail!("Referrers can only be rewarded when minting to a new mint keypair");
+    }
 
     let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_limit(400_000);
     let mut instructions = vec![compute_budget_ix];
@@ -32,18 +49,46 @@ pub fn mint(args: MintArgs) -> Result<()> {
         ));
     }
 
-    instructions.push(create_mint_solmap_ix(
-        config.keypair.pubkey(),
-        mint.pubkey(),
-        args.solmap_number,
-    ));
+    // A PDA mint is signed for by the program, otherwise a new mint keypair signs.
+    let mint = if args.pda { None } else { Some(Keypair::new()) };
+
+    let (mint_pubkey, mint_ix) = match &mint {
+        Some(mint) => (
+            mint.pubkey(),
+            create_mint_solmap_ix(
+                config.keypair.pubkey(),
+                recipient,
+                mint.pubkey(),
+                solmap_config.mcc,
+                &season,
+                args.solmap_number,
+                args.fee_mint,
+                solmap_config.treasury,
+                args.referrer,
+            ),
+        ),
+        None => (
+            find_solmap_mint_key(args.solmap_number),
+            create_mint_solmap_pda_ix(
+                config.keypair.pubkey(),
+                recipient,
+                solmap_config.mcc,
+                &season,
+                args.solmap_number,
+            ),
+        ),
+    };
+    instructions.push(mint_ix);
+
+    let mut signers = vec![&config.keypair];
+    signers.extend(&mint);
 
     let blockhash = config.client.get_latest_blockhash()?;
 
     let tx = Transaction::new_signed_with_payer(
         &instructions,
         Some(&config.keypair.pubkey()),
-        &[&config.keypair, &mint],
+        &signers,
         blockhash,
     );
     let sig = config
@@ -51,8 +96,8 @@ pub fn mint(args: MintArgs) -> Result<()> {
         .send_and_confirm_transaction_with_spinner(&tx)?;
 
     println!(
-        "Minted solmap number {} with signature {}",
-        args.solmap_number, sig
+        "Minted solmap number {} to mint {} for {} with signature {}",
+        args.solmap_number, mint_pubkey, recipient, sig
     );
 
     Ok(())
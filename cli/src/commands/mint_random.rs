@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use solana_sdk::signer::Signer;
+use solmap::slot_index_page;
+
+use crate::{
+    accounts::{
+        fetch_config, fetch_random_draw, fetch_season, find_random_draw_key, find_solmap_mint_key,
+    },
+    commands::{
+        batch_mint::send,
+        instructions::{create_finalize_solmap_ix, create_mint_random_ix},
+    },
+    setup::CliConfig,
+};
+
+pub struct MintRandomArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub season: u16,
+    pub boost: bool,
+}
+
+// The program draws the solmap and reserves it in one transaction, then it is minted to
+// its PDA mint in another. A draw that wasn't minted yet is minted before drawing again.
+pub fn mint_random(args: MintRandomArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let solmap_config = fetch_config(&config.client)?;
+    let owner = config.keypair.pubkey();
+
+    let reservation = match fetch_random_draw(&config.client, owner)? {
+        Some(reservation) => {
+            println!("Resuming the draw of solmap number {}", reservation.start);
+            reservation
+        }
+        None => {
+            let season = fetch_season(&config.client, args.season)?;
+
+            // Pass every page up to the one the season's mintable range ends in, in case
+            // the range reaches the next page before the draw lands.
+            let end = season.mintable_end(config.client.get_slot()?);
+            let pages: Vec<u32> =
+                (slot_index_page(season.first_solmap)..=slot_index_page(end)).collect();
+
+            let ix = create_mint_random_ix(owner, &season, &pages);
+            let sig = send(&config, ix, args.boost)?;
+
+            let reservation = fetch_random_draw(&config.client, owner)?
+                .ok_or_else(|| anyhow!("The draw landed without a reservation"))?;
+            println!(
+                "Drew solmap number {} with signature {}",
+                reservation.start, sig
+            );
+            reservation
+        }
+    };
+
+    let solmap_number = reservation.start;
+    let ix = create_finalize_solmap_ix(
+        owner,
+        solmap_config.mcc,
+        reservation.season,
+        find_random_draw_key(owner),
+        solmap_number,
+    );
+    let sig = send(&config, ix, args.boost)?;
+
+    println!(
+        "Minted solmap number {} to mint {} with signature {}",
+        solmap_number,
+        find_solmap_mint_key(solmap_number),
+        sig
+    );
+
+    Ok(())
+}
@@ -1,9 +1,35 @@
+pub mod auction;
+pub use auction::*;
+pub mod allowlist;
+pub use allowlist::*;
+pub mod batch_mint;
+pub use batch_mint::*;
 pub mod check;
 pub use check::*;
+pub mod claim_reserved;
+pub use claim_reserved::*;
+pub mod commit_mint;
+pub use commit_mint::*;
+pub mod grow;
+pub use grow::*;
+pub mod hold;
+pub use hold::*;
 pub mod init;
 pub use init::*;
 pub mod mint;
 pub use mint::*;
+pub mod mint_random;
+pub use mint_random::*;
 pub mod instructions;
+pub mod lookup;
+pub use lookup::*;
+pub mod quote;
+pub use quote::*;
+pub mod referrals;
+pub use referrals::*;
+pub mod release;
+pub use release::*;
+pub mod sweep;
+pub use sweep::*;
 pub mod total_minted;
 pub use total_minted::*;
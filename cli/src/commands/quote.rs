@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use crate::{accounts::fetch_season, setup::CliConfig};
+use anyhow::Result;
+
+pub struct QuoteArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub solmap_number: u64,
+    pub season: u16,
+}
+
+// Prices the mint with the same function the program uses, at the time of the latest block.
+pub fn quote(args: QuoteArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+
+    let season = fetch_season(&config.client, args.season)?;
+
+    let now = config.client.get_block_time(config.client.get_slot()?)?;
+    let fee = season.mint_fee(now);
+
+    println!(
+        "Minting solmap number {} costs {} lamports",
+        args.solmap_number, fee
+    );
+
+    Ok(())
+}
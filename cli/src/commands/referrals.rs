@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+
+use crate::{accounts::fetch_referral_stats, setup::CliConfig};
+
+pub struct ReferralsArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub wallet: Option<Pubkey>,
+}
+
+pub fn referrals(args: ReferralsArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let wallet = args.wallet.unwrap_or(config.keypair.pubkey());
+
+    match fetch_referral_stats(&config.client, wallet)? {
+        Some(stats) => {
+            println!("Referrer: {}", stats.referrer);
+            println!("Referred mints: {}", stats.mints);
+            println!("Earned: {} lamports", stats.earned);
+        }
+        None => println!("{} has not referred any mint", wallet),
+    }
+
+    Ok(())
+}
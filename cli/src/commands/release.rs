@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::{
+    accounts::{fetch_reservation, fetch_season},
+    commands::instructions::create_release_reservation_ix,
+    setup::CliConfig,
+};
+
+pub struct ReleaseArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+    pub start: u64,
+}
+
+pub fn release_reservation(args: ReleaseArgs) -> Result<()> {
+    println!("Releasing reservation of solmaps from {}", args.start);
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let reservation = fetch_reservation(&config.client, args.start)?
+        .ok_or_else(|| anyhow!("No reservation of solmaps from {}", args.start))?;
+
+    let season = fetch_season(&config.client, reservation.season)?;
+
+    let ix = create_release_reservation_ix(reservation.owner, &season, args.start);
+
+    let blockhash = config.client.get_latest_blockhash()?;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&config.keypair.pubkey()),
+        &[&config.keypair],
+        blockhash,
+    );
+
+    let sig = config
+        .client
+        .send_and_confirm_transaction_with_spinner(&tx)?;
+
+    println!("Successful with signature {}", sig);
+
+    Ok(())
+}
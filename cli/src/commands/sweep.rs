@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use solmap::FEE_VAULT_SHARDS;
+
+use crate::{
+    accounts::{fetch_config, fetch_fee_split},
+    commands::instructions::create_sweep_fee_vault_ix,
+    setup::CliConfig,
+};
+
+pub struct SweepFeesArgs {
+    pub keypair_path: Option<PathBuf>,
+    pub rpc_url: Option<String>,
+}
+
+pub fn sweep_fees(args: SweepFeesArgs) -> Result<()> {
+    let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
+    let solmap_config = fetch_config(&config.client)?;
+    let fee_split = fetch_fee_split(&config.client)?;
+
+    match &fee_split {
+        Some(fee_split) => println!(
+            "Sweeping fee vaults to {} fee recipients",
+            fee_split.recipients.len()
+        ),
+        None => println!("Sweeping fee vaults to the treasury"),
+    }
+
+    let instructions: Vec<_> = (0..FEE_VAULT_SHARDS)
+        .map(|shard| create_sweep_fee_vault_ix(solmap_config.treasury, shard, fee_split.as_ref()))
+        .collect();
+
+    let blockhash = config.client.get_latest_blockhash()?;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&config.keypair.pubkey()),
+        &[&config.keypair],
+        blockhash,
+    );
+
+    let sig = config
+        .client
+        .send_and_confirm_transaction_with_spinner(&tx)?;
+
+    println!("Successful with signature {}", sig);
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use std::path::PathBuf;
 
 use crate::setup::CliConfig;
+use anchor_lang::{AccountDeserialize, Discriminator};
 use anyhow::Result;
-use bitvec::prelude::*;
-use solana_program::pubkey::Pubkey;
+use solana_client::{
+    rpc_config::RpcProgramAccountsConfig,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solmap::SlotIndex;
 
 pub struct TotalMintedArgs {
     pub keypair_path: Option<PathBuf>,
@@ -13,13 +17,23 @@ pub struct TotalMintedArgs {
 pub fn total_minted(args: TotalMintedArgs) -> Result<()> {
     let config = CliConfig::new(args.keypair_path, args.rpc_url)?;
 
-    let slot_index = Pubkey::find_program_address(&[b"slot_index"], &solmap::ID).0;
-    let slot_index_account = config.client.get_account(&slot_index)?;
-
-    let slot_index_bits = slot_index_account.data.view_bits::<Lsb0>();
-
-    // Count all the 1s in the slot index solmap
-    let total_minted = slot_index_bits.count_ones();
+    let pages = config.client.get_program_accounts_with_config(
+        &solmap::ID,
+        RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                0,
+                &SlotIndex::DISCRIMINATOR,
+            ))]),
+            ..Default::default()
+        },
+    )?;
+
+    // Each slot index page keeps a count of the solmaps minted in it.
+    let mut total_minted = 0;
+    for (_, page) in pages {
+        let slot_index = SlotIndex::try_deserialize(&mut page.data.as_slice())?;
+        total_minted += slot_index.minted as u64;
+    }
 
     println!("{total_minted} solmaps have been minted");
 
@@ -1,6 +1,5 @@
 use solana_program::{pubkey, pubkey::Pubkey};
 
-pub const TREASURY: Pubkey = pubkey!("72GEqCXZ5GLWnCWon5LBXjsZaoUh8jmarhXoBXnFr6CB");
 pub const SOLMAP_PROGRAM_ID: Pubkey = pubkey!("SoLMAPutKhdpSSGpCCWioKfqqNQhsdaM8EEi41ummJM");
 pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
     pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -11,6 +10,23 @@ pub const SOLMAP_COLLECTION: Pubkey = pubkey!("7GuWX1QnSzhT2Km6UZg1prndyQfkjfBqs
 pub const INSCRIPTION_PROGRAM_ID: Pubkey = pubkey!("inscokhJarcjaEs59QbQ7hYjrKz25LEPRfCbP8EmdUp");
 
 pub const MINT_SOLMAP_DISC: [u8; 8] = [51, 57, 225, 47, 182, 146, 137, 166];
+pub const MINT_SOLMAP_PDA_DISC: [u8; 8] = [207, 71, 223, 65, 154, 12, 138, 209];
+pub const RESERVE_SOLMAPS_DISC: [u8; 8] = [231, 248, 74, 216, 129, 214, 202, 208];
+pub const FINALIZE_SOLMAP_DISC: [u8; 8] = [242, 182, 247, 99, 178, 5, 237, 235];
+pub const RESERVE_ALLOWLIST_DISC: [u8; 8] = [175, 140, 210, 230, 234, 199, 100, 0];
+pub const COMMIT_SOLMAP_DISC: [u8; 8] = [20, 179, 16, 204, 133, 97, 216, 157];
+pub const REVEAL_SOLMAP_DISC: [u8; 8] = [161, 207, 123, 43, 175, 102, 124, 119];
+pub const CANCEL_COMMITMENT_DISC: [u8; 8] = [36, 39, 70, 137, 71, 179, 88, 232];
+pub const MINT_RANDOM_DISC: [u8; 8] = [72, 235, 182, 124, 22, 102, 103, 135];
+pub const HOLD_SOLMAPS_DISC: [u8; 8] = [28, 221, 70, 236, 39, 102, 49, 206];
+pub const CLAIM_RESERVED_DISC: [u8; 8] = [50, 220, 22, 50, 50, 167, 183, 236];
+pub const CREATE_AUCTION_DISC: [u8; 8] = [234, 6, 201, 246, 47, 219, 176, 107];
+pub const BID_DISC: [u8; 8] = [199, 56, 85, 38, 146, 243, 37, 158];
+pub const SETTLE_AUCTION_DISC: [u8; 8] = [246, 196, 183, 98, 222, 139, 46, 133];
+pub const CANCEL_AUCTION_DISC: [u8; 8] = [156, 43, 197, 110, 218, 105, 143, 182];
+pub const RELEASE_RESERVATION_DISC: [u8; 8] = [57, 15, 101, 135, 113, 21, 168, 247];
 pub const INIT_INDEX_DISC: [u8; 8] = [206, 236, 58, 58, 171, 221, 237, 57];
+pub const GROW_INDEX_DISC: [u8; 8] = [222, 32, 78, 153, 148, 86, 108, 168];
+pub const SWEEP_FEE_VAULT_DISC: [u8; 8] = [254, 87, 106, 70, 153, 200, 235, 150];
 
 pub const PRIORITY_FEE_RATE: u64 = 25000;
@@ -4,7 +4,13 @@ use clap::Parser;
 use solmap_cli::{
     args::{Args, Commands},
     commands::{
-        check_if_minted, init, mint, total_minted, CheckArgs, InitArgs, MintArgs, TotalMintedArgs,
+        allowlist, batch_mint, bid, cancel_auction, cancel_commit, check_if_minted, claim_reserved,
+        commit_mint, create_auction, grow, hold, init, lookup, mint, mint_random, quote, referrals,
+        release_reservation, settle_auction, sweep_fees, total_minted, AllowlistArgs,
+        BatchMintArgs, BidArgs, CancelAuctionArgs, CancelCommitArgs, CheckArgs, ClaimReservedArgs,
+        CommitMintArgs, CreateAuctionArgs, GrowArgs, HoldArgs, InitArgs, LookupArgs, MintArgs,
+        MintRandomArgs, QuoteArgs, ReferralsArgs, ReleaseArgs, SettleAuctionArgs, SweepFeesArgs,
+        TotalMintedArgs,
     },
 };
 
@@ -21,7 +27,17 @@ fn main() -> Result<()> {
             keypair_path,
             rpc_url,
         }),
-        Commands::InitSlotIndex {} => init(InitArgs {
+        Commands::InitSlotIndex { page } => init(InitArgs {
+            keypair_path,
+            rpc_url,
+            page,
+        }),
+        Commands::GrowSlotIndex { solmap } => grow(GrowArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+        }),
+        Commands::SweepFees {} => sweep_fees(SweepFeesArgs {
             keypair_path,
             rpc_url,
         }),
@@ -30,11 +46,149 @@ fn main() -> Result<()> {
             rpc_url,
             solmap_number: solmap,
         }),
-        Commands::Mint { solmap, boost } => mint(MintArgs {
+        Commands::Lookup { solmap } => lookup(LookupArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+        }),
+        Commands::Referrals { wallet } => referrals(ReferralsArgs {
+            keypair_path,
+            rpc_url,
+            wallet,
+        }),
+        Commands::Mint {
+            solmap,
+            season,
+            pda,
+            fee_mint,
+            recipient,
+            referrer,
+            boost,
+        } => mint(MintArgs {
             keypair_path,
             rpc_url,
             solmap_number: solmap,
+            season,
+            pda,
+            fee_mint,
+            recipient,
+            referrer,
+            boost,
+        }),
+        Commands::MintRandom { season, boost } => mint_random(MintRandomArgs {
+            keypair_path,
+            rpc_url,
+            season,
+            boost,
+        }),
+        Commands::CommitMint {
+            solmap,
+            season,
+            salt,
+            boost,
+        } => commit_mint(CommitMintArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+            season,
+            salt,
+            boost,
+        }),
+        Commands::CancelCommit {
+            solmap,
+            salt,
+            boost,
+        } => cancel_commit(CancelCommitArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+            salt,
+            boost,
+        }),
+        Commands::Quote { solmap, season } => quote(QuoteArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+            season,
+        }),
+        Commands::Hold {
+            numbers,
+            claimant,
+            season,
+        } => hold(HoldArgs {
+            keypair_path,
+            rpc_url,
+            numbers,
+            claimant,
+            season,
+        }),
+        Commands::ClaimReserved { start, boost } => claim_reserved(ClaimReservedArgs {
+            keypair_path,
+            rpc_url,
+            start,
+            boost,
+        }),
+        Commands::CreateAuction {
+            solmap,
+            reserve,
+            start_price,
+            min_increment,
+            start,
+            duration,
+            season,
+        } => create_auction(CreateAuctionArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+            season,
+            reserve_price: reserve,
+            start_price,
+            min_increment,
+            start_time: start,
+            duration,
+        }),
+        Commands::Bid {
+            solmap,
+            amount,
+            boost,
+        } => bid(BidArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+            amount,
+            boost,
+        }),
+        Commands::SettleAuction { solmap, boost } => settle_auction(SettleAuctionArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+            boost,
+        }),
+        Commands::CancelAuction { solmap } => cancel_auction(CancelAuctionArgs {
+            keypair_path,
+            rpc_url,
+            solmap_number: solmap,
+        }),
+        Commands::ReleaseReservation { start } => release_reservation(ReleaseArgs {
+            keypair_path,
+            rpc_url,
+            start,
+        }),
+        Commands::BatchMint {
+            start,
+            count,
+            season,
+            allowlist,
+            boost,
+        } => batch_mint(BatchMintArgs {
+            keypair_path,
+            rpc_url,
+            start,
+            count,
+            season,
+            allowlist,
             boost,
         }),
+        Commands::Allowlist { csv, out } => allowlist(AllowlistArgs { csv_path: csv, out }),
     }
 }
@@ -13,4 +13,160 @@ pub enum SolmapError {
 
     #[msg("Invalid Solmap NFT")]
     InvalidSolmapNFT,
+
+    #[msg("Public minting is disabled")]
+    PublicMintDisabled,
+
+    #[msg("Signer is not authorized")]
+    Unauthorized,
+
+    #[msg("URI is too long")]
+    UriTooLong,
+
+    #[msg("Season is closed")]
+    SeasonClosed,
+
+    #[msg("Season range is invalid")]
+    InvalidSeasonRange,
+
+    #[msg("Slot index page is already initialized")]
+    SlotIndexPageAlreadyInitialized,
+
+    #[msg("Solmap number is beyond the slot index capacity")]
+    SolmapBeyondIndexCapacity,
+
+    #[msg("Solmap is not minted")]
+    SolmapNotMinted,
+
+    #[msg("Governance members are empty, duplicated or too many")]
+    InvalidGovernanceMembers,
+
+    #[msg("Governance threshold must be between 1 and the number of members")]
+    InvalidGovernanceThreshold,
+
+    #[msg("Signer is not a governance member")]
+    NotGovernanceMember,
+
+    #[msg("Proposed instruction is too large")]
+    ProposalTooLarge,
+
+    #[msg("Proposal was already approved by this member")]
+    ProposalAlreadyApproved,
+
+    #[msg("Proposal was already executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal does not have enough approvals")]
+    ProposalThresholdNotMet,
+
+    #[msg("An account of the proposed instruction is missing")]
+    ProposalAccountMissing,
+
+    #[msg("Timelock cannot be negative")]
+    InvalidTimelock,
+
+    #[msg("New authority cannot be accepted before its timelock expires")]
+    AuthorityTimelockActive,
+
+    #[msg("Reservations hold 1 to 64 Solmaps tracked by the same slot index page")]
+    InvalidReservationRange,
+
+    #[msg("Solmap is not part of the reservation")]
+    SolmapNotReserved,
+
+    #[msg("Solmap is already finalized")]
+    SolmapAlreadyFinalized,
+
+    #[msg("Reservation has expired")]
+    ReservationExpired,
+
+    #[msg("Reservation has not expired yet")]
+    ReservationNotExpired,
+
+    #[msg("Revealed Solmap number and salt don't match the commitment")]
+    InvalidCommitment,
+
+    #[msg("Commitment cannot be revealed yet")]
+    RevealTooEarly,
+
+    #[msg("Solmaps can only be minted through commit and reveal")]
+    CommitRevealRequired,
+
+    #[msg("Wallet and quota are not in the allowlist")]
+    InvalidAllowlistProof,
+
+    #[msg("Allowlist quota exceeded")]
+    AllowlistQuotaExceeded,
+
+    #[msg("Wallet has reached its mint limit for this phase")]
+    WalletMintLimitReached,
+
+    #[msg("Pricing mode parameters are invalid for the season")]
+    InvalidPricing,
+
+    #[msg("Fee split needs 1 to 8 distinct recipients whose shares add up to 10000 bps")]
+    InvalidFeeSplit,
+
+    #[msg("Fee recipients don't match the fee split")]
+    FeeRecipientMismatch,
+
+    #[msg("Token is not accepted for the grant fee")]
+    FeeTokenNotAccepted,
+
+    #[msg("Fee token accounts are missing or don't match the fee token and treasury")]
+    InvalidFeeTokenAccounts,
+
+    #[msg("Referral share cannot exceed 10000 bps")]
+    InvalidReferralShare,
+
+    #[msg("Referrer is missing its stats account or referred its own mint")]
+    InvalidReferrer,
+
+    #[msg("No unminted Solmap is available to draw")]
+    NoSolmapAvailable,
+
+    #[msg("Reservation was not held by the admin for a claimant")]
+    ReservationNotHeld,
+
+    #[msg("Auction prices or times are invalid")]
+    InvalidAuction,
+
+    #[msg("Auction is not taking bids")]
+    AuctionNotOpen,
+
+    #[msg("Bid is below the auction's minimum bid")]
+    BidTooLow,
+
+    #[msg("Previous bidder doesn't match the auction's highest bidder")]
+    BidderMismatch,
+
+    #[msg("Auction has not ended yet")]
+    AuctionNotOver,
+
+    #[msg("Auction ended without bids")]
+    AuctionHasNoBids,
+
+    #[msg("Auction already has bids")]
+    AuctionHasBids,
+
+    #[msg("No recent slot hash to draw from")]
+    SlotHashUnavailable,
+
+    #[msg("Slot index page is not owned by the program")]
+    SlotIndexNotOwned,
+
+    #[msg("Solmap is reserved for a designated claimant")]
+    SolmapReserved,
+
+    #[msg("A slot index page the random draw scans is missing")]
+    RandomDrawPageMissing,
+
+    #[msg("Season range overlaps an existing season")]
+    SeasonOverlap,
+
+    #[msg("Season must be writable when its pricing counts mints")]
+    SeasonNotWritable,
+
+    #[msg("Slot index page has an older layout version")]
+    SlotIndexVersionMismatch,
 }
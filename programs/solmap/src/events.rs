@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AuctionKind;
+
+#[event]
+pub struct SolmapMinted {
+    pub solmap_number: u64,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    /// Community grant fee paid in lamports, or in base units of `fee_mint`.
+    pub fee: u64,
+    /// SPL token the fee was paid in, none for SOL.
+    pub fee_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct SolmapCollectionAdded {
+    pub solmap_number: u64,
+    pub mint: Pubkey,
+    pub collection: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SlotIndexGrown {
+    pub page: u32,
+    /// Number of Solmaps the page can now track.
+    pub capacity: u32,
+    pub payer: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub id: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub id: u64,
+    pub member: Pubkey,
+    /// Approvals from current members, including this one.
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub id: u64,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    /// Unix timestamp from which the pending admin can accept.
+    pub eta: i64,
+}
+
+#[event]
+pub struct AuthorityAccepted {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct SolmapsReserved {
+    pub start: u64,
+    pub count: u8,
+    pub owner: Pubkey,
+    pub slot: u64,
+    /// Slot from which Solmaps that are not finalized can be released.
+    pub expiry_slot: u64,
+    /// Community grant fee paid in lamports for the whole run.
+    pub fee: u64,
+}
+
+#[event]
+pub struct SolmapCommitted {
+    pub owner: Pubkey,
+    pub hash: [u8; 32],
+    pub slot: u64,
+}
+
+#[event]
+pub struct CommitmentCancelled {
+    pub owner: Pubkey,
+    pub hash: [u8; 32],
+    pub slot: u64,
+}
+
+#[event]
+pub struct SolmapsReleased {
+    pub start: u64,
+    pub owner: Pubkey,
+    /// Numbers of the Solmaps that were never finalized and can be minted again.
+    pub released: Vec<u64>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AuctionCreated {
+    pub solmap_number: u64,
+    pub kind: AuctionKind,
+    pub reserve_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct BidPlaced {
+    pub solmap_number: u64,
+    pub bidder: Pubkey,
+    /// Lamports held in escrow for the bid.
+    pub bid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub solmap_number: u64,
+    pub winner: Pubkey,
+    /// Winning bid in lamports, paid to the treasury.
+    pub price: u64,
+}
@@ -1,146 +1,491 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token},
+    token::{self, Mint, Token, TokenAccount, Transfer},
 };
 use bitvec::prelude::*;
-use libreplex_inscriptions::{
-    cpi::accounts::{
-        CreateInscriptionV3, MakeInscriptionImmutableV3, ResizeInscriptionV3, WriteToInscriptionV3,
-    },
-    instructions::{SignerType, WriteToInscriptionInput},
-};
 use mpl_token_metadata::{
     accounts::{MasterEdition, Metadata},
-    instructions::{SetAndVerifyCollection, VerifyCpi, VerifyInstructionArgs},
-    types::{Collection, CreateArgs, Creator, MintArgs, TokenStandard, VerificationArgs},
-};
-use mpl_token_metadata::{
-    instructions::{CreateCpiBuilder, MintCpiBuilder},
-    types::PrintSupply,
+    instructions::{SetAndVerifyCollection, UpdateV1CpiBuilder},
 };
 use solana_program::{
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    instruction::Instruction,
     program::{invoke, invoke_signed},
     system_instruction,
-    sysvar::{instructions::Instructions, SysvarId},
+    sysvar::{instructions::Instructions, slot_hashes::SlotHashes, SysvarId},
     {pubkey, pubkey::Pubkey},
 };
 
-mod error;
+pub mod error;
+mod events;
+pub mod merkle;
+mod nft;
+mod state;
 mod utils;
-use utils::{create_or_allocate_account_raw, resize_or_reallocate_account_raw};
+#[cfg(feature = "cpi")]
+pub mod verify;
+use merkle::{allowlist_leaf, verify_proof};
+use nft::{create_solmap_nft, SolmapNftAccounts};
+use utils::{
+    create_or_allocate_account_raw, parse_solmap_name, resize_or_reallocate_account_raw,
+    verify_solmap_collection, verify_solmap_creator,
+};
 
 use crate::error::SolmapError;
+pub use crate::events::*;
+pub use crate::state::*;
 
 declare_id!("SoLMAPutKhdpSSGpCCWioKfqqNQhsdaM8EEi41ummJM");
 
-const COMMUNITY_GRANT_FEE: u64 = 30_000_000; // 0.03 SOL
-const COMMUNITY_TREASURY: Pubkey = pubkey!("72GEqCXZ5GLWnCWon5LBXjsZaoUh8jmarhXoBXnFr6CB");
-
-const SOLMAP_URI: &str = "https://arweave.net/o8sskjgVX80gn27pHPp_Q9DlCbIP8twSrHMwzLvm2ZI";
 const INSCRIPTION_PROGRAM_ID: Pubkey = pubkey!("inscokhJarcjaEs59QbQ7hYjrKz25LEPRfCbP8EmdUp");
 
-const DEPLOY_AUTH: Pubkey = if cfg!(feature = "anchor-test") {
-    pubkey!("5vHqxWaUMhQjjYkffkba91BwtVMAKyX62fy1mexdgGHU")
-} else {
-    pubkey!("GVjofg6NHMq9jwThd79WWuh9aCa1m82qmuV68YwexzEQ")
-};
-
-const SOLMAP_MCC: Pubkey = if cfg!(feature = "anchor-test") {
-    pubkey!("5sEHGFo7PYFQamTxkH37qayQLtK7QyCDUWUvCskACwMG")
-} else {
-    pubkey!("smccQeqMfKUE3W4a1tQHDxUnx122y3eUoV21JDnQj54")
-};
-
-const SEASON_1_SUPPLY: u64 = 240_042;
-
-const GO_LIVE_DATE: i64 = if cfg!(feature = "anchor-test") {
-    0 // Always live for tests.
-} else {
-    1703108400 // Wed Dec 20 2023 21:40:00 GMT+0000
-};
-
 #[program]
 pub mod solmap {
     use super::*;
 
-    pub fn init_index(ctx: Context<InitIndex>) -> Result<()> {
-        if ctx.accounts.slot_index.data_is_empty() {
-            let bump = ctx.bumps.slot_index;
-            msg!("Initializing slot index account");
-            create_or_allocate_account_raw(
-                crate::ID,
-                &ctx.accounts.slot_index,
-                &ctx.accounts.system_program,
-                &ctx.accounts.payer,
-                10240,
-                &[b"slot_index", &[bump]],
-            )?;
-        } else {
-            let current_len = ctx.accounts.slot_index.data_len();
-            msg!("Resizing slot index account");
-            resize_or_reallocate_account_raw(
-                &ctx.accounts.slot_index,
-                &ctx.accounts.payer,
-                &ctx.accounts.system_program,
-                current_len + 10240,
-            )?;
-        }
+    /// Creates slot index page `page`. Mints recorded for its numbers in the single
+    /// `["slot_index"]` account used before the index was paged are copied into it. The
+    /// legacy account is left as it is and no longer read, so creating the pages is its
+    /// only migration.
+    pub fn init_index(ctx: Context<InitIndex>, page: u32) -> Result<()> {
+        init_index_handler(ctx, page)
+    }
 
-        Ok(())
+    pub fn grow_index(ctx: Context<GrowIndex>, solmap_number: u64) -> Result<()> {
+        grow_index_handler(ctx, solmap_number)
+    }
+
+    pub fn sweep_fee_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepFeeVault<'info>>,
+        shard: u8,
+    ) -> Result<()> {
+        sweep_fee_vault_handler(ctx, shard)
+    }
+
+    pub fn set_fee_token(ctx: Context<SetFeeToken>, args: FeeTokenArgs) -> Result<()> {
+        set_fee_token_handler(ctx, args)
+    }
+
+    pub fn set_fee_split(ctx: Context<SetFeeSplit>, recipients: Vec<FeeRecipient>) -> Result<()> {
+        set_fee_split_handler(ctx, recipients)
     }
 
     pub fn add_mcc(ctx: Context<AddMcc>) -> Result<()> {
         add_mcc_handler(ctx)
     }
 
-    // pub fn mint(ctx: Context<MintSolmap>, solmap: u64) -> Result<()> {
-    //     mint_handler(ctx, solmap)
-    // }
+    /// Checks that `mint` is a genuine Solmap and returns its number. Meant to be called
+    /// through CPI by other programs, see `verify::verify_solmap`.
+    pub fn verify_solmap(ctx: Context<VerifySolmap>) -> Result<u64> {
+        verify_solmap_handler(ctx)
+    }
+
+    /// Returns the community grant fee a mint of `solmap` would pay now.
+    pub fn quote(ctx: Context<Quote>, solmap: u64) -> Result<u64> {
+        quote_handler(ctx, solmap)
+    }
+
+    pub fn backfill_record(
+        ctx: Context<BackfillRecord>,
+        solmap_number: u64,
+        args: BackfillRecordArgs,
+    ) -> Result<()> {
+        backfill_record_handler(ctx, solmap_number, args)
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>, args: ConfigArgs) -> Result<()> {
+        initialize_config_handler(ctx, args)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        update_config_handler(ctx, args)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_admin: Pubkey) -> Result<()> {
+        propose_authority_handler(ctx, new_admin)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority_handler(ctx)
+    }
+
+    pub fn set_collection_update_authority(
+        ctx: Context<SetCollectionUpdateAuthority>,
+        new_update_authority: Pubkey,
+    ) -> Result<()> {
+        set_collection_update_authority_handler(ctx, new_update_authority)
+    }
+
+    pub fn set_public_mint(ctx: Context<SetPublicMint>, enabled: bool) -> Result<()> {
+        msg!("Setting public mint enabled to {}", enabled);
+        ctx.accounts.config.public_mint_enabled = enabled;
+
+        Ok(())
+    }
+
+    pub fn create_governance(ctx: Context<CreateGovernance>, args: GovernanceArgs) -> Result<()> {
+        create_governance_handler(ctx, args)
+    }
+
+    pub fn set_governance(ctx: Context<SetGovernance>, args: GovernanceArgs) -> Result<()> {
+        set_governance_handler(ctx, args)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, args: ProposalArgs) -> Result<()> {
+        create_proposal_handler(ctx, args)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        approve_proposal_handler(ctx)
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        execute_proposal_handler(ctx)
+    }
+
+    pub fn create_season(ctx: Context<CreateSeason>, id: u16, args: SeasonArgs) -> Result<()> {
+        create_season_handler(ctx, id, args)
+    }
+
+    pub fn open_season(ctx: Context<SetSeasonOpen>) -> Result<()> {
+        msg!("Opening season {}", ctx.accounts.season.id);
+        ctx.accounts.season.open = true;
+
+        Ok(())
+    }
+
+    pub fn close_season(ctx: Context<SetSeasonOpen>) -> Result<()> {
+        msg!("Closing season {}", ctx.accounts.season.id);
+        ctx.accounts.season.open = false;
+
+        Ok(())
+    }
+
+    pub fn set_season_allowlist(ctx: Context<SetSeasonOpen>, args: AllowlistArgs) -> Result<()> {
+        msg!("Setting the allowlist of season {}", ctx.accounts.season.id);
+        let season = &mut ctx.accounts.season;
+        season.allowlist_root = args.root;
+        season.allowlist_start_time = args.start_time;
+
+        Ok(())
+    }
+
+    pub fn set_season_pricing(ctx: Context<SetSeasonOpen>, pricing: PricingMode) -> Result<()> {
+        msg!("Setting the pricing of season {}", ctx.accounts.season.id);
+        pricing.validate(&ctx.accounts.season)?;
+        ctx.accounts.season.pricing = pricing;
+
+        Ok(())
+    }
+
+    pub fn set_season_wallet_limits(
+        ctx: Context<SetSeasonOpen>,
+        args: WalletLimitArgs,
+    ) -> Result<()> {
+        msg!(
+            "Setting the wallet limits of season {}",
+            ctx.accounts.season.id
+        );
+        let season = &mut ctx.accounts.season;
+        season.allowlist_wallet_limit = args.allowlist;
+        season.public_wallet_limit = args.public;
+
+        Ok(())
+    }
+
+    pub fn mint(ctx: Context<MintSolmap>, solmap: u64) -> Result<()> {
+        mint_handler(ctx, solmap)
+    }
+
+    pub fn mint_pda(ctx: Context<MintSolmapPda>, solmap: u64) -> Result<()> {
+        mint_pda_handler(ctx, solmap)
+    }
+
+    pub fn reserve_solmap(ctx: Context<ReserveSolmaps>, solmap: u64) -> Result<()> {
+        reserve_solmaps_handler(ctx, solmap, 1)
+    }
+
+    pub fn reserve_solmaps(ctx: Context<ReserveSolmaps>, start: u64, count: u8) -> Result<()> {
+        reserve_solmaps_handler(ctx, start, count)
+    }
+
+    pub fn release_reservation(ctx: Context<ReleaseReservation>) -> Result<()> {
+        release_reservation_handler(ctx)
+    }
+
+    /// Draws a random unminted Solmap of the season and reserves it for the signer, who
+    /// mints it with `finalize_solmap`. The slot index pages of the season are passed as
+    /// writable remaining accounts.
+    pub fn mint_random<'info>(ctx: Context<'_, '_, '_, 'info, MintRandom<'info>>) -> Result<()> {
+        mint_random_handler(ctx)
+    }
+
+    pub fn reserve_allowlist(
+        ctx: Context<ReserveAllowlist>,
+        start: u64,
+        count: u8,
+        quota: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        reserve_allowlist_handler(ctx, start, count, quota, proof)
+    }
+
+    pub fn commit_solmap(ctx: Context<CommitSolmap>, hash: [u8; 32]) -> Result<()> {
+        commit_solmap_handler(ctx, hash)
+    }
+
+    pub fn reveal_solmap(ctx: Context<RevealSolmap>, solmap: u64, salt: [u8; 32]) -> Result<()> {
+        reveal_solmap_handler(ctx, solmap, salt)
+    }
+
+    pub fn cancel_commitment(ctx: Context<CancelCommitment>) -> Result<()> {
+        cancel_commitment_handler(ctx)
+    }
+
+    pub fn finalize_solmap(ctx: Context<FinalizeSolmap>, solmap: u64) -> Result<()> {
+        finalize_solmap_handler(ctx, solmap)
+    }
+
+    pub fn hold_solmaps(
+        ctx: Context<HoldSolmaps>,
+        start: u64,
+        count: u8,
+        claimant: Pubkey,
+    ) -> Result<()> {
+        hold_solmaps_handler(ctx, start, count, claimant)
+    }
+
+    /// Mints a Solmap the admin held for the signer with `hold_solmaps`, once its slot exists.
+    pub fn claim_reserved(ctx: Context<FinalizeSolmap>, solmap: u64) -> Result<()> {
+        claim_reserved_handler(ctx, solmap)
+    }
+
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        solmap: u64,
+        args: AuctionArgs,
+    ) -> Result<()> {
+        create_auction_handler(ctx, solmap, args)
+    }
+
+    /// Bids on an auction. In a Dutch auction `amount` is the most the bidder pays.
+    pub fn bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        bid_handler(ctx, amount)
+    }
+
+    /// Pays the winning bid to the treasury and holds the Solmap for the winner, who
+    /// mints it with `claim_reserved`.
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        settle_auction_handler(ctx)
+    }
+
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        cancel_auction_handler(ctx)
+    }
 }
 
+// The config can only be created by the program's upgrade authority, so the same
+// binary can be deployed to any cluster and configured there.
 #[rustfmt::skip]
 #[derive(Accounts)]
-pub struct InitIndex<'info> {
+pub struct InitializeConfig<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
 
-    /// CHECK: seeds check here and a new account is created.
     #[account(
-        mut,
-        seeds = ["slot_index".as_bytes()], bump,
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = ["config".as_bytes()], bump,
     )]
-    pub slot_index: UncheckedAccount<'info>,
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Solmap>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ SolmapError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
 
     pub system_program: Program<'info, System>,
 }
 
+pub fn initialize_config_handler(ctx: Context<InitializeConfig>, args: ConfigArgs) -> Result<()> {
+    if args.admin_timelock < 0 {
+        return Err(SolmapError::InvalidTimelock.into());
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.admin = args.admin;
+    config.treasury = args.treasury;
+    config.mcc = args.mcc;
+    config.public_mint_enabled = false;
+    config.pending_admin = None;
+    config.pending_admin_eta = 0;
+    config.admin_timelock = args.admin_timelock;
+    config.pending_admin_timelock = None;
+    config.pending_admin_timelock_eta = 0;
+    config.reservation_expiry_slots = args.reservation_expiry_slots;
+    config.reveal_delay_slots = args.reveal_delay_slots;
+    config.commit_reveal_only = false;
+    config.referral_bps = 0;
+    config.seasons_end = 0;
+    config.bump = ctx.bumps.config;
+
+    Ok(())
+}
+
 #[rustfmt::skip]
 #[derive(Accounts)]
-pub struct AddMcc<'info> {
-    #[account(mut, address = DEPLOY_AUTH)]
-    pub authority: Signer<'info>,
+pub struct UpdateConfig<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
 
-    pub mint: Account<'info, Mint>,
+    #[account(mut, seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
 
-    /// CHECK: seeds and ownership checked here, Token Metadata provides the rest of validations
-    #[account(mut,
-        seeds = [
-            Metadata::PREFIX,
-            mpl_token_metadata::ID.as_ref(),
-            mint.key().as_ref(),
-        ],
-        bump,
-        seeds::program = mpl_token_metadata::ID,
+pub fn update_config_handler(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    config.settle_admin_timelock(now);
+
+    if let Some(treasury) = args.treasury {
+        config.treasury = treasury;
+    }
+    if let Some(mcc) = args.mcc {
+        config.mcc = mcc;
+    }
+    if let Some(admin_timelock) = args.admin_timelock {
+        if admin_timelock < 0 {
+            return Err(SolmapError::InvalidTimelock.into());
+        }
+
+        let eta = now + config.admin_timelock;
+        msg!(
+            "Setting the admin timelock to {} from {}",
+            admin_timelock,
+            eta
+        );
+        config.pending_admin_timelock = Some(admin_timelock);
+        config.pending_admin_timelock_eta = eta;
+        config.settle_admin_timelock(now);
+    }
+    if let Some(reservation_expiry_slots) = args.reservation_expiry_slots {
+        config.reservation_expiry_slots = reservation_expiry_slots;
+    }
+    if let Some(reveal_delay_slots) = args.reveal_delay_slots {
+        config.reveal_delay_slots = reveal_delay_slots;
+    }
+    if let Some(commit_reveal_only) = args.commit_reveal_only {
+        config.commit_reveal_only = commit_reveal_only;
+    }
+    if let Some(referral_bps) = args.referral_bps {
+        if referral_bps > 10_000 {
+            return Err(SolmapError::InvalidReferralShare.into());
+        }
+        config.referral_bps = referral_bps;
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+// Proposing again replaces the pending admin and restarts the timelock. Proposing the
+// current admin cancels a pending rotation.
+pub fn propose_authority_handler(ctx: Context<ProposeAuthority>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    config.settle_admin_timelock(now);
+
+    if new_admin == config.admin {
+        msg!("Cancelling pending admin rotation");
+        config.pending_admin = None;
+        config.pending_admin_eta = 0;
+
+        return Ok(());
+    }
+
+    let eta = now + config.admin_timelock;
+
+    msg!("Proposing {} as admin, can accept from {}", new_admin, eta);
+    config.pending_admin = Some(new_admin);
+    config.pending_admin_eta = eta;
+
+    emit!(AuthorityProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+        eta,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        constraint = config.pending_admin == Some(new_admin.key()) @ SolmapError::Unauthorized
     )]
-    pub metadata: UncheckedAccount<'info>,
+    pub new_admin: Signer<'info>,
+
+    #[account(mut, seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+pub fn accept_authority_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if Clock::get()?.unix_timestamp < config.pending_admin_eta {
+        return Err(SolmapError::AuthorityTimelockActive.into());
+    }
+
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.new_admin.key();
+    config.pending_admin = None;
+    config.pending_admin_eta = 0;
+
+    emit!(AuthorityAccepted {
+        previous_admin,
+        admin: config.admin,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct SetCollectionUpdateAuthority<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
 
     /// CHECK: address checked here
-    #[account(address = SOLMAP_MCC)]
+    #[account(address = config.mcc)]
     pub mcc: UncheckedAccount<'info>,
 
-        /// CHECK: seeds and ownership checked here
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
     #[account(
+        mut,
         seeds = [
             Metadata::PREFIX,
             mpl_token_metadata::ID.as_ref(),
@@ -151,7 +496,7 @@ pub struct AddMcc<'info> {
     )]
     pub collection_metadata: UncheckedAccount<'info>,
 
-        /// CHECK: seeds and ownership checked here
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
     #[account(
         seeds = [
             MasterEdition::PREFIX.0,
@@ -164,11 +509,8 @@ pub struct AddMcc<'info> {
     )]
     pub collection_master_edition: UncheckedAccount<'info>,
 
-    /// CHECK: seeds and ownership checked here
-    #[account(
-        seeds = ["fvca".as_bytes()],
-        bump
-    )]
+    /// CHECK: seeds check here
+    #[account(seeds = ["fvca".as_bytes()], bump)]
     pub fvca: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
@@ -180,88 +522,2147 @@ pub struct AddMcc<'info> {
     /// CHECK: address checked here
     #[account(address = mpl_token_metadata::ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
+}
 
-    #[account()]
-    pub token_program: Program<'info, Token>,
+// Hands the update authority of the Solmap collection from the FVCA to another PDA or
+// key. The FVCA then can no longer verify new Solmaps into the collection, so this is
+// meant for migrating the collection once minting is done.
+pub fn set_collection_update_authority_handler(
+    ctx: Context<SetCollectionUpdateAuthority>,
+    new_update_authority: Pubkey,
+) -> Result<()> {
+    msg!(
+        "Setting collection update authority to {}",
+        new_update_authority
+    );
+
+    UpdateV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
+        .authority(&ctx.accounts.fvca)
+        .mint(&ctx.accounts.mcc)
+        .metadata(&ctx.accounts.collection_metadata)
+        .edition(Some(&ctx.accounts.collection_master_edition))
+        .payer(&ctx.accounts.payer)
+        .system_program(&ctx.accounts.system_program)
+        .sysvar_instructions(&ctx.accounts.sysvar_instructions)
+        .new_update_authority(new_update_authority)
+        .invoke_signed(&[&[b"fvca", &[ctx.bumps.fvca]]])?;
 
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    Ok(())
 }
 
-pub fn add_mcc_handler(ctx: Context<AddMcc>) -> Result<()> {
-    let metadata = &ctx.accounts.metadata;
-    let mcc = &ctx.accounts.mcc;
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct SetPublicMint<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
 
-    let md = Metadata::safe_deserialize(&metadata.data.borrow())?;
+    #[account(mut, seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
 
-    // Ensure Solmaps have a FVCA that matches what we expect.
-    if let Some(creators) = md.creators {
-        let fc = creators.first().unwrap();
-        if fc.address != *ctx.accounts.fvca.key || !fc.verified {
-            return Err(SolmapError::InvalidSolmapNFT.into());
-        }
-    } else {
-        return Err(SolmapError::InvalidSolmapNFT.into());
-    }
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(id: u16)]
+pub struct CreateSeason<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
 
-    let mut ix = SetAndVerifyCollection {
-        metadata: metadata.key(),
-        collection_authority: ctx.accounts.fvca.key(),
-        payer: ctx.accounts.authority.key(),
-        update_authority: ctx.accounts.fvca.key(),
-        collection_mint: mcc.key(),
-        collection: ctx.accounts.collection_metadata.key(),
-        collection_master_edition_account: ctx.accounts.collection_master_edition.key(),
-        collection_authority_record: None,
-    }
-    .instruction();
+    #[account(mut)]
+    pub payer: Signer<'info>,
 
-    let account_infos = &[
-        metadata.to_account_info(),
-        ctx.accounts.fvca.to_account_info(),
-        ctx.accounts.authority.to_account_info(),
-        mcc.to_account_info(),
-        ctx.accounts.collection_metadata.to_account_info(),
-        ctx.accounts.collection_master_edition.to_account_info(),
-        ctx.accounts.token_metadata_program.to_account_info(),
-        ctx.accounts.sysvar_instructions.to_account_info(),
-    ];
+    #[account(mut, seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
 
-    // This account doesn't actually need to be writable.
-    let collection_authority_meta = ix.accounts.get_mut(1).unwrap();
-    collection_authority_meta.is_writable = false;
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Season::INIT_SPACE,
+        seeds = ["season".as_bytes(), &id.to_le_bytes()], bump,
+    )]
+    pub season: Account<'info, Season>,
 
-    invoke_signed(&ix, account_infos, &[&[b"fvca", &[ctx.bumps.fvca]]])?;
+    pub system_program: Program<'info, System>,
+}
+
+// Seasons are created in the order of their ranges, so a Solmap number belongs to one
+// season at most and can't be priced or counted twice.
+pub fn create_season_handler(ctx: Context<CreateSeason>, id: u16, args: SeasonArgs) -> Result<()> {
+    if args.first_solmap >= args.end_solmap {
+        return Err(SolmapError::InvalidSeasonRange.into());
+    }
+    if args.first_solmap < ctx.accounts.config.seasons_end {
+        return Err(SolmapError::SeasonOverlap.into());
+    }
+    if args.uri.len() > MAX_URI_LENGTH {
+        return Err(SolmapError::UriTooLong.into());
+    }
+
+    msg!(
+        "Creating season {} for solmaps {}..{}",
+        id,
+        args.first_solmap,
+        args.end_solmap
+    );
+
+    // Seasons start closed so the admin can review them before opening.
+    let season = &mut ctx.accounts.season;
+    season.id = id;
+    season.first_solmap = args.first_solmap;
+    season.end_solmap = args.end_solmap;
+    season.start_time = args.start_time;
+    season.uri = args.uri;
+    season.fee = args.fee;
+    season.pricing = PricingMode::Flat;
+    season.open = false;
+    season.allowlist_root = [0; 32];
+    season.allowlist_start_time = 0;
+    season.allowlist_wallet_limit = 0;
+    season.public_wallet_limit = 0;
+    season.minted = 0;
+    season.bump = ctx.bumps.season;
+
+    ctx.accounts.config.seasons_end = args.end_solmap;
 
     Ok(())
 }
 
 #[rustfmt::skip]
 #[derive(Accounts)]
-pub struct MintSolmap<'info> {
-    #[account(mut, address = DEPLOY_AUTH)]
-    pub minter: Signer<'info>,
+pub struct SetSeasonOpen<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
 
-    /// CHECK: seeds check here
-    #[account(mut, seeds = ["slot_index".as_bytes()], bump)]
-    pub slot_index: UncheckedAccount<'info>,
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct CreateGovernance<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut, seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Governance::INIT_SPACE,
+        seeds = ["governance".as_bytes()], bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Proposes the governance account as admin, like `propose_authority`. Once the timelock
+// has passed, members accept the role with an `accept_authority` proposal. From then on
+// admin instructions are proposed, approved by members and executed by the program
+// signing as governance.
+pub fn create_governance_handler(
+    ctx: Context<CreateGovernance>,
+    args: GovernanceArgs,
+) -> Result<()> {
+    Governance::validate(&args.members, args.threshold)?;
+
+    msg!(
+        "Proposing a {}-of-{} governance as admin",
+        args.threshold,
+        args.members.len()
+    );
+
+    let governance = &mut ctx.accounts.governance;
+    governance.members = args.members;
+    governance.threshold = args.threshold;
+    governance.proposal_count = 0;
+    governance.bump = ctx.bumps.governance;
+
+    // Replaces any rotation the admin proposed before.
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    config.settle_admin_timelock(now);
+
+    let eta = now + config.admin_timelock;
+    config.pending_admin = Some(governance.key());
+    config.pending_admin_eta = eta;
+
+    emit!(AuthorityProposed {
+        admin: config.admin,
+        pending_admin: governance.key(),
+        eta,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct SetGovernance<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, seeds = ["governance".as_bytes()], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+}
+
+pub fn set_governance_handler(ctx: Context<SetGovernance>, args: GovernanceArgs) -> Result<()> {
+    Governance::validate(&args.members, args.threshold)?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.members = args.members;
+    governance.threshold = args.threshold;
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = ["governance".as_bytes()], bump = governance.bump,
+        constraint = governance.is_member(proposer.key) @ SolmapError::NotGovernanceMember,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = ["proposal".as_bytes(), &governance.proposal_count.to_le_bytes()], bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_proposal_handler(ctx: Context<CreateProposal>, args: ProposalArgs) -> Result<()> {
+    if args.accounts.len() > MAX_PROPOSAL_ACCOUNTS || args.data.len() > MAX_PROPOSAL_DATA_LENGTH {
+        return Err(SolmapError::ProposalTooLarge.into());
+    }
+
+    let governance = &mut ctx.accounts.governance;
+    let proposer = ctx.accounts.proposer.key();
+
+    // The proposer approves their own proposal.
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = governance.proposal_count;
+    proposal.proposer = proposer;
+    proposal.accounts = args.accounts;
+    proposal.data = args.data;
+    proposal.approvals = vec![proposer];
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance.proposal_count += 1;
+
+    emit!(ProposalCreated {
+        id: proposal.id,
+        proposer,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = ["governance".as_bytes()], bump = governance.bump,
+        constraint = governance.is_member(member.key) @ SolmapError::NotGovernanceMember,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = ["proposal".as_bytes(), &proposal.id.to_le_bytes()], bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn approve_proposal_handler(ctx: Context<ApproveProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let member = ctx.accounts.member.key();
+
+    if proposal.executed {
+        return Err(SolmapError::ProposalAlreadyExecuted.into());
+    }
+    if proposal.approvals.contains(&member) {
+        return Err(SolmapError::ProposalAlreadyApproved.into());
+    }
+
+    // Approvals from wallets that left the admin set stop counting, so drop them to make
+    // room for current members.
+    let governance = &ctx.accounts.governance;
+    proposal
+        .approvals
+        .retain(|approver| governance.is_member(approver));
+    proposal.approvals.push(member);
+
+    emit!(ProposalApproved {
+        id: proposal.id,
+        member,
+        approvals: proposal.approvals.len() as u8,
+    });
+
+    Ok(())
+}
+
+// Governance is not writable here: the proposed instruction may update it, and writing
+// this copy back on exit would undo that.
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(seeds = ["governance".as_bytes()], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = ["proposal".as_bytes(), &proposal.id.to_le_bytes()], bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub solmap_program: Program<'info, crate::program::Solmap>,
+}
+
+// Runs the proposed instruction against this program with governance as the signing
+// admin. Its accounts are passed as remaining accounts, in any order.
+pub fn execute_proposal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+
+    if proposal.executed {
+        return Err(SolmapError::ProposalAlreadyExecuted.into());
+    }
+    if proposal.approval_count(governance) < governance.threshold as usize {
+        return Err(SolmapError::ProposalThresholdNotMet.into());
+    }
+
+    // Persist the flag before the CPI so the proposal can't be executed again from within.
+    proposal.executed = true;
+    proposal.exit(&crate::ID)?;
+
+    let mut metas = Vec::with_capacity(proposal.accounts.len());
+    let mut account_infos = Vec::with_capacity(proposal.accounts.len() + 1);
+    for account in &proposal.accounts {
+        let info = ctx
+            .remaining_accounts
+            .iter()
+            .find(|info| info.key == &account.pubkey)
+            .ok_or(SolmapError::ProposalAccountMissing)?;
+
+        metas.push(AccountMeta {
+            pubkey: account.pubkey,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(info.clone());
+    }
+    account_infos.push(ctx.accounts.solmap_program.to_account_info());
+
+    msg!("Executing proposal {}", proposal.id);
+    invoke_signed(
+        &Instruction {
+            program_id: crate::ID,
+            accounts: metas,
+            data: proposal.data.clone(),
+        },
+        &account_infos,
+        &[&[b"governance", &[governance.bump]]],
+    )?;
+
+    emit!(ProposalExecuted {
+        id: proposal.id,
+        executor: ctx.accounts.executor.key(),
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(page: u32)]
+pub struct InitIndex<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: seeds check here and a new account is created.
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &page.to_le_bytes()], bump,
+    )]
+    pub slot_index: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, only read if it was created by this program.
+    #[account(seeds = ["slot_index".as_bytes()], bump)]
+    pub legacy_slot_index: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_index_handler(ctx: Context<InitIndex>, page: u32) -> Result<()> {
+    let slot_index = &ctx.accounts.slot_index;
+
+    if !slot_index.data_is_empty() {
+        return Err(SolmapError::SlotIndexPageAlreadyInitialized.into());
+    }
+
+    msg!("Initializing slot index page {}", page);
+    let header = create_slot_index_page(
+        slot_index,
+        &ctx.accounts.legacy_slot_index,
+        &ctx.accounts.system_program,
+        &ctx.accounts.payer,
+        page,
+        ctx.bumps.slot_index,
+        SlotIndex::LEN,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(SlotIndexGrown {
+        page,
+        capacity: header.capacity,
+        payer: ctx.accounts.payer.key(),
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Creates slot index page `page` with at least `len` bytes and writes its header.
+/// Solmaps minted before the index was sharded live in the single legacy account, so the
+/// part of its bitmap this page tracks is carried over and the page made large enough
+/// to hold it.
+fn create_slot_index_page<'info>(
+    slot_index: &AccountInfo<'info>,
+    legacy_slot_index: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    page: u32,
+    bump: u8,
+    len: usize,
+) -> Result<SlotIndex> {
+    let legacy_data = legacy_slot_index.data.borrow();
+    let legacy_bitmap: &[u8] = if legacy_slot_index.owner == &crate::ID {
+        let start = (page as usize * SLOT_INDEX_PAGE_SIZE).min(legacy_data.len());
+        let end = (start + SLOT_INDEX_PAGE_SIZE).min(legacy_data.len());
+        &legacy_data[start..end]
+    } else {
+        &[]
+    };
+    let len = len.max(SlotIndex::BITMAP_OFFSET + legacy_bitmap.len());
+
+    create_or_allocate_account_raw(
+        crate::ID,
+        slot_index,
+        system_program,
+        payer,
+        len,
+        &[b"slot_index", &page.to_le_bytes(), &[bump]],
+    )?;
+
+    let mut data = slot_index.try_borrow_mut_data()?;
+    if !legacy_bitmap.is_empty() {
+        msg!(
+            "Copying {} bytes from the legacy slot index",
+            legacy_bitmap.len()
+        );
+        data[SlotIndex::BITMAP_OFFSET..][..legacy_bitmap.len()].copy_from_slice(legacy_bitmap);
+    }
+
+    let minted = data[SlotIndex::BITMAP_OFFSET..]
+        .view_bits::<Lsb0>()
+        .count_ones();
+
+    let header = SlotIndex {
+        version: SLOT_INDEX_VERSION,
+        page,
+        minted: minted as u32,
+        capacity: SlotIndex::capacity_for(len),
+        last_minted_slot: 0,
+        bump,
+    };
+    header.try_serialize(&mut &mut data[..])?;
+
+    Ok(header)
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(solmap_number: u64)]
+pub struct GrowIndex<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: seeds check here, ownership and layout checked in the handler.
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(solmap_number).to_le_bytes()], bump,
+    )]
+    pub slot_index: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, only read if it was created by this program.
+    #[account(seeds = ["slot_index".as_bytes()], bump)]
+    pub legacy_slot_index: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Creates or grows the slot index page that tracks `solmap_number` until its bitmap
+// can hold it. An account can only grow by MAX_PERMITTED_DATA_INCREASE bytes per
+// instruction, so a request past that is met partially and has to be repeated. A new
+// page carries over the legacy index like `init_index` does.
+pub fn grow_index_handler(ctx: Context<GrowIndex>, solmap_number: u64) -> Result<()> {
+    let slot_index = &ctx.accounts.slot_index;
+    let page = slot_index_page(solmap_number);
+    let target_len = SlotIndex::len_for(solmap_number);
+    let clock = Clock::get()?;
+
+    if slot_index.data_is_empty() {
+        let len = target_len.min(MAX_PERMITTED_DATA_INCREASE);
+
+        msg!("Initializing slot index page {} with {} bytes", page, len);
+        let header = create_slot_index_page(
+            slot_index,
+            &ctx.accounts.legacy_slot_index,
+            &ctx.accounts.system_program,
+            &ctx.accounts.payer,
+            page,
+            ctx.bumps.slot_index,
+            len,
+        )?;
+
+        emit!(SlotIndexGrown {
+            page,
+            capacity: header.capacity,
+            payer: ctx.accounts.payer.key(),
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    let mut header = SlotIndex::load(slot_index)?;
+    if header.capacity as usize > slot_index_offset(solmap_number) {
+        msg!(
+            "Slot index page {} already fits solmap #{}",
+            page,
+            solmap_number
+        );
+        return Ok(());
+    }
+
+    let len = target_len.min(slot_index.data_len() + MAX_PERMITTED_DATA_INCREASE);
+
+    msg!("Resizing slot index page {} to {} bytes", page, len);
+    resize_or_reallocate_account_raw(
+        slot_index,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        len,
+    )?;
+
+    header.capacity = SlotIndex::capacity_for(len);
+    header.try_serialize(&mut &mut slot_index.try_borrow_mut_data()?[..])?;
+
+    emit!(SlotIndexGrown {
+        page,
+        capacity: header.capacity,
+        payer: ctx.accounts.payer.key(),
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(shard: u8)]
+pub struct SweepFeeVault<'info> {
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, seeds = ["fee_vault".as_bytes(), &[shard]], bump)]
+    pub fee_vault: SystemAccount<'info>,
 
     /// CHECK: Address check here
-    #[account(mut, address = COMMUNITY_TREASURY)]
+    #[account(mut, address = config.treasury)]
     pub treasury: UncheckedAccount<'info>,
 
+    /// CHECK: seeds check here, read as the fee split in the handler once it is set
+    #[account(seeds = ["fee_split".as_bytes()], bump)]
+    pub fee_split: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// With a fee split, the vault is shared between its recipients, passed as remaining
+// accounts in the order of the split. Otherwise it all goes to the treasury. The split
+// account is always required so a sweep can't leave it out to skip the split.
+pub fn sweep_fee_vault_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SweepFeeVault<'info>>,
+    shard: u8,
+) -> Result<()> {
+    let fee_vault = &ctx.accounts.fee_vault;
+    let amount = fee_vault.lamports();
+
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let fee_split_info = &ctx.accounts.fee_split;
+    let fee_split = if fee_split_info.owner == &crate::ID && !fee_split_info.data_is_empty() {
+        Some(FeeSplit::try_deserialize(
+            &mut &fee_split_info.try_borrow_data()?[..],
+        )?)
+    } else {
+        None
+    };
+
+    let (recipients, shares) = match &fee_split {
+        Some(fee_split) => {
+            let recipients = ctx.remaining_accounts;
+            if recipients.len() != fee_split.recipients.len()
+                || recipients
+                    .iter()
+                    .zip(&fee_split.recipients)
+                    .any(|(account, recipient)| account.key() != recipient.address)
+            {
+                return Err(SolmapError::FeeRecipientMismatch.into());
+            }
+
+            (recipients.to_vec(), fee_split.shares(amount))
+        }
+        None => (vec![ctx.accounts.treasury.to_account_info()], vec![amount]),
+    };
+
+    msg!("Sweeping {} lamports from fee vault {}", amount, shard);
+    for (recipient, share) in recipients.iter().zip(shares) {
+        if share == 0 {
+            continue;
+        }
+
+        invoke_signed(
+            &system_instruction::transfer(fee_vault.key, recipient.key, share),
+            &[fee_vault.to_account_info(), recipient.clone()],
+            &[&[b"fee_vault", &[shard], &[ctx.bumps.fee_vault]]],
+        )?;
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct SetFeeToken<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + FeeToken::INIT_SPACE,
+        seeds = ["fee_token".as_bytes(), mint.key().as_ref()], bump,
+    )]
+    pub fee_token: Account<'info, FeeToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Accepts `mint` for grant fees, or changes its amount. Any number of tokens can be
+// accepted at once, each with its own account.
+pub fn set_fee_token_handler(ctx: Context<SetFeeToken>, args: FeeTokenArgs) -> Result<()> {
+    let fee_token = &mut ctx.accounts.fee_token;
+    fee_token.mint = ctx.accounts.mint.key();
+    fee_token.amount = args.amount;
+    fee_token.enabled = args.enabled;
+    fee_token.bump = ctx.bumps.fee_token;
+
+    msg!(
+        "Fee token {} {}",
+        fee_token.mint,
+        if args.enabled { "enabled" } else { "disabled" }
+    );
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct SetFeeSplit<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + FeeSplit::INIT_SPACE,
+        seeds = ["fee_split".as_bytes()], bump,
+    )]
+    pub fee_split: Account<'info, FeeSplit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_fee_split_handler(
+    ctx: Context<SetFeeSplit>,
+    recipients: Vec<FeeRecipient>,
+) -> Result<()> {
+    FeeSplit::validate(&recipients)?;
+
+    let fee_split = &mut ctx.accounts.fee_split;
+    fee_split.recipients = recipients;
+    fee_split.bump = ctx.bumps.fee_split;
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct AddMcc<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: seeds and ownership checked here, Token Metadata provides the rest of validations
+    #[account(mut,
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: address checked here
+    #[account(address = config.mcc)]
+    pub mcc: UncheckedAccount<'info>,
+
+        /// CHECK: seeds and ownership checked here
+    #[account(
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mcc.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+        /// CHECK: seeds and ownership checked here
+    #[account(
+        seeds = [
+            MasterEdition::PREFIX.0,
+            mpl_token_metadata::ID.as_ref(),
+            mcc.key().as_ref(),
+            MasterEdition::PREFIX.1,
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: seeds and ownership checked here
+    #[account(
+        seeds = ["fvca".as_bytes()],
+        bump
+    )]
+    pub fvca: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: address checked here
+    #[account(address = Instructions::id())]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: address checked here
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn add_mcc_handler(ctx: Context<AddMcc>) -> Result<()> {
+    let metadata = &ctx.accounts.metadata;
+    let mcc = &ctx.accounts.mcc;
+
+    let md = Metadata::safe_deserialize(&metadata.data.borrow())?;
+
+    // Ensure Solmaps have a FVCA that matches what we expect.
+    verify_solmap_creator(&md, ctx.accounts.fvca.key)?;
+
+    let solmap_number = parse_solmap_name(&md.name).ok_or(SolmapError::InvalidSolmapNFT)?;
+
+    let mut ix = SetAndVerifyCollection {
+        metadata: metadata.key(),
+        collection_authority: ctx.accounts.fvca.key(),
+        payer: ctx.accounts.payer.key(),
+        update_authority: ctx.accounts.fvca.key(),
+        collection_mint: mcc.key(),
+        collection: ctx.accounts.collection_metadata.key(),
+        collection_master_edition_account: ctx.accounts.collection_master_edition.key(),
+        collection_authority_record: None,
+    }
+    .instruction();
+
+    let account_infos = &[
+        metadata.to_account_info(),
+        ctx.accounts.fvca.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        mcc.to_account_info(),
+        ctx.accounts.collection_metadata.to_account_info(),
+        ctx.accounts.collection_master_edition.to_account_info(),
+        ctx.accounts.token_metadata_program.to_account_info(),
+        ctx.accounts.sysvar_instructions.to_account_info(),
+    ];
+
+    // This account doesn't actually need to be writable.
+    let collection_authority_meta = ix.accounts.get_mut(1).unwrap();
+    collection_authority_meta.is_writable = false;
+
+    invoke_signed(&ix, account_infos, &[&[b"fvca", &[ctx.bumps.fvca]]])?;
+
+    let clock = Clock::get()?;
+    emit!(SolmapCollectionAdded {
+        solmap_number,
+        mint: ctx.accounts.mint.key(),
+        collection: mcc.key(),
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct VerifySolmap<'info> {
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: seeds and ownership checked here, deserialized in the handler
+    #[account(
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+        owner = mpl_token_metadata::ID,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: seeds checked here
+    #[account(seeds = ["fvca".as_bytes()], bump)]
+    pub fvca: UncheckedAccount<'info>,
+}
+
+pub fn verify_solmap_handler(ctx: Context<VerifySolmap>) -> Result<u64> {
+    let md = Metadata::safe_deserialize(&ctx.accounts.metadata.data.borrow())?;
+
+    verify_solmap_creator(&md, ctx.accounts.fvca.key)?;
+    verify_solmap_collection(&md, &ctx.accounts.config.mcc)?;
+
+    let solmap_number = parse_solmap_name(&md.name).ok_or(SolmapError::InvalidSolmapNFT)?;
+    msg!("Verified Solmap #{}", solmap_number);
+
+    // Anchor hands the number back to the caller with `set_return_data`.
+    Ok(solmap_number)
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+}
+
+// Like `verify_solmap`, meant to be simulated. Fees grow with the Solmaps sold in the
+// season, so every number of the season costs the same at a given time.
+pub fn quote_handler(ctx: Context<Quote>, solmap_number: u64) -> Result<u64> {
+    let clock = Clock::get()?;
+    let fee = ctx.accounts.season.mint_fee(clock.unix_timestamp);
+    msg!("Solmap #{} costs {} lamports", solmap_number, fee);
+
+    Ok(fee)
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(solmap_number: u64)]
+pub struct BackfillRecord<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = ["slot_index".as_bytes(), &slot_index_page(solmap_number).to_le_bytes()],
+        bump = slot_index.bump,
+        constraint = slot_index.version == SLOT_INDEX_VERSION @ SolmapError::SlotIndexVersionMismatch,
+    )]
+    pub slot_index: Account<'info, SlotIndex>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SolmapRecord::INIT_SPACE,
+        seeds = ["solmap".as_bytes(), &solmap_number.to_le_bytes()], bump,
+    )]
+    pub solmap_record: Box<Account<'info, SolmapRecord>>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: seeds check here, contents checked in the handler
+    #[account(
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here
+    #[account(
+        seeds = ["fvca".as_bytes()],
+        bump
+    )]
+    pub fvca: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, ownership checked in the handler
+    #[account(
+        seeds = ["inscription_v3".as_bytes(), mint.key().as_ref()],
+        bump,
+        seeds::program = INSCRIPTION_PROGRAM_ID,
+    )]
+    pub inscription_v3: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Records Solmaps minted before records were written on mint. The NFT must be a
+// genuine Solmap for `solmap_number` and the number must be marked in the slot index.
+pub fn backfill_record_handler(
+    ctx: Context<BackfillRecord>,
+    solmap_number: u64,
+    args: BackfillRecordArgs,
+) -> Result<()> {
+    let md = Metadata::safe_deserialize(&ctx.accounts.metadata.data.borrow())?;
+
+    verify_solmap_creator(&md, ctx.accounts.fvca.key)?;
+    if parse_solmap_name(&md.name) != Some(solmap_number) {
+        return Err(SolmapError::InvalidSolmapNFT.into());
+    }
+    if ctx.accounts.inscription_v3.owner != &INSCRIPTION_PROGRAM_ID {
+        return Err(SolmapError::InvalidSolmapNFT.into());
+    }
+
+    let slot_index_info = ctx.accounts.slot_index.to_account_info();
+    if !ctx
+        .accounts
+        .slot_index
+        .is_minted(&slot_index_info, solmap_number)?
+    {
+        return Err(SolmapError::SolmapNotMinted.into());
+    }
+
+    msg!("Backfilling record for Solmap #{}", solmap_number);
+    let record = &mut ctx.accounts.solmap_record;
+    record.solmap_number = solmap_number;
+    record.mint = ctx.accounts.mint.key();
+    record.owner = args.owner;
+    record.payer = args.payer;
+    record.slot = args.slot;
+    record.timestamp = args.timestamp;
+    record.inscription = ctx.accounts.inscription_v3.key();
+    record.bump = ctx.bumps.solmap_record;
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(solmap_number: u64)]
+pub struct MintSolmap<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    // Receives the NFT, so a custodian or relayer can pay for a Solmap sent to another
+    // wallet. Pass the minter to mint to yourself.
+    /// CHECK: any wallet
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    /// CHECK: seeds check here, loaded in the handler so a page that doesn't exist yet
+    /// is reported as SolmapBeyondIndexCapacity.
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(solmap_number).to_le_bytes()],
+        bump,
+    )]
+    pub slot_index: UncheckedAccount<'info>,
+
+    // Fees are spread across several vaults so parallel mints don't all lock the treasury.
+    #[account(mut, seeds = ["fee_vault".as_bytes(), &[fee_vault_shard(solmap_number)]], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = minter,
+        space = 8 + WalletMints::INIT_SPACE,
+        seeds = ["wallet_mints".as_bytes(), &season.id.to_le_bytes(), recipient.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mints: Account<'info, WalletMints>,
+
+    #[account(
+        init,
+        payer = minter,
+        mint::decimals = 0,
+        mint::freeze_authority = minter,
+        mint::authority = minter
+    )]    
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: seeds check here, created in the handler once the Solmap is claimed
+    #[account(
+        mut,
+        seeds = ["solmap".as_bytes(), &solmap_number.to_le_bytes()], bump,
+    )]
+    pub solmap_record: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(mut,
+        seeds = [
+            recipient.key().as_ref(),
+            token_program.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = associated_token_program.key(),
+    )]
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(mut, 
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(mut, 
+        seeds = [
+            MasterEdition::PREFIX.0,
+            mpl_token_metadata::ID.as_ref(),
+            mint.key().as_ref(),
+            MasterEdition::PREFIX.1,
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    // Used to tie all Solmap NFTs together in a collection.
+    /// CHECK: Address checked here
+    #[account( 
+        seeds = ["fvca".as_bytes()],
+        bump
+    )]
+    pub fvca: UncheckedAccount<'info>,
+
+        /// CHECK: seeds check here
+    #[account(address = config.mcc)]
+    pub mcc: UncheckedAccount<'info>,
+
+            /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(
+        mut,
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mcc.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+        /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(
+        seeds = [
+            MasterEdition::PREFIX.0,
+            mpl_token_metadata::ID.as_ref(),
+            mcc.key().as_ref(),
+            MasterEdition::PREFIX.1,
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
+    /// CHECK: Validated by inscriptions program
+    #[account(mut)]
+    pub inscription_v3: UncheckedAccount<'info>,
+    
+    /// CHECK: Validated by inscriptions program
+    #[account(mut)]
+    pub inscription_data: UncheckedAccount<'info>,
+    
+    /// CHECK: seed check here and validation in inscription program
+    #[account(mut, 
+        seeds = ["inscription_summary".as_bytes()],
+        bump,
+        seeds::program = INSCRIPTION_PROGRAM_ID,
+    )]
+    pub inscription_summary: UncheckedAccount<'info>,
+
+    /// CHECK: address checked here
+    /// CHECK: address checked here
+    #[account(address = INSCRIPTION_PROGRAM_ID)]
+    pub inscriptions_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: address contraints check here
+    #[account(address = Instructions::id())]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: address checked here
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // The last three are only passed to pay the fee in an accepted SPL token.
+    #[account(seeds = ["fee_token".as_bytes(), fee_token.mint.as_ref()], bump = fee_token.bump)]
+    pub fee_token: Option<Account<'info, FeeToken>>,
+
+    #[account(mut)]
+    pub minter_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Both are passed when another wallet referred the mint, to pay it a share of the fee.
+    #[account(mut)]
+    pub referrer: Option<SystemAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = minter,
+        space = 8 + ReferralStats::INIT_SPACE,
+        seeds = [
+            "referral".as_bytes(),
+            referrer.as_ref().map(|referrer| referrer.key()).unwrap_or_default().as_ref(),
+        ],
+        bump,
+    )]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+}
+
+impl<'info> MintSolmap<'info> {
+    fn nft_accounts(&self) -> SolmapNftAccounts<'_, 'info> {
+        SolmapNftAccounts {
+            minter: self.minter.as_ref(),
+            owner: self.recipient.as_ref(),
+            mint: self.mint.as_ref(),
+            token_account: self.token_account.as_ref(),
+            metadata: self.metadata.as_ref(),
+            master_edition: self.master_edition.as_ref(),
+            fvca: self.fvca.as_ref(),
+            mcc: self.mcc.as_ref(),
+            collection_metadata: self.collection_metadata.as_ref(),
+            collection_master_edition: self.collection_master_edition.as_ref(),
+            inscription_v3: self.inscription_v3.as_ref(),
+            inscription_data: self.inscription_data.as_ref(),
+            inscription_summary: self.inscription_summary.as_ref(),
+            inscriptions_program: self.inscriptions_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+            sysvar_instructions: self.sysvar_instructions.as_ref(),
+            token_metadata_program: self.token_metadata_program.as_ref(),
+            token_program: self.token_program.as_ref(),
+            associated_token_program: self.associated_token_program.as_ref(),
+        }
+    }
+
+    /// Pays the referrer its share of the lamport grant fee and counts the referral.
+    /// Mints paying in an SPL token are counted without a reward. Returns the reward.
+    fn pay_referral(&mut self, fee: u64, bump: u8) -> Result<u64> {
+        let (referrer, stats) = match (&self.referrer, &mut self.referral_stats) {
+            (None, None) => return Ok(0),
+            (Some(referrer), Some(stats))
+                if referrer.key() != self.minter.key()
+                    && referrer.key() != self.recipient.key() =>
+            {
+                (referrer, stats)
+            }
+            _ => return Err(SolmapError::InvalidReferrer.into()),
+        };
+
+        let reward = match self.fee_token {
+            Some(_) => 0,
+            None => (fee as u128 * self.config.referral_bps as u128 / 10_000) as u64,
+        };
+        if reward > 0 {
+            pay_mint_fee(&self.minter, referrer, reward)?;
+        }
+        stats.record(referrer.key(), reward, bump);
+
+        Ok(reward)
+    }
+
+    /// Pays the community grant fee in lamports, less the referral `reward` already paid,
+    /// or the set amount of the accepted SPL token when `fee_token` is passed. Returns the
+    /// fee paid and the token it was paid in.
+    fn pay_fee(&self, fee: u64, reward: u64) -> Result<(u64, Option<Pubkey>)> {
+        let fee_token = match &self.fee_token {
+            Some(fee_token) => fee_token,
+            None => {
+                pay_mint_fee(&self.minter, &self.fee_vault, fee - reward)?;
+                return Ok((fee, None));
+            }
+        };
+
+        if !fee_token.enabled {
+            return Err(SolmapError::FeeTokenNotAccepted.into());
+        }
+
+        let (from, to) = match (&self.minter_token_account, &self.treasury_token_account) {
+            (Some(from), Some(to))
+                if from.mint == fee_token.mint
+                    && to.mint == fee_token.mint
+                    && to.owner == self.config.treasury =>
+            {
+                (from, to)
+            }
+            _ => return Err(SolmapError::InvalidFeeTokenAccounts.into()),
+        };
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.minter.to_account_info(),
+                },
+            ),
+            fee_token.amount,
+        )?;
+
+        Ok((fee_token.amount, Some(fee_token.mint)))
+    }
+}
+
+pub fn mint_handler(ctx: Context<MintSolmap>, solmap_number: u64) -> Result<()> {
+    msg!("Minting Solmap #{:?}", solmap_number);
+    if ctx.accounts.config.commit_reveal_only {
+        return Err(SolmapError::CommitRevealRequired.into());
+    }
+
+    let clock = Clock::get()?;
+
+    ctx.accounts.wallet_mints.record(
+        &ctx.accounts.season,
+        ctx.accounts.recipient.key(),
+        MintPhase::Public,
+        1,
+        ctx.bumps.wallet_mints,
+    )?;
+
+    let fee = ctx.accounts.season.mint_fee(clock.unix_timestamp);
+
+    let slot_index_info = ctx.accounts.slot_index.to_account_info();
+    let mut slot_index = SlotIndex::load(&slot_index_info)?;
+    claim_solmap(
+        &ctx.accounts.config,
+        &mut ctx.accounts.season,
+        &mut slot_index,
+        &slot_index_info,
+        solmap_number,
+        &clock,
+        MintPhase::Public,
+    )?;
+    slot_index.save(&slot_index_info)?;
+
+    create_solmap_nft(
+        ctx.accounts.nft_accounts(),
+        solmap_number,
+        &ctx.accounts.season.uri,
+        ctx.bumps.fvca,
+        None,
+    )?;
+
+    let reward = ctx.accounts.pay_referral(fee, ctx.bumps.referral_stats)?;
+    let (fee, fee_mint) = ctx.accounts.pay_fee(fee, reward)?;
+
+    complete_mint(
+        &ctx.accounts.minter,
+        &ctx.accounts.solmap_record,
+        &ctx.accounts.system_program,
+        fee,
+        fee_mint,
+        SolmapRecord {
+            solmap_number,
+            mint: ctx.accounts.mint.key(),
+            owner: ctx.accounts.recipient.key(),
+            payer: ctx.accounts.minter.key(),
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            inscription: ctx.accounts.inscription_v3.key(),
+            bump: ctx.bumps.solmap_record,
+        },
+    )
+}
+
+// Same as `MintSolmap`, except the mint is a PDA derived from the Solmap number so
+// clients can find a Solmap's NFT, metadata and inscription without an indexer.
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(solmap_number: u64)]
+pub struct MintSolmapPda<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    /// CHECK: any wallet
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    /// CHECK: seeds check here, loaded in the handler so a page that doesn't exist yet
+    /// is reported as SolmapBeyondIndexCapacity.
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(solmap_number).to_le_bytes()],
+        bump,
+    )]
+    pub slot_index: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = ["fee_vault".as_bytes(), &[fee_vault_shard(solmap_number)]], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = minter,
+        space = 8 + WalletMints::INIT_SPACE,
+        seeds = ["wallet_mints".as_bytes(), &season.id.to_le_bytes(), recipient.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mints: Account<'info, WalletMints>,
+
+    #[account(
+        init,
+        payer = minter,
+        seeds = ["solmap_mint".as_bytes(), &solmap_number.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::freeze_authority = minter,
+        mint::authority = minter
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: seeds check here, created in the handler once the Solmap is claimed
+    #[account(
+        mut,
+        seeds = ["solmap".as_bytes(), &solmap_number.to_le_bytes()], bump,
+    )]
+    pub solmap_record: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(mut,
+        seeds = [
+            recipient.key().as_ref(),
+            token_program.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = associated_token_program.key(),
+    )]
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(mut,
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(mut,
+        seeds = [
+            MasterEdition::PREFIX.0,
+            mpl_token_metadata::ID.as_ref(),
+            mint.key().as_ref(),
+            MasterEdition::PREFIX.1,
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked here
+    #[account(seeds = ["fvca".as_bytes()], bump)]
+    pub fvca: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked here
+    #[account(address = config.mcc)]
+    pub mcc: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(
+        mut,
+        seeds = [
+            Metadata::PREFIX,
+            mpl_token_metadata::ID.as_ref(),
+            mcc.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    #[account(
+        seeds = [
+            MasterEdition::PREFIX.0,
+            mpl_token_metadata::ID.as_ref(),
+            mcc.key().as_ref(),
+            MasterEdition::PREFIX.1,
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: seeds check here, validated by inscriptions program
+    #[account(mut,
+        seeds = ["inscription_v3".as_bytes(), mint.key().as_ref()],
+        bump,
+        seeds::program = INSCRIPTION_PROGRAM_ID,
+    )]
+    pub inscription_v3: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by inscriptions program
+    #[account(mut)]
+    pub inscription_data: UncheckedAccount<'info>,
+
+    /// CHECK: seed check here and validation in inscription program
+    #[account(mut,
+        seeds = ["inscription_summary".as_bytes()],
+        bump,
+        seeds::program = INSCRIPTION_PROGRAM_ID,
+    )]
+    pub inscription_summary: UncheckedAccount<'info>,
+
+    /// CHECK: address checked here
+    #[account(address = INSCRIPTION_PROGRAM_ID)]
+    pub inscriptions_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: address contraints check here
+    #[account(address = Instructions::id())]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+
+    /// CHECK: address checked here
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> MintSolmapPda<'info> {
+    fn nft_accounts(&self) -> SolmapNftAccounts<'_, 'info> {
+        SolmapNftAccounts {
+            minter: self.minter.as_ref(),
+            owner: self.recipient.as_ref(),
+            mint: self.mint.as_ref(),
+            token_account: self.token_account.as_ref(),
+            metadata: self.metadata.as_ref(),
+            master_edition: self.master_edition.as_ref(),
+            fvca: self.fvca.as_ref(),
+            mcc: self.mcc.as_ref(),
+            collection_metadata: self.collection_metadata.as_ref(),
+            collection_master_edition: self.collection_master_edition.as_ref(),
+            inscription_v3: self.inscription_v3.as_ref(),
+            inscription_data: self.inscription_data.as_ref(),
+            inscription_summary: self.inscription_summary.as_ref(),
+            inscriptions_program: self.inscriptions_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+            sysvar_instructions: self.sysvar_instructions.as_ref(),
+            token_metadata_program: self.token_metadata_program.as_ref(),
+            token_program: self.token_program.as_ref(),
+            associated_token_program: self.associated_token_program.as_ref(),
+        }
+    }
+}
+
+pub fn mint_pda_handler(ctx: Context<MintSolmapPda>, solmap_number: u64) -> Result<()> {
+    msg!("Minting Solmap #{:?} to its PDA mint", solmap_number);
+    if ctx.accounts.config.commit_reveal_only {
+        return Err(SolmapError::CommitRevealRequired.into());
+    }
+
+    let clock = Clock::get()?;
+
+    ctx.accounts.wallet_mints.record(
+        &ctx.accounts.season,
+        ctx.accounts.recipient.key(),
+        MintPhase::Public,
+        1,
+        ctx.bumps.wallet_mints,
+    )?;
+
+    let fee = ctx.accounts.season.mint_fee(clock.unix_timestamp);
+
+    let slot_index_info = ctx.accounts.slot_index.to_account_info();
+    let mut slot_index = SlotIndex::load(&slot_index_info)?;
+    claim_solmap(
+        &ctx.accounts.config,
+        &mut ctx.accounts.season,
+        &mut slot_index,
+        &slot_index_info,
+        solmap_number,
+        &clock,
+        MintPhase::Public,
+    )?;
+    slot_index.save(&slot_index_info)?;
+
+    // The mint signs as the inscription root, so sign for it with its seeds.
+    let number_bytes = solmap_number.to_le_bytes();
+    let mint_seeds: &[&[u8]] = &[b"solmap_mint", &number_bytes, &[ctx.bumps.mint]];
+
+    create_solmap_nft(
+        ctx.accounts.nft_accounts(),
+        solmap_number,
+        &ctx.accounts.season.uri,
+        ctx.bumps.fvca,
+        Some(mint_seeds),
+    )?;
+
+    pay_mint_fee(&ctx.accounts.minter, &ctx.accounts.fee_vault, fee)?;
+
+    complete_mint(
+        &ctx.accounts.minter,
+        &ctx.accounts.solmap_record,
+        &ctx.accounts.system_program,
+        fee,
+        None,
+        SolmapRecord {
+            solmap_number,
+            mint: ctx.accounts.mint.key(),
+            owner: ctx.accounts.recipient.key(),
+            payer: ctx.accounts.minter.key(),
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            inscription: ctx.accounts.inscription_v3.key(),
+            bump: ctx.bumps.solmap_record,
+        },
+    )
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(start: u64)]
+pub struct ReserveSolmaps<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    /// CHECK: seeds check here, loaded in the handler so a page that doesn't exist yet
+    /// is reported as SolmapBeyondIndexCapacity.
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(start).to_le_bytes()],
+        bump,
+    )]
+    pub slot_index: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = ["fee_vault".as_bytes(), &[fee_vault_shard(start)]], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + WalletMints::INIT_SPACE,
+        seeds = ["wallet_mints".as_bytes(), &season.id.to_le_bytes(), owner.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mints: Account<'info, WalletMints>,
+
     #[account(
         init,
-        payer = minter,
+        payer = owner,
+        space = 8 + Reservation::INIT_SPACE,
+        seeds = ["reservation".as_bytes(), &start.to_le_bytes()], bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Claims a run of adjacent Solmaps in the slot index and takes their fees in one go.
+// Their NFTs and inscriptions are created afterwards with `finalize_solmap`, and the ones
+// not finalized before the reservation expires can be released by anyone.
+pub fn reserve_solmaps_handler(ctx: Context<ReserveSolmaps>, start: u64, count: u8) -> Result<()> {
+    if ctx.accounts.config.commit_reveal_only {
+        return Err(SolmapError::CommitRevealRequired.into());
+    }
+
+    msg!("Reserving {} Solmaps from #{}", count, start);
+    ctx.accounts.wallet_mints.record(
+        &ctx.accounts.season,
+        ctx.accounts.owner.key(),
+        MintPhase::Public,
+        count as u32,
+        ctx.bumps.wallet_mints,
+    )?;
+    open_reservation(
+        &ctx.accounts.config,
+        &mut ctx.accounts.season,
+        &ctx.accounts.slot_index,
+        &ctx.accounts.owner,
+        &ctx.accounts.fee_vault,
+        &mut ctx.accounts.reservation,
+        ctx.bumps.reservation,
+        start,
+        count,
+        MintPhase::Public,
+    )
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(start: u64)]
+pub struct ReserveAllowlist<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    /// CHECK: seeds check here, loaded in the handler so a page that doesn't exist yet
+    /// is reported as SolmapBeyondIndexCapacity.
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(start).to_le_bytes()],
+        bump,
+    )]
+    pub slot_index: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = ["fee_vault".as_bytes(), &[fee_vault_shard(start)]], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + WalletMints::INIT_SPACE,
+        seeds = ["wallet_mints".as_bytes(), &season.id.to_le_bytes(), owner.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mints: Account<'info, WalletMints>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Reservation::INIT_SPACE,
+        seeds = ["reservation".as_bytes(), &start.to_le_bytes()], bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Presale counterpart of `reserve_solmaps` for the wallets in the season's allowlist. Each
+// allowlist entry is a wallet and the number of Solmaps it may mint, counted across
+// calls in its `WalletMints` account. It is rejected as well when minting is
+// commit-reveal only.
+pub fn reserve_allowlist_handler(
+    ctx: Context<ReserveAllowlist>,
+    start: u64,
+    count: u8,
+    quota: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    if ctx.accounts.config.commit_reveal_only {
+        return Err(SolmapError::CommitRevealRequired.into());
+    }
+
+    let owner = ctx.accounts.owner.key();
+    let leaf = allowlist_leaf(&owner, quota);
+    if !verify_proof(&proof, &ctx.accounts.season.allowlist_root, leaf) {
+        return Err(SolmapError::InvalidAllowlistProof.into());
+    }
+
+    let wallet_mints = &mut ctx.accounts.wallet_mints;
+    if wallet_mints
+        .minted(MintPhase::Allowlist)
+        .saturating_add(count as u32)
+        > quota
+    {
+        return Err(SolmapError::AllowlistQuotaExceeded.into());
+    }
+    wallet_mints.record(
+        &ctx.accounts.season,
+        owner,
+        MintPhase::Allowlist,
+        count as u32,
+        ctx.bumps.wallet_mints,
+    )?;
+
+    msg!("Reserving {} allowlisted Solmaps from #{}", count, start);
+    open_reservation(
+        &ctx.accounts.config,
+        &mut ctx.accounts.season,
+        &ctx.accounts.slot_index,
+        &ctx.accounts.owner,
+        &ctx.accounts.fee_vault,
+        &mut ctx.accounts.reservation,
+        ctx.bumps.reservation,
+        start,
+        count,
+        MintPhase::Allowlist,
+    )
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct MintRandom<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(mut, seeds = ["fee_vault".as_bytes(), &[random_draw_fee_vault_shard(&owner.key())]], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + WalletMints::INIT_SPACE,
+        seeds = ["wallet_mints".as_bytes(), &season.id.to_le_bytes(), owner.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mints: Account<'info, WalletMints>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Reservation::INIT_SPACE,
+        seeds = ["random_draw".as_bytes(), owner.key().as_ref()], bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// CHECK: address checked here, only the newest hash is read from its raw data
+    #[account(address = SlotHashes::id())]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Draws from every season number whose slot exists and reserves the first free one from
+// there, moving on to the next slot index pages when a page is full. The draw is seeded
+// with the newest slot hash and the signing owner, so neither can be picked to aim at a
+// number, and the drawn number is only known once the instruction runs. Its accounts
+// can't be passed yet, so it is reserved in the owner's random draw reservation and
+// minted with `finalize_solmap`. An owner has one random draw pending at a time. Like
+// every other mint path, it is rejected when minting is commit-reveal only.
+pub fn mint_random_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintRandom<'info>>,
+) -> Result<()> {
+    if ctx.accounts.config.commit_reveal_only {
+        return Err(SolmapError::CommitRevealRequired.into());
+    }
+
+    let slot_hashes = ctx.accounts.slot_hashes.try_borrow_data()?;
+    let (draw_slot, slot_hash) =
+        newest_slot_hash(&slot_hashes).ok_or(SolmapError::SlotHashUnavailable)?;
+    msg!("Drawing a Solmap from the hash of slot {}", draw_slot);
+    let clock = Clock::get()?;
+    let season = &ctx.accounts.season;
+
+    let first = season.first_solmap;
+    let end = season.mintable_end(clock.slot);
+    if first >= end {
+        return Err(SolmapError::NoSolmapAvailable.into());
+    }
+
+    let start = random_draw_start(first, end, slot_hash, &ctx.accounts.owner.key());
+
+    let mut drawn = None;
+    for (from, to) in random_draw_runs(first, start, end) {
+        let page_address = find_slot_index_page_address(slot_index_page(from));
+        let page_info = ctx
+            .remaining_accounts
+            .iter()
+            .find(|info| info.key == &page_address)
+            .ok_or(SolmapError::RandomDrawPageMissing)?;
+
+        // A page that was never created can't track any Solmap yet.
+        if page_info.data_is_empty() {
+            continue;
+        }
+
+        let found = SlotIndex::load(page_info)?.find_unminted(
+            &page_info.try_borrow_data()?,
+            from,
+            from,
+            to,
+        );
+        if let Some(solmap_number) = found {
+            drawn = Some((solmap_number, page_info));
+            break;
+        }
+    }
+
+    let (solmap_number, slot_index_info) = drawn.ok_or(SolmapError::NoSolmapAvailable)?;
+    msg!("Drew Solmap #{}", solmap_number);
+
+    ctx.accounts.wallet_mints.record(
+        &ctx.accounts.season,
+        ctx.accounts.owner.key(),
+        MintPhase::Public,
+        1,
+        ctx.bumps.wallet_mints,
+    )?;
+    open_reservation(
+        &ctx.accounts.config,
+        &mut ctx.accounts.season,
+        slot_index_info,
+        &ctx.accounts.owner,
+        &ctx.accounts.fee_vault,
+        &mut ctx.accounts.reservation,
+        ctx.bumps.reservation,
+        solmap_number,
+        1,
+        MintPhase::Public,
+    )
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct CommitSolmap<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Commitment::INIT_SPACE,
+        seeds = ["commitment".as_bytes(), owner.key().as_ref(), &hash], bump,
+    )]
+    pub commitment: Account<'info, Commitment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Only the hash of the Solmap number is sent, so a pending commitment doesn't tell
+// searchers which number is being bid for.
+pub fn commit_solmap_handler(ctx: Context<CommitSolmap>, hash: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let commitment = &mut ctx.accounts.commitment;
+    commitment.owner = ctx.accounts.owner.key();
+    commitment.hash = hash;
+    commitment.committed_slot = clock.slot;
+    commitment.bump = ctx.bumps.commitment;
+
+    emit!(SolmapCommitted {
+        owner: commitment.owner,
+        hash,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct CancelCommitment<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = ["commitment".as_bytes(), owner.key().as_ref(), &commitment.hash],
+        bump = commitment.bump,
+        has_one = owner @ SolmapError::Unauthorized,
+    )]
+    pub commitment: Account<'info, Commitment>,
+}
+
+// A commitment whose number was taken or that won't be revealed can be cancelled at any
+// time to get its rent back.
+pub fn cancel_commitment_handler(ctx: Context<CancelCommitment>) -> Result<()> {
+    let commitment = &ctx.accounts.commitment;
+    let clock = Clock::get()?;
+
+    msg!("Cancelling commitment {:?}", commitment.hash);
+    emit!(CommitmentCancelled {
+        owner: commitment.owner,
+        hash: commitment.hash,
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(solmap_number: u64)]
+pub struct RevealSolmap<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    /// CHECK: seeds check here, loaded in the handler so a page that doesn't exist yet
+    /// is reported as SolmapBeyondIndexCapacity.
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(solmap_number).to_le_bytes()],
+        bump,
+    )]
+    pub slot_index: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = ["fee_vault".as_bytes(), &[fee_vault_shard(solmap_number)]], bump)]
+    pub fee_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + WalletMints::INIT_SPACE,
+        seeds = ["wallet_mints".as_bytes(), &season.id.to_le_bytes(), owner.key().as_ref()],
+        bump,
+    )]
+    pub wallet_mints: Account<'info, WalletMints>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = ["commitment".as_bytes(), owner.key().as_ref(), &commitment.hash],
+        bump = commitment.bump,
+        has_one = owner @ SolmapError::Unauthorized,
+    )]
+    pub commitment: Account<'info, Commitment>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Reservation::INIT_SPACE,
+        seeds = ["reservation".as_bytes(), &solmap_number.to_le_bytes()], bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Reserves the committed Solmap, which is then minted with `finalize_solmap`. The first
+// valid reveal takes the number; later ones fail with `SolmapAlreadyMinted`.
+pub fn reveal_solmap_handler(
+    ctx: Context<RevealSolmap>,
+    solmap_number: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    let commitment = &ctx.accounts.commitment;
+    if commitment_hash(solmap_number, &salt, &commitment.owner) != commitment.hash {
+        return Err(SolmapError::InvalidCommitment.into());
+    }
+
+    let clock = Clock::get()?;
+    if !commitment.can_reveal(clock.slot, ctx.accounts.config.reveal_delay_slots) {
+        return Err(SolmapError::RevealTooEarly.into());
+    }
+
+    msg!("Revealing Solmap #{}", solmap_number);
+    ctx.accounts.wallet_mints.record(
+        &ctx.accounts.season,
+        ctx.accounts.owner.key(),
+        MintPhase::Public,
+        1,
+        ctx.bumps.wallet_mints,
+    )?;
+    open_reservation(
+        &ctx.accounts.config,
+        &mut ctx.accounts.season,
+        &ctx.accounts.slot_index,
+        &ctx.accounts.owner,
+        &ctx.accounts.fee_vault,
+        &mut ctx.accounts.reservation,
+        ctx.bumps.reservation,
+        solmap_number,
+        1,
+        MintPhase::Public,
+    )
+}
+
+// Reserved Solmaps are always minted to their PDA mint, so finalizing doesn't need a
+// new keypair per NFT.
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(solmap_number: u64)]
+pub struct FinalizeSolmap<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // Reservations are only created by this program, under the seeds of their first
+    // Solmap or of the owner of a random draw, so their address isn't checked again.
+    #[account(mut, has_one = owner @ SolmapError::Unauthorized)]
+    pub reservation: Account<'info, Reservation>,
+
+    #[account(
+        seeds = ["season".as_bytes(), &reservation.season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(solmap_number).to_le_bytes()],
+        bump = slot_index.bump,
+        constraint = slot_index.version == SLOT_INDEX_VERSION @ SolmapError::SlotIndexVersionMismatch,
+    )]
+    pub slot_index: Account<'info, SlotIndex>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = ["solmap_mint".as_bytes(), &solmap_number.to_le_bytes()],
+        bump,
         mint::decimals = 0,
-        mint::freeze_authority = minter,
-        mint::authority = minter
-    )]    
+        mint::freeze_authority = owner,
+        mint::authority = owner
+    )]
     pub mint: Account<'info, Mint>,
 
+    /// CHECK: seeds check here, created in the handler
+    #[account(
+        mut,
+        seeds = ["solmap".as_bytes(), &solmap_number.to_le_bytes()], bump,
+    )]
+    pub solmap_record: UncheckedAccount<'info>,
+
     /// CHECK: seeds check here, Token Metadata provides the rest of validations
     #[account(mut,
         seeds = [
-            minter.key().as_ref(),
+            owner.key().as_ref(),
             token_program.key().as_ref(),
             mint.key().as_ref()
         ],
@@ -271,7 +2672,7 @@ pub struct MintSolmap<'info> {
     pub token_account: UncheckedAccount<'info>,
 
     /// CHECK: seeds check here, Token Metadata provides the rest of validations
-    #[account(mut, 
+    #[account(mut,
         seeds = [
             Metadata::PREFIX,
             mpl_token_metadata::ID.as_ref(),
@@ -283,7 +2684,7 @@ pub struct MintSolmap<'info> {
     pub metadata: UncheckedAccount<'info>,
 
     /// CHECK: seeds check here, Token Metadata provides the rest of validations
-    #[account(mut, 
+    #[account(mut,
         seeds = [
             MasterEdition::PREFIX.0,
             mpl_token_metadata::ID.as_ref(),
@@ -295,19 +2696,15 @@ pub struct MintSolmap<'info> {
     )]
     pub master_edition: UncheckedAccount<'info>,
 
-    // Used to tie all Solmap NFTs together in a collection.
     /// CHECK: Address checked here
-    #[account( 
-        seeds = ["fvca".as_bytes()],
-        bump
-    )]
+    #[account(seeds = ["fvca".as_bytes()], bump)]
     pub fvca: UncheckedAccount<'info>,
 
-        /// CHECK: seeds check here
-    #[account(address = SOLMAP_MCC)]
+    /// CHECK: Address checked here
+    #[account(address = config.mcc)]
     pub mcc: UncheckedAccount<'info>,
 
-            /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
     #[account(
         mut,
         seeds = [
@@ -320,7 +2717,7 @@ pub struct MintSolmap<'info> {
     )]
     pub collection_metadata: UncheckedAccount<'info>,
 
-        /// CHECK: seeds check here, Token Metadata provides the rest of validations
+    /// CHECK: seeds check here, Token Metadata provides the rest of validations
     #[account(
         seeds = [
             MasterEdition::PREFIX.0,
@@ -332,24 +2729,27 @@ pub struct MintSolmap<'info> {
         seeds::program = mpl_token_metadata::ID,
     )]
     pub collection_master_edition: UncheckedAccount<'info>,
-    
-    /// CHECK: Validated by inscriptions program
-    #[account(mut)]
+
+    /// CHECK: seeds check here, validated by inscriptions program
+    #[account(mut,
+        seeds = ["inscription_v3".as_bytes(), mint.key().as_ref()],
+        bump,
+        seeds::program = INSCRIPTION_PROGRAM_ID,
+    )]
     pub inscription_v3: UncheckedAccount<'info>,
-    
+
     /// CHECK: Validated by inscriptions program
     #[account(mut)]
     pub inscription_data: UncheckedAccount<'info>,
-    
+
     /// CHECK: seed check here and validation in inscription program
-    #[account(mut, 
+    #[account(mut,
         seeds = ["inscription_summary".as_bytes()],
         bump,
         seeds::program = INSCRIPTION_PROGRAM_ID,
     )]
     pub inscription_summary: UncheckedAccount<'info>,
 
-    /// CHECK: address checked here
     /// CHECK: address checked here
     #[account(address = INSCRIPTION_PROGRAM_ID)]
     pub inscriptions_program: UncheckedAccount<'info>,
@@ -364,46 +2764,563 @@ pub struct MintSolmap<'info> {
     #[account(address = mpl_token_metadata::ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
 
-    #[account()]
     pub token_program: Program<'info, Token>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn mint_handler(ctx: Context<MintSolmap>, solmap_number: u64) -> Result<()> {
-    // GO LIVE DATE CHECK
+impl<'info> FinalizeSolmap<'info> {
+    fn nft_accounts(&self) -> SolmapNftAccounts<'_, 'info> {
+        SolmapNftAccounts {
+            minter: self.owner.as_ref(),
+            owner: self.owner.as_ref(),
+            mint: self.mint.as_ref(),
+            token_account: self.token_account.as_ref(),
+            metadata: self.metadata.as_ref(),
+            master_edition: self.master_edition.as_ref(),
+            fvca: self.fvca.as_ref(),
+            mcc: self.mcc.as_ref(),
+            collection_metadata: self.collection_metadata.as_ref(),
+            collection_master_edition: self.collection_master_edition.as_ref(),
+            inscription_v3: self.inscription_v3.as_ref(),
+            inscription_data: self.inscription_data.as_ref(),
+            inscription_summary: self.inscription_summary.as_ref(),
+            inscriptions_program: self.inscriptions_program.as_ref(),
+            system_program: self.system_program.as_ref(),
+            sysvar_instructions: self.sysvar_instructions.as_ref(),
+            token_metadata_program: self.token_metadata_program.as_ref(),
+            token_program: self.token_program.as_ref(),
+            associated_token_program: self.associated_token_program.as_ref(),
+        }
+    }
+}
+
+pub fn finalize_solmap_handler(ctx: Context<FinalizeSolmap>, solmap_number: u64) -> Result<()> {
+    let reservation = &ctx.accounts.reservation;
+    if !reservation.contains(solmap_number) {
+        return Err(SolmapError::SolmapNotReserved.into());
+    }
+    if reservation.is_finalized(solmap_number) {
+        return Err(SolmapError::SolmapAlreadyFinalized.into());
+    }
+
     let clock = Clock::get()?;
-    if clock.unix_timestamp < GO_LIVE_DATE {
-        return Err(SolmapError::MintingNotLiveYet.into());
+    if reservation.is_expired(clock.slot) {
+        return Err(SolmapError::ReservationExpired.into());
     }
 
-    msg!("Minting Solmap #{:?}", solmap_number);
-    let fvca = &ctx.accounts.fvca;
+    // Held milestone numbers can be reserved before their slot exists.
+    if !solmap_slot_exists(solmap_number, clock.slot) {
+        return Err(SolmapError::InvalidSolmapNumber.into());
+    }
 
-    let inscription_v3 = &ctx.accounts.inscription_v3;
-    let inscription_data = &ctx.accounts.inscription_data;
-    let inscription_summary = &ctx.accounts.inscription_summary;
+    msg!("Finalizing Solmap #{:?}", solmap_number);
 
-    let minter = &ctx.accounts.minter;
-    let mint = &ctx.accounts.mint;
-    let token_account = &ctx.accounts.token_account;
-    let metadata = &ctx.accounts.metadata;
-    let master_edition = &ctx.accounts.master_edition;
+    // Held and auctioned Solmaps are only counted as minted once they are claimed.
+    if reservation.is_held() {
+        let slot_index_info = ctx.accounts.slot_index.to_account_info();
+        ctx.accounts
+            .slot_index
+            .claim_held(&slot_index_info, solmap_number, clock.slot)?;
+        update_season_mints(&mut ctx.accounts.season, |minted| minted + 1)?;
+    }
+
+    let number_bytes = solmap_number.to_le_bytes();
+    let mint_seeds: &[&[u8]] = &[b"solmap_mint", &number_bytes, &[ctx.bumps.mint]];
+
+    create_solmap_nft(
+        ctx.accounts.nft_accounts(),
+        solmap_number,
+        &ctx.accounts.season.uri,
+        ctx.bumps.fvca,
+        Some(mint_seeds),
+    )?;
+
+    complete_mint(
+        &ctx.accounts.owner,
+        &ctx.accounts.solmap_record,
+        &ctx.accounts.system_program,
+        reservation.fee_share(solmap_number),
+        None,
+        SolmapRecord {
+            solmap_number,
+            mint: ctx.accounts.mint.key(),
+            owner: ctx.accounts.owner.key(),
+            payer: ctx.accounts.owner.key(),
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            inscription: ctx.accounts.inscription_v3.key(),
+            bump: ctx.bumps.solmap_record,
+        },
+    )?;
+
+    // Give the rent back once every reserved Solmap has its NFT.
+    let reservation = &mut ctx.accounts.reservation;
+    reservation.set_finalized(solmap_number);
+    if reservation.is_complete() {
+        reservation.close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+// Held Solmaps are claimed with the same accounts as a finalized reservation.
+pub fn claim_reserved_handler(ctx: Context<FinalizeSolmap>, solmap_number: u64) -> Result<()> {
+    if !ctx.accounts.reservation.is_held() {
+        return Err(SolmapError::ReservationNotHeld.into());
+    }
+
+    finalize_solmap_handler(ctx, solmap_number)
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(start: u64)]
+pub struct HoldSolmaps<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(start).to_le_bytes()],
+        bump = slot_index.bump,
+        constraint = slot_index.version == SLOT_INDEX_VERSION @ SolmapError::SlotIndexVersionMismatch,
+    )]
+    pub slot_index: Account<'info, SlotIndex>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Reservation::INIT_SPACE,
+        seeds = ["reservation".as_bytes(), &start.to_le_bytes()], bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Holds milestone or partner Solmaps back from every public mint path by marking them
+// in the held bitmap of the slot index, in a reservation that never expires and only
+// `claimant` can claim. They are counted as minted when claimed. A list of numbers is
+// held with one run per number or per adjacent numbers.
+pub fn hold_solmaps_handler(
+    ctx: Context<HoldSolmaps>,
+    start: u64,
+    count: u8,
+    claimant: Pubkey,
+) -> Result<()> {
+    let last = reservation_last(start, count)?;
+
+    msg!("Holding {} Solmaps from #{} for {}", count, start, claimant);
+    let clock = Clock::get()?;
+    let season = &ctx.accounts.season;
+    let slot_index = &ctx.accounts.slot_index;
+    let slot_index_info = slot_index.to_account_info();
+    for solmap_number in start..=last {
+        if !season.contains(solmap_number) {
+            return Err(SolmapError::InvalidSolmapNumber.into());
+        }
+        slot_index.hold(&slot_index_info, solmap_number)?;
+    }
+
+    let reservation = &mut ctx.accounts.reservation;
+    reservation.owner = claimant;
+    reservation.season = season.id;
+    reservation.start = start;
+    reservation.count = count;
+    reservation.finalized = 0;
+    reservation.fee = 0;
+    reservation.reserved_slot = clock.slot;
+    reservation.expiry_slot = u64::MAX;
+    reservation.bump = ctx.bumps.reservation;
+
+    emit!(SolmapsReserved {
+        start,
+        count,
+        owner: claimant,
+        slot: clock.slot,
+        expiry_slot: reservation.expiry_slot,
+        fee: 0,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct ReleaseReservation<'info> {
+    /// CHECK: address checked here, receives the reservation rent
+    #[account(mut, address = reservation.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    // Like in `FinalizeSolmap`, any reservation of this program can be released.
+    #[account(mut)]
+    pub reservation: Account<'info, Reservation>,
+
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(reservation.start).to_le_bytes()],
+        bump = slot_index.bump,
+        constraint = slot_index.version == SLOT_INDEX_VERSION @ SolmapError::SlotIndexVersionMismatch,
+    )]
+    pub slot_index: Account<'info, SlotIndex>,
+
+    #[account(
+        seeds = ["season".as_bytes(), &reservation.season.to_le_bytes()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+}
+
+// Anyone can release an expired reservation. Solmaps that were not finalized can be
+// minted again and no longer count towards the season's pricing. Their fees are kept
+// and the rent goes back to the owner.
+pub fn release_reservation_handler(ctx: Context<ReleaseReservation>) -> Result<()> {
+    let clock = Clock::get()?;
+    let reservation = &ctx.accounts.reservation;
+
+    if !reservation.is_expired(clock.slot) {
+        return Err(SolmapError::ReservationNotExpired.into());
+    }
+
+    let released: Vec<u64> = (reservation.start..reservation.start + reservation.count as u64)
+        .filter(|solmap_number| !reservation.is_finalized(*solmap_number))
+        .collect();
+
+    msg!("Releasing {} reserved Solmaps", released.len());
+    let slot_index_info = ctx.accounts.slot_index.to_account_info();
+    for solmap_number in &released {
+        ctx.accounts
+            .slot_index
+            .clear_minted(&slot_index_info, *solmap_number)?;
+    }
+    // Saturates in case the pricing started counting mints after the reservation.
+    update_season_mints(&mut ctx.accounts.season, |minted| {
+        minted.saturating_sub(released.len() as u32)
+    })?;
+
+    emit!(SolmapsReleased {
+        start: reservation.start,
+        owner: reservation.owner,
+        released,
+        slot: clock.slot,
+    });
+
+    ctx.accounts
+        .reservation
+        .close(ctx.accounts.owner.to_account_info())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+#[instruction(solmap: u64)]
+pub struct CreateAuction<'info> {
+    #[account(address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = ["season".as_bytes(), &season.id.to_le_bytes()], bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(solmap).to_le_bytes()],
+        bump = slot_index.bump,
+        constraint = slot_index.version == SLOT_INDEX_VERSION @ SolmapError::SlotIndexVersionMismatch,
+    )]
+    pub slot_index: Account<'info, SlotIndex>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = ["auction".as_bytes(), &solmap.to_le_bytes()], bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Premium numbers are held in the slot index for the whole auction, like held Solmaps,
+// so no public mint path can claim them while bids are open. The winner's claim counts
+// them as minted.
+pub fn create_auction_handler(
+    ctx: Context<CreateAuction>,
+    solmap_number: u64,
+    args: AuctionArgs,
+) -> Result<()> {
+    let season = &ctx.accounts.season;
+    if !season.contains(solmap_number) {
+        return Err(SolmapError::InvalidSolmapNumber.into());
+    }
+
+    msg!("Auctioning Solmap #{}", solmap_number);
+    let slot_index_info = ctx.accounts.slot_index.to_account_info();
+    ctx.accounts
+        .slot_index
+        .hold(&slot_index_info, solmap_number)?;
+
+    let auction = &mut ctx.accounts.auction;
+    auction.solmap_number = solmap_number;
+    auction.season = season.id;
+    auction.kind = args.kind;
+    auction.reserve_price = args.reserve_price;
+    auction.start_time = args.start_time;
+    auction.end_time = args.end_time;
+    auction.bidder = None;
+    auction.bid = 0;
+    auction.bump = ctx.bumps.auction;
+    auction.validate()?;
+
+    emit!(AuctionCreated {
+        solmap_number,
+        kind: args.kind,
+        reserve_price: args.reserve_price,
+        start_time: args.start_time,
+        end_time: args.end_time,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = ["auction".as_bytes(), &auction.solmap_number.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// CHECK: address checked in the handler, refunded the bid it was outbid on
+    #[account(mut)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Bids are escrowed in the auction account. An English bid refunds the bid it outbids,
+// so the previous bidder is passed whenever the auction has one.
+pub fn bid_handler(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction = &ctx.accounts.auction;
+    if !auction.is_open(clock.unix_timestamp) {
+        return Err(SolmapError::AuctionNotOpen.into());
+    }
+
+    let min_bid = auction.min_bid(clock.unix_timestamp);
+    if amount < min_bid {
+        return Err(SolmapError::BidTooLow.into());
+    }
+
+    // A Dutch auction sells at its current price, `amount` only caps what is paid.
+    let bid = match auction.kind {
+        AuctionKind::Dutch { .. } => min_bid,
+        AuctionKind::English { .. } => amount,
+    };
+
+    let previous = match (auction.bidder, &ctx.accounts.previous_bidder) {
+        (None, None) => None,
+        (Some(bidder), Some(previous_bidder)) if previous_bidder.key() == bidder => {
+            Some((previous_bidder.to_account_info(), auction.bid))
+        }
+        _ => return Err(SolmapError::BidderMismatch.into()),
+    };
+
+    msg!(
+        "Bidding {} lamports on Solmap #{}",
+        bid,
+        auction.solmap_number
+    );
+    invoke(
+        &system_instruction::transfer(ctx.accounts.bidder.key, &auction.key(), bid),
+        &[
+            ctx.accounts.bidder.to_account_info(),
+            auction.to_account_info(),
+        ],
+    )?;
+
+    // The auction account is owned by the program, so the escrow is refunded directly.
+    if let Some((previous_bidder, refund)) = previous {
+        **auction.to_account_info().try_borrow_mut_lamports()? -= refund;
+        **previous_bidder.try_borrow_mut_lamports()? += refund;
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.bidder = Some(ctx.accounts.bidder.key());
+    auction.bid = bid;
+
+    emit!(BidPlaced {
+        solmap_number: auction.solmap_number,
+        bidder: ctx.accounts.bidder.key(),
+        bid,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = treasury,
+        seeds = ["auction".as_bytes(), &auction.solmap_number.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// CHECK: address checked here, receives the winning bid
+    #[account(mut, address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Reservation::INIT_SPACE,
+        seeds = ["reservation".as_bytes(), &auction.solmap_number.to_le_bytes()], bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Anyone can settle an auction once it is over. The escrowed bid goes to the treasury
+// with the auction rent, and the winner gets a held reservation for the Solmap.
+pub fn settle_auction_handler(ctx: Context<SettleAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction = &ctx.accounts.auction;
+    if !auction.is_over(clock.unix_timestamp) {
+        return Err(SolmapError::AuctionNotOver.into());
+    }
+    let winner = auction.bidder.ok_or(SolmapError::AuctionHasNoBids)?;
+
+    msg!(
+        "Settling Solmap #{} to {} for {} lamports",
+        auction.solmap_number,
+        winner,
+        auction.bid
+    );
+    let reservation = &mut ctx.accounts.reservation;
+    reservation.owner = winner;
+    reservation.season = auction.season;
+    reservation.start = auction.solmap_number;
+    reservation.count = 1;
+    reservation.finalized = 0;
+    reservation.fee = auction.bid;
+    reservation.reserved_slot = clock.slot;
+    reservation.expiry_slot = u64::MAX;
+    reservation.bump = ctx.bumps.reservation;
+
+    emit!(AuctionSettled {
+        solmap_number: auction.solmap_number,
+        winner,
+        price: auction.bid,
+    });
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    #[account(mut, address = config.admin @ SolmapError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = ["config".as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = ["auction".as_bytes(), &auction.solmap_number.to_le_bytes()],
+        bump = auction.bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = ["slot_index".as_bytes(), &slot_index_page(auction.solmap_number).to_le_bytes()],
+        bump = slot_index.bump,
+        constraint = slot_index.version == SLOT_INDEX_VERSION @ SolmapError::SlotIndexVersionMismatch,
+    )]
+    pub slot_index: Account<'info, SlotIndex>,
+}
+
+// An auction without bids can be cancelled at any time, which releases its Solmap to
+// the public mint again.
+pub fn cancel_auction_handler(ctx: Context<CancelAuction>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    if auction.bidder.is_some() {
+        return Err(SolmapError::AuctionHasBids.into());
+    }
+
+    msg!(
+        "Cancelling the auction of Solmap #{}",
+        auction.solmap_number
+    );
+    let slot_index_info = ctx.accounts.slot_index.to_account_info();
+    ctx.accounts
+        .slot_index
+        .clear_held(&slot_index_info, auction.solmap_number)
+}
 
-    let token_metadata_program = &ctx.accounts.token_metadata_program;
-    let sysvar_instructions = &ctx.accounts.sysvar_instructions;
-    let system_program = &ctx.accounts.system_program;
-    let token_program = &ctx.accounts.token_program;
-    let associated_token_program = &ctx.accounts.associated_token_program;
-    let inscriptions_program = &ctx.accounts.inscriptions_program;
+/// Checks that a Solmap can be minted now and marks it as minted in its slot index page.
+fn claim_solmap(
+    config: &Config,
+    season: &mut Account<Season>,
+    slot_index: &mut SlotIndex,
+    slot_index_info: &AccountInfo,
+    solmap_number: u64,
+    clock: &Clock,
+    phase: MintPhase,
+) -> Result<()> {
+    if phase == MintPhase::Public && !config.public_mint_enabled {
+        return Err(SolmapError::PublicMintDisabled.into());
+    }
+
+    if !season.open {
+        return Err(SolmapError::SeasonClosed.into());
+    }
 
-    let solmap_string = format!("{solmap_number}.solmap");
-    let solmap_bytes = solmap_string.as_bytes();
+    // GO LIVE DATE CHECK
+    let start_time = match phase {
+        MintPhase::Allowlist => season.allowlist_start_time,
+        MintPhase::Public => season.start_time,
+    };
+    if clock.unix_timestamp < start_time {
+        return Err(SolmapError::MintingNotLiveYet.into());
+    }
 
     // Solmap validations
 
-    // Solmap must be less than SEASON_1_SUPPLY.
-    if solmap_number >= SEASON_1_SUPPLY {
+    // Solmap must be within the season's range.
+    if !season.contains(solmap_number) {
         return Err(SolmapError::InvalidSolmapNumber.into());
     }
 
@@ -412,196 +3329,167 @@ pub fn mint_handler(ctx: Context<MintSolmap>, solmap_number: u64) -> Result<()>
 
     // Slot must already exist.
     let current_slot = clock.slot;
-    if (solmap_number + 1) * 1000 > current_slot {
+    if !solmap_slot_exists(solmap_number, current_slot) {
         return Err(SolmapError::InvalidSolmapNumber.into());
     }
 
-    // Slot cannot be minted already. We track minted slots as 1s in the slot index account.
-    let slot_index = &mut ctx.accounts.slot_index.data.borrow_mut();
-    let slot_index_bits = slot_index.view_bits_mut::<Lsb0>();
+    // Slot cannot be minted already. We track minted slots as 1s in the slot index page.
+    slot_index.set_minted(slot_index_info, solmap_number, current_slot)?;
+
+    update_season_mints(season, |minted| minted + 1)
+}
+
+/// Updates the mints the season's pricing follows. Seasons whose pricing doesn't count
+/// mints are passed read-only, so their mints don't lock the season and run in parallel.
+fn update_season_mints(
+    season: &mut Account<Season>,
+    update: impl FnOnce(u32) -> u32,
+) -> Result<()> {
+    if !season.pricing.counts_mints() {
+        return Ok(());
+    }
+
+    if !season.to_account_info().is_writable {
+        return Err(SolmapError::SeasonNotWritable.into());
+    }
+    season.minted = update(season.minted);
+    season.exit(&crate::ID)
+}
 
-    let mut slot_index_bit = slot_index_bits.get_mut(solmap_number as usize).unwrap();
+/// Last Solmap of a run of `count` from `start`, which must fit in one reservation and
+/// one slot index page.
+fn reservation_last(start: u64, count: u8) -> Result<u64> {
+    if count == 0 || count > MAX_RESERVATION_SIZE {
+        return Err(SolmapError::InvalidReservationRange.into());
+    }
 
-    if *slot_index_bit {
-        return Err(SolmapError::SolmapAlreadyMinted.into());
-    } else {
-        *slot_index_bit = true;
-    }
-
-    // Create mint and ATA
-    let create_args = CreateArgs::V1 {
-        name: solmap_string.clone(),
-        symbol: "SOLMAP".to_string(),
-        uri: SOLMAP_URI.to_string(),
-        seller_fee_basis_points: 0,
-        creators: Some(vec![Creator {
-            address: fvca.key(),
-            verified: true,
-            share: 100,
-        }]),
-        primary_sale_happened: true,
-        is_mutable: true,
-        token_standard: TokenStandard::NonFungible,
-        collection: Some(Collection {
-            key: SOLMAP_MCC,
-            verified: false, // need to verify in a separate instruction
-        }),
-        uses: None,
-        collection_details: None,
-        rule_set: None,
-        decimals: None,
-        print_supply: Some(PrintSupply::Zero),
-    };
+    match start.checked_add(count as u64 - 1) {
+        Some(last) if slot_index_page(last) == slot_index_page(start) => Ok(last),
+        _ => Err(SolmapError::InvalidReservationRange.into()),
+    }
+}
 
-    let fvca_seeds = &[b"fvca".as_ref(), &[ctx.bumps.fvca]];
-
-    let mut create_builder = CreateCpiBuilder::new(&ctx.accounts.token_metadata_program);
-    create_builder
-        .payer(minter)
-        .metadata(metadata)
-        .master_edition(Some(master_edition))
-        .mint(&mint.to_account_info(), true)
-        .authority(minter)
-        .update_authority(fvca, true)
-        .system_program(system_program)
-        .spl_token_program(Some(&token_program.to_account_info()))
-        .sysvar_instructions(sysvar_instructions)
-        .create_args(create_args)
-        .invoke_signed(&[fvca_seeds])?;
-
-    // Mint token.
-    let mint_args = MintArgs::V1 {
-        amount: 1,
-        authorization_data: None,
-    };
+/// Claims `count` Solmaps from `start`, takes their fees and fills in the reservation
+/// holding them.
+#[allow(clippy::too_many_arguments)]
+fn open_reservation<'info>(
+    config: &Config,
+    season: &mut Account<'info, Season>,
+    slot_index_info: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    fee_vault: &AccountInfo<'info>,
+    reservation: &mut Account<'info, Reservation>,
+    bump: u8,
+    start: u64,
+    count: u8,
+    phase: MintPhase,
+) -> Result<()> {
+    let last = reservation_last(start, count)?;
 
-    let mut mint_builder = MintCpiBuilder::new(&ctx.accounts.token_metadata_program);
-    mint_builder
-        .token(token_account)
-        .token_owner(Some(minter))
-        .metadata(metadata)
-        .master_edition(Some(master_edition))
-        .mint(&mint.to_account_info())
-        .authority(fvca)
-        .payer(minter)
-        .system_program(system_program)
-        .spl_token_program(token_program)
-        .spl_ata_program(associated_token_program)
-        .sysvar_instructions(sysvar_instructions)
-        .mint_args(mint_args)
-        .invoke_signed(&[fvca_seeds])?;
-
-    VerifyCpi {
-        __program: token_metadata_program,
-        authority: &ctx.accounts.fvca,
-        delegate_record: None,
-        metadata: &ctx.accounts.metadata,
-        collection_mint: Some(&ctx.accounts.mcc),
-        collection_metadata: Some(&ctx.accounts.collection_metadata),
-        collection_master_edition: Some(&ctx.accounts.collection_master_edition),
-        system_program: &ctx.accounts.system_program,
-        sysvar_instructions: &ctx.accounts.sysvar_instructions,
-        __args: VerifyInstructionArgs {
-            verification_args: VerificationArgs::CollectionV1,
-        },
+    let clock = Clock::get()?;
+
+    // Each Solmap is priced by the mints before it, including those earlier in the run.
+    let mut slot_index = SlotIndex::load(slot_index_info)?;
+    let mut fee = 0;
+    for solmap_number in start..=last {
+        fee += season.mint_fee(clock.unix_timestamp);
+        claim_solmap(
+            config,
+            season,
+            &mut slot_index,
+            slot_index_info,
+            solmap_number,
+            &clock,
+            phase,
+        )?;
     }
-    .invoke_signed(&[&[b"fvca", &[ctx.bumps.fvca]]])?;
-
-    // Create inscription.
-    libreplex_inscriptions::cpi::create_inscription_v3(
-        CpiContext::new(
-            ctx.accounts.inscriptions_program.to_account_info(),
-            CreateInscriptionV3 {
-                /* the inscription root is set to metaplex
-                    inscription object.
-                */
-                inscription_summary: inscription_summary.to_account_info(),
-
-                root: mint.to_account_info(),
-                // since root in this case can sign (we are creating a brand new mint),
-                // it will sign
-                signer: mint.to_account_info(),
-                inscription_v3: inscription_v3.to_account_info(),
-
-                system_program: system_program.to_account_info(),
-                payer: ctx.accounts.minter.to_account_info(),
-                inscription_data: inscription_data.to_account_info(),
-            },
-        ),
-        libreplex_inscriptions::instructions::CreateInscriptionInputV3 {
-            authority: Some(minter.key()), // this includes update auth / holder, hence
-            signer_type: SignerType::Root,
-            validation_hash: None,
-        },
-    )?;
+    slot_index.save(slot_index_info)?;
+
+    pay_mint_fee(owner, fee_vault, fee)?;
+
+    reservation.owner = owner.key();
+    reservation.season = season.id;
+    reservation.start = start;
+    reservation.count = count;
+    reservation.finalized = 0;
+    reservation.fee = fee;
+    reservation.reserved_slot = clock.slot;
+    reservation.expiry_slot = clock.slot + config.reservation_expiry_slots;
+    reservation.bump = bump;
+
+    emit!(SolmapsReserved {
+        start,
+        count,
+        owner: reservation.owner,
+        slot: clock.slot,
+        expiry_slot: reservation.expiry_slot,
+        fee,
+    });
 
-    // Resize inscription data account.
-    libreplex_inscriptions::cpi::resize_inscription_v3(
-        CpiContext::new(
-            inscriptions_program.to_account_info(),
-            ResizeInscriptionV3 {
-                /* the inscription root is set to metaplex
-                 inscription object.
-                */
-                authority: minter.to_account_info(),
-
-                system_program: system_program.to_account_info(),
-                payer: minter.to_account_info(),
-                inscription_data: inscription_data.to_account_info(),
-                inscription_v3: inscription_v3.to_account_info(),
-            },
-        ),
-        libreplex_inscriptions::instructions::ResizeInscriptionInput {
-            change: solmap_bytes.len() as i32 - 8,
-            expected_start_size: 8,
-            target_size: solmap_bytes.len() as u32,
-        },
-    )?;
+    Ok(())
+}
 
-    // Write inscription data.
-    libreplex_inscriptions::cpi::write_to_inscription_v3(
-        CpiContext::new(
-            inscriptions_program.to_account_info(),
-            WriteToInscriptionV3 {
-                authority: minter.to_account_info(),
-                payer: minter.to_account_info(),
-                inscription_v3: inscription_v3.to_account_info(),
-                system_program: system_program.to_account_info(),
-                inscription_data: inscription_data.to_account_info(),
-            },
-        ),
-        WriteToInscriptionInput {
-            data: solmap_bytes.to_vec(),
-            start_pos: 0,
-            media_type: Some("text/plain".to_owned()),
-            encoding_type: Some("ascii".to_owned()),
-        },
+/// Pays the community grant fee, or part of it, into a fee vault or to a referrer.
+fn pay_mint_fee<'info>(
+    payer: &AccountInfo<'info>,
+    fee_vault: &AccountInfo<'info>,
+    fee: u64,
+) -> Result<()> {
+    // Pay community treasury to fund project grants. Fees are swept to the treasury later.
+    invoke(
+        &system_instruction::transfer(payer.key, fee_vault.key, fee),
+        &[payer.clone(), fee_vault.clone()],
     )?;
 
-    // Make inscription immutable.
-    libreplex_inscriptions::cpi::make_inscription_immutable_v3(CpiContext::new(
-        inscriptions_program.to_account_info(),
-        MakeInscriptionImmutableV3 {
-            payer: minter.to_account_info(),
-            authority: minter.to_account_info(),
-            inscription_summary: inscription_summary.to_account_info(),
-            inscription_v3: inscription_v3.to_account_info(),
-            system_program: system_program.to_account_info(),
-        },
-    ))?;
+    Ok(())
+}
 
-    // Pay community treasury to fund project grants.
-    invoke(
-        &system_instruction::transfer(
-            ctx.accounts.minter.key,
-            ctx.accounts.treasury.key,
-            COMMUNITY_GRANT_FEE,
-        ),
+/// Records the minted Solmap and emits `SolmapMinted`.
+fn complete_mint<'info>(
+    payer: &AccountInfo<'info>,
+    solmap_record: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fee: u64,
+    fee_mint: Option<Pubkey>,
+    record: SolmapRecord,
+) -> Result<()> {
+    create_solmap_record(solmap_record, payer, system_program, record.clone())?;
+
+    emit!(SolmapMinted {
+        solmap_number: record.solmap_number,
+        mint: record.mint,
+        owner: record.owner,
+        slot: record.slot,
+        timestamp: record.timestamp,
+        fee,
+        fee_mint,
+    });
+
+    Ok(())
+}
+
+// Records are created by hand rather than with `init` so that minting a taken number
+// fails on the slot index check with `SolmapAlreadyMinted`.
+fn create_solmap_record<'info>(
+    record_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    record: SolmapRecord,
+) -> Result<()> {
+    create_or_allocate_account_raw(
+        crate::ID,
+        record_info,
+        system_program,
+        payer,
+        8 + SolmapRecord::INIT_SPACE,
         &[
-            ctx.accounts.minter.to_account_info(),
-            ctx.accounts.treasury.to_account_info(),
+            b"solmap",
+            &record.solmap_number.to_le_bytes(),
+            &[record.bump],
         ],
     )?;
 
+    record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+
     Ok(())
 }
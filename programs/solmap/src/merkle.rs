@@ -0,0 +1,26 @@
+//! Merkle tree of the allowlist a season's presale is checked against.
+//!
+//! Leaves and inner nodes are hashed with different prefixes so an inner node can't be
+//! passed off as a leaf, and pairs are sorted before hashing so proofs don't need to say
+//! which side each sibling is on.
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+/// Leaf of the allowlist entry letting `wallet` mint up to `quota` Solmaps.
+pub fn allowlist_leaf(wallet: &Pubkey, quota: u32) -> [u8; 32] {
+    hashv(&[&[0], wallet.as_ref(), &quota.to_le_bytes()]).to_bytes()
+}
+
+/// Parent node of two nodes of the allowlist tree.
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+
+    hashv(&[&[1], left, right]).to_bytes()
+}
+
+/// Whether `proof` leads from `leaf` to `root`.
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling))
+        == *root
+}
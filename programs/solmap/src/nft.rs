@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use libreplex_inscriptions::{
+    cpi::accounts::{
+        CreateInscriptionV3, MakeInscriptionImmutableV3, ResizeInscriptionV3, WriteToInscriptionV3,
+    },
+    instructions::{SignerType, WriteToInscriptionInput},
+};
+use mpl_token_metadata::{
+    instructions::{CreateCpiBuilder, MintCpiBuilder, VerifyCpi, VerifyInstructionArgs},
+    types::{
+        Collection, CreateArgs, Creator, MintArgs, PrintSupply, TokenStandard, VerificationArgs,
+    },
+};
+
+/// Accounts needed to create a Solmap NFT and its inscription.
+pub struct SolmapNftAccounts<'a, 'info> {
+    /// Pays for the new accounts and signs for the inscription until it is immutable.
+    pub minter: &'a AccountInfo<'info>,
+    /// Wallet that receives the NFT, and with it the inscription rooted in its mint.
+    pub owner: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub token_account: &'a AccountInfo<'info>,
+    pub metadata: &'a AccountInfo<'info>,
+    pub master_edition: &'a AccountInfo<'info>,
+    pub fvca: &'a AccountInfo<'info>,
+    pub mcc: &'a AccountInfo<'info>,
+    pub collection_metadata: &'a AccountInfo<'info>,
+    pub collection_master_edition: &'a AccountInfo<'info>,
+    pub inscription_v3: &'a AccountInfo<'info>,
+    pub inscription_data: &'a AccountInfo<'info>,
+    pub inscription_summary: &'a AccountInfo<'info>,
+    pub inscriptions_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub sysvar_instructions: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+/// Creates the metadata, master edition and token of a Solmap NFT, verifies it into the
+/// collection and writes its immutable inscription.
+///
+/// `mint_seeds` are the signer seeds of the mint when it is a PDA rather than a keypair
+/// that signed the transaction.
+pub fn create_solmap_nft(
+    accounts: SolmapNftAccounts,
+    solmap_number: u64,
+    uri: &str,
+    fvca_bump: u8,
+    mint_seeds: Option<&[&[u8]]>,
+) -> Result<()> {
+    let SolmapNftAccounts {
+        minter,
+        owner,
+        mint,
+        token_account,
+        metadata,
+        master_edition,
+        fvca,
+        mcc,
+        collection_metadata,
+        collection_master_edition,
+        inscription_v3,
+        inscription_data,
+        inscription_summary,
+        inscriptions_program,
+        system_program,
+        sysvar_instructions,
+        token_metadata_program,
+        token_program,
+        associated_token_program,
+    } = accounts;
+
+    let solmap_string = format!("{solmap_number}.solmap");
+    let solmap_bytes = solmap_string.as_bytes();
+
+    let fvca_seeds: &[&[u8]] = &[b"fvca".as_ref(), &[fvca_bump]];
+    let mut create_signers = vec![fvca_seeds];
+    create_signers.extend(mint_seeds);
+    let mint_signers: Vec<&[&[u8]]> = mint_seeds.into_iter().collect();
+
+    // Create mint and ATA
+    let create_args = CreateArgs::V1 {
+        name: solmap_string.clone(),
+        symbol: "SOLMAP".to_string(),
+        uri: uri.to_string(),
+        seller_fee_basis_points: 0,
+        creators: Some(vec![Creator {
+            address: fvca.key(),
+            verified: true,
+            share: 100,
+        }]),
+        primary_sale_happened: true,
+        is_mutable: true,
+        token_standard: TokenStandard::NonFungible,
+        collection: Some(Collection {
+            key: mcc.key(),
+            verified: false, // need to verify in a separate instruction
+        }),
+        uses: None,
+        collection_details: None,
+        rule_set: None,
+        decimals: None,
+        print_supply: Some(PrintSupply::Zero),
+    };
+
+    let mut create_builder = CreateCpiBuilder::new(token_metadata_program);
+    create_builder
+        .payer(minter)
+        .metadata(metadata)
+        .master_edition(Some(master_edition))
+        .mint(mint, true)
+        .authority(minter)
+        .update_authority(fvca, true)
+        .system_program(system_program)
+        .spl_token_program(Some(token_program))
+        .sysvar_instructions(sysvar_instructions)
+        .create_args(create_args)
+        .invoke_signed(&create_signers)?;
+
+    // Mint token.
+    let mint_args = MintArgs::V1 {
+        amount: 1,
+        authorization_data: None,
+    };
+
+    let mut mint_builder = MintCpiBuilder::new(token_metadata_program);
+    mint_builder
+        .token(token_account)
+        .token_owner(Some(owner))
+        .metadata(metadata)
+        .master_edition(Some(master_edition))
+        .mint(mint)
+        .authority(fvca)
+        .payer(minter)
+        .system_program(system_program)
+        .spl_token_program(token_program)
+        .spl_ata_program(associated_token_program)
+        .sysvar_instructions(sysvar_instructions)
+        .mint_args(mint_args)
+        .invoke_signed(&[fvca_seeds])?;
+
+    VerifyCpi {
+        __program: token_metadata_program,
+        authority: fvca,
+        delegate_record: None,
+        metadata,
+        collection_mint: Some(mcc),
+        collection_metadata: Some(collection_metadata),
+        collection_master_edition: Some(collection_master_edition),
+        system_program,
+        sysvar_instructions,
+        __args: VerifyInstructionArgs {
+            verification_args: VerificationArgs::CollectionV1,
+        },
+    }
+    .invoke_signed(&[fvca_seeds])?;
+
+    // Create inscription.
+    libreplex_inscriptions::cpi::create_inscription_v3(
+        CpiContext::new_with_signer(
+            inscriptions_program.to_account_info(),
+            CreateInscriptionV3 {
+                /* the inscription root is set to metaplex
+                    inscription object.
+                */
+                inscription_summary: inscription_summary.to_account_info(),
+
+                root: mint.to_account_info(),
+                // since root in this case can sign (we are creating a brand new mint),
+                // it will sign
+                signer: mint.to_account_info(),
+                inscription_v3: inscription_v3.to_account_info(),
+
+                system_program: system_program.to_account_info(),
+                payer: minter.to_account_info(),
+                inscription_data: inscription_data.to_account_info(),
+            },
+            &mint_signers,
+        ),
+        libreplex_inscriptions::instructions::CreateInscriptionInputV3 {
+            authority: Some(minter.key()), // this includes update auth / holder, hence
+            signer_type: SignerType::Root,
+            validation_hash: None,
+        },
+    )?;
+
+    // Resize inscription data account.
+    libreplex_inscriptions::cpi::resize_inscription_v3(
+        CpiContext::new(
+            inscriptions_program.to_account_info(),
+            ResizeInscriptionV3 {
+                /* the inscription root is set to metaplex
+                 inscription object.
+                */
+                authority: minter.to_account_info(),
+
+                system_program: system_program.to_account_info(),
+                payer: minter.to_account_info(),
+                inscription_data: inscription_data.to_account_info(),
+                inscription_v3: inscription_v3.to_account_info(),
+            },
+        ),
+        libreplex_inscriptions::instructions::ResizeInscriptionInput {
+            change: solmap_bytes.len() as i32 - 8,
+            expected_start_size: 8,
+            target_size: solmap_bytes.len() as u32,
+        },
+    )?;
+
+    // Write inscription data.
+    libreplex_inscriptions::cpi::write_to_inscription_v3(
+        CpiContext::new(
+            inscriptions_program.to_account_info(),
+            WriteToInscriptionV3 {
+                authority: minter.to_account_info(),
+                payer: minter.to_account_info(),
+                inscription_v3: inscription_v3.to_account_info(),
+                system_program: system_program.to_account_info(),
+                inscription_data: inscription_data.to_account_info(),
+            },
+        ),
+        WriteToInscriptionInput {
+            data: solmap_bytes.to_vec(),
+            start_pos: 0,
+            media_type: Some("text/plain".to_owned()),
+            encoding_type: Some("ascii".to_owned()),
+        },
+    )?;
+
+    // Make inscription immutable.
+    libreplex_inscriptions::cpi::make_inscription_immutable_v3(CpiContext::new(
+        inscriptions_program.to_account_info(),
+        MakeInscriptionImmutableV3 {
+            payer: minter.to_account_info(),
+            authority: minter.to_account_info(),
+            inscription_summary: inscription_summary.to_account_info(),
+            inscription_v3: inscription_v3.to_account_info(),
+            system_program: system_program.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,1066 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+use bitvec::prelude::*;
+
+use crate::error::SolmapError;
+
+pub const MAX_URI_LENGTH: usize = 200;
+
+/// Size in bytes of each slot index page bitmap.
+pub const SLOT_INDEX_PAGE_SIZE: usize = 2048;
+
+/// Number of Solmaps tracked by each slot index page.
+pub const SLOT_INDEX_PAGE_BITS: u64 = SLOT_INDEX_PAGE_SIZE as u64 * 8;
+
+/// Number of fee vaults mint fees are spread across.
+pub const FEE_VAULT_SHARDS: u8 = 16;
+
+/// Slot index page that tracks a Solmap number.
+pub fn slot_index_page(solmap_number: u64) -> u32 {
+    (solmap_number / SLOT_INDEX_PAGE_BITS) as u32
+}
+
+/// Address of slot index page `page`.
+pub fn find_slot_index_page_address(page: u32) -> Pubkey {
+    Pubkey::find_program_address(&[b"slot_index", &page.to_le_bytes()], &crate::ID).0
+}
+
+/// Whether all 1,000 slots of a Solmap are behind `slot`. Numbers whose slots would be
+/// past `u64::MAX` never are.
+pub fn solmap_slot_exists(solmap_number: u64, slot: u64) -> bool {
+    matches!(
+        solmap_number.checked_add(1).and_then(|n| n.checked_mul(1000)),
+        Some(end) if end <= slot
+    )
+}
+
+/// Bit position of a Solmap number within its slot index page.
+pub fn slot_index_offset(solmap_number: u64) -> usize {
+    (solmap_number % SLOT_INDEX_PAGE_BITS) as usize
+}
+
+/// Current layout version of slot index pages. Version 2 added the held bitmap in front
+/// of the page bitmap.
+pub const SLOT_INDEX_VERSION: u8 = 2;
+
+/// Header of a slot index page. The held bitmap and then the page bitmap are stored
+/// after it in the same account. In the page bitmap 1 means minted and 0 means not
+/// minted, in the held bitmap 1 means held back for a claimant.
+#[account]
+#[derive(InitSpace)]
+pub struct SlotIndex {
+    pub version: u8,
+
+    pub page: u32,
+
+    /// Number of Solmaps minted in this page.
+    pub minted: u32,
+
+    /// Number of Solmaps the page bitmap can track.
+    pub capacity: u32,
+
+    /// Slot of the most recent mint in this page.
+    pub last_minted_slot: u64,
+
+    pub bump: u8,
+}
+
+impl SlotIndex {
+    /// Offset of the held bitmap in the account data. It always covers the whole page, so
+    /// numbers can be held before the page bitmap grows to fit them.
+    pub const HELD_BITMAP_OFFSET: usize = 8 + SlotIndex::INIT_SPACE;
+
+    /// Offset of the page bitmap in the account data.
+    pub const BITMAP_OFFSET: usize = Self::HELD_BITMAP_OFFSET + SLOT_INDEX_PAGE_SIZE;
+
+    pub const LEN: usize = Self::BITMAP_OFFSET + SLOT_INDEX_PAGE_SIZE;
+
+    /// Account size needed for the page bitmap to track `solmap_number`.
+    pub fn len_for(solmap_number: u64) -> usize {
+        Self::BITMAP_OFFSET + slot_index_offset(solmap_number) / 8 + 1
+    }
+
+    /// Number of Solmaps a page account of `len` bytes can track.
+    pub fn capacity_for(len: usize) -> u32 {
+        (len.saturating_sub(Self::BITMAP_OFFSET) * 8) as u32
+    }
+
+    /// Reads the header of the page held by `info`. A page that was never created
+    /// can't track any Solmap yet.
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        if info.data_is_empty() {
+            return Err(SolmapError::SolmapBeyondIndexCapacity.into());
+        }
+        if info.owner != &crate::ID {
+            return Err(SolmapError::SlotIndexNotOwned.into());
+        }
+
+        let header = Self::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        if header.version != SLOT_INDEX_VERSION {
+            return Err(SolmapError::SlotIndexVersionMismatch.into());
+        }
+
+        Ok(header)
+    }
+
+    /// Writes the header back to the page held by `info`.
+    pub fn save(&self, info: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+    }
+
+    /// Marks a Solmap as minted in the page bitmap held by `info`. Held Solmaps can only
+    /// be minted with `claim_held`.
+    pub fn set_minted(&mut self, info: &AccountInfo, solmap_number: u64, slot: u64) -> Result<()> {
+        if self.is_held(info, solmap_number)? {
+            return Err(SolmapError::SolmapReserved.into());
+        }
+
+        self.mark_minted(info, solmap_number, slot)
+    }
+
+    /// Mints a held Solmap for its claimant, clearing its bit in the held bitmap.
+    pub fn claim_held(&mut self, info: &AccountInfo, solmap_number: u64, slot: u64) -> Result<()> {
+        self.clear_held(info, solmap_number)?;
+
+        self.mark_minted(info, solmap_number, slot)
+    }
+
+    fn mark_minted(&mut self, info: &AccountInfo, solmap_number: u64, slot: u64) -> Result<()> {
+        let offset = slot_index_offset(solmap_number);
+        if offset >= self.capacity as usize {
+            return Err(SolmapError::SolmapBeyondIndexCapacity.into());
+        }
+
+        let mut data = info.try_borrow_mut_data()?;
+        let bits = data[Self::BITMAP_OFFSET..].view_bits_mut::<Lsb0>();
+
+        let mut bit = bits
+            .get_mut(offset)
+            .ok_or(SolmapError::SolmapBeyondIndexCapacity)?;
+        if *bit {
+            return Err(SolmapError::SolmapAlreadyMinted.into());
+        }
+        *bit = true;
+
+        self.minted += 1;
+        self.last_minted_slot = slot;
+
+        Ok(())
+    }
+
+    /// Holds a Solmap back from every public mint path. It isn't counted as minted until
+    /// it is claimed.
+    pub fn hold(&self, info: &AccountInfo, solmap_number: u64) -> Result<()> {
+        if self.is_minted(info, solmap_number)? {
+            return Err(SolmapError::SolmapAlreadyMinted.into());
+        }
+
+        let mut data = info.try_borrow_mut_data()?;
+        let mut bit = Self::held_bits_mut(&mut data)
+            .get_mut(slot_index_offset(solmap_number))
+            .unwrap();
+        if *bit {
+            return Err(SolmapError::SolmapReserved.into());
+        }
+        *bit = true;
+
+        Ok(())
+    }
+
+    /// Gives a held Solmap back to the public mint.
+    pub fn clear_held(&self, info: &AccountInfo, solmap_number: u64) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut bit = Self::held_bits_mut(&mut data)
+            .get_mut(slot_index_offset(solmap_number))
+            .unwrap();
+        if !*bit {
+            return Err(SolmapError::SolmapNotReserved.into());
+        }
+        *bit = false;
+
+        Ok(())
+    }
+
+    /// Whether a Solmap is held back for a claimant in the held bitmap of `info`.
+    pub fn is_held(&self, info: &AccountInfo, solmap_number: u64) -> Result<bool> {
+        let data = info.try_borrow_data()?;
+
+        Ok(Self::held_bits(&data)[slot_index_offset(solmap_number)])
+    }
+
+    fn held_bits(data: &[u8]) -> &BitSlice<u8, Lsb0> {
+        data[Self::HELD_BITMAP_OFFSET..Self::BITMAP_OFFSET].view_bits::<Lsb0>()
+    }
+
+    fn held_bits_mut(data: &mut [u8]) -> &mut BitSlice<u8, Lsb0> {
+        data[Self::HELD_BITMAP_OFFSET..Self::BITMAP_OFFSET].view_bits_mut::<Lsb0>()
+    }
+
+    /// Marks a Solmap as not minted again, releasing it for minting.
+    pub fn clear_minted(&mut self, info: &AccountInfo, solmap_number: u64) -> Result<()> {
+        let offset = slot_index_offset(solmap_number);
+
+        let mut data = info.try_borrow_mut_data()?;
+        let bits = data[Self::BITMAP_OFFSET..].view_bits_mut::<Lsb0>();
+
+        let mut bit = bits
+            .get_mut(offset)
+            .ok_or(SolmapError::SolmapBeyondIndexCapacity)?;
+        if !*bit {
+            return Err(SolmapError::SolmapNotMinted.into());
+        }
+        *bit = false;
+
+        self.minted -= 1;
+
+        Ok(())
+    }
+
+    /// First Solmap from `start` up to `end` that is neither minted nor held in the
+    /// bitmaps of the account `data`, wrapping around to `first` once `end` is reached.
+    /// The search is limited to the numbers tracked by this page.
+    pub fn find_unminted(&self, data: &[u8], first: u64, start: u64, end: u64) -> Option<u64> {
+        let page_start = self.page as u64 * SLOT_INDEX_PAGE_BITS;
+        let first = first.max(page_start);
+        let end = end.min(page_start + self.capacity as u64);
+        if start < first || start >= end {
+            return None;
+        }
+
+        let bits = data[Self::BITMAP_OFFSET..].view_bits::<Lsb0>();
+        let held = Self::held_bits(data);
+        let unminted = |from: u64, to: u64| {
+            let mut from = from;
+            while from < to {
+                let offset = slot_index_offset(from);
+                let i = bits[offset..offset + (to - from) as usize].first_zero()?;
+                if !held[offset + i] {
+                    return Some(from + i as u64);
+                }
+                from += i as u64 + 1;
+            }
+
+            None
+        };
+
+        unminted(start, end).or_else(|| unminted(first, start))
+    }
+
+    /// Whether a Solmap is marked as minted in the page bitmap held by `info`.
+    pub fn is_minted(&self, info: &AccountInfo, solmap_number: u64) -> Result<bool> {
+        let offset = slot_index_offset(solmap_number);
+        if offset >= self.capacity as usize {
+            return Ok(false);
+        }
+
+        let data = info.try_borrow_data()?;
+        let bits = data[Self::BITMAP_OFFSET..].view_bits::<Lsb0>();
+
+        Ok(bits.get(offset).map(|bit| *bit).unwrap_or(false))
+    }
+}
+
+/// Reverse lookup from a Solmap number to its NFT.
+#[account]
+#[derive(InitSpace)]
+pub struct SolmapRecord {
+    pub solmap_number: u64,
+
+    pub mint: Pubkey,
+
+    /// Wallet the Solmap was minted to.
+    pub owner: Pubkey,
+
+    /// Wallet that paid for the mint and its fee.
+    pub payer: Pubkey,
+
+    /// Slot the Solmap was minted in.
+    pub slot: u64,
+
+    pub timestamp: i64,
+
+    /// Inscription v3 account holding the Solmap inscription.
+    pub inscription: Pubkey,
+
+    pub bump: u8,
+}
+
+/// Mint details of a Solmap minted before records existed, taken from its mint transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BackfillRecordArgs {
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+/// Maximum number of adjacent Solmaps one reservation can hold.
+pub const MAX_RESERVATION_SIZE: u8 = 64;
+
+/// A run of adjacent Solmaps claimed in the slot index whose NFTs are created one by one
+/// with `finalize_solmap`.
+#[account]
+#[derive(InitSpace)]
+pub struct Reservation {
+    /// Wallet that reserved the Solmaps and receives their NFTs.
+    pub owner: Pubkey,
+
+    /// Season the Solmaps were reserved in, its URI is set on the NFTs.
+    pub season: u16,
+
+    /// First reserved Solmap number.
+    pub start: u64,
+
+    pub count: u8,
+
+    /// Bit `i` is set once Solmap `start + i` is finalized.
+    pub finalized: u64,
+
+    /// Community grant fee paid for the whole run when reserving, or the winning bid of an
+    /// auctioned Solmap.
+    pub fee: u64,
+
+    /// Slot the Solmaps were reserved in.
+    pub reserved_slot: u64,
+
+    /// Slot from which Solmaps that are not finalized yet can be released.
+    pub expiry_slot: u64,
+
+    pub bump: u8,
+}
+
+impl Reservation {
+    pub fn contains(&self, solmap_number: u64) -> bool {
+        (self.start..self.start + self.count as u64).contains(&solmap_number)
+    }
+
+    pub fn is_finalized(&self, solmap_number: u64) -> bool {
+        self.finalized & (1 << (solmap_number - self.start)) != 0
+    }
+
+    pub fn set_finalized(&mut self, solmap_number: u64) {
+        self.finalized |= 1 << (solmap_number - self.start);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.finalized.count_ones() == self.count as u32
+    }
+
+    /// Part of `fee` reported for one Solmap of the run. The remainder of an even split
+    /// goes to the first Solmaps, so the parts add up to the fee.
+    pub fn fee_share(&self, solmap_number: u64) -> u64 {
+        let count = self.count as u64;
+        let offset = solmap_number - self.start;
+        self.fee / count + u64::from(offset < self.fee % count)
+    }
+
+    pub fn is_expired(&self, slot: u64) -> bool {
+        slot >= self.expiry_slot
+    }
+
+    /// Whether the admin held the Solmaps for a claimant with `hold_solmaps`. Held
+    /// reservations never expire.
+    pub fn is_held(&self) -> bool {
+        self.expiry_slot == u64::MAX
+    }
+}
+
+/// A hidden bid for a Solmap number, revealed with `reveal_solmap` once
+/// `Config::reveal_delay_slots` have passed or closed with `cancel_commitment`.
+#[account]
+#[derive(InitSpace)]
+pub struct Commitment {
+    pub owner: Pubkey,
+
+    /// `commitment_hash` of the Solmap number, a salt and the owner.
+    pub hash: [u8; 32],
+
+    /// Slot the commitment was made in.
+    pub committed_slot: u64,
+
+    pub bump: u8,
+}
+
+impl Commitment {
+    pub fn can_reveal(&self, slot: u64, reveal_delay_slots: u64) -> bool {
+        slot >= self.committed_slot + reveal_delay_slots
+    }
+}
+
+/// How a premium Solmap is sold at auction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AuctionKind {
+    /// Price declines linearly from `start_price` to the reserve price between the start
+    /// and end time. The first bid at the current price wins.
+    Dutch { start_price: u64 },
+    /// Bids start at the reserve price and must raise the highest bid by `min_increment`.
+    /// The highest bid at the end time wins.
+    English { min_increment: u64 },
+}
+
+/// Auction of a premium Solmap number. Its account holds the highest bid in escrow
+/// until the auction is settled.
+#[account]
+#[derive(InitSpace)]
+pub struct Auction {
+    pub solmap_number: u64,
+
+    /// Season the Solmap belongs to, its URI is set on the NFT.
+    pub season: u16,
+
+    pub kind: AuctionKind,
+
+    /// Lowest price in lamports the Solmap is sold for.
+    pub reserve_price: u64,
+
+    /// Unix timestamp from which bids are accepted.
+    pub start_time: i64,
+
+    /// Unix timestamp from which bids are rejected and the auction can be settled.
+    pub end_time: i64,
+
+    /// Highest bidder, refunded when outbid.
+    pub bidder: Option<Pubkey>,
+
+    /// Highest bid in lamports, held by this account.
+    pub bid: u64,
+
+    pub bump: u8,
+}
+
+impl Auction {
+    pub fn validate(&self) -> Result<()> {
+        let valid = self.start_time < self.end_time
+            && self.reserve_price > 0
+            && match self.kind {
+                AuctionKind::Dutch { start_price } => start_price >= self.reserve_price,
+                AuctionKind::English { min_increment } => min_increment > 0,
+            };
+        if !valid {
+            return Err(SolmapError::InvalidAuction.into());
+        }
+
+        Ok(())
+    }
+
+    /// Whether bids are accepted at unix timestamp `now`. A Dutch auction stops at its
+    /// first bid.
+    pub fn is_open(&self, now: i64) -> bool {
+        (self.start_time..self.end_time).contains(&now) && !self.is_sold()
+    }
+
+    /// Whether the auction can be settled at unix timestamp `now`.
+    pub fn is_over(&self, now: i64) -> bool {
+        now >= self.end_time || self.is_sold()
+    }
+
+    fn is_sold(&self) -> bool {
+        matches!(self.kind, AuctionKind::Dutch { .. }) && self.bidder.is_some()
+    }
+
+    /// Lowest bid accepted at unix timestamp `now`, which is also the price paid in a
+    /// Dutch auction.
+    pub fn min_bid(&self, now: i64) -> u64 {
+        match self.kind {
+            AuctionKind::Dutch { start_price } => {
+                let duration = self.end_time - self.start_time;
+                let remaining = self.end_time.saturating_sub(now).clamp(0, duration);
+                let decline = (start_price - self.reserve_price) as u128 * remaining as u128
+                    / duration as u128;
+
+                self.reserve_price + decline as u64
+            }
+            AuctionKind::English { min_increment } => match self.bidder {
+                Some(_) => self.bid.saturating_add(min_increment),
+                None => self.reserve_price,
+            },
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AuctionArgs {
+    pub kind: AuctionKind,
+    pub reserve_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+/// Hash committed to before revealing a Solmap number.
+pub fn commitment_hash(solmap_number: u64, salt: &[u8; 32], wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[&solmap_number.to_le_bytes(), salt, wallet.as_ref()]).to_bytes()
+}
+
+/// Fee vault that receives the fee for a Solmap number.
+pub fn fee_vault_shard(solmap_number: u64) -> u8 {
+    (solmap_number % FEE_VAULT_SHARDS as u64) as u8
+}
+
+/// Fee vault that receives the fee of a random draw, whose number isn't known before the
+/// draw runs.
+pub fn random_draw_fee_vault_shard(owner: &Pubkey) -> u8 {
+    owner.to_bytes()[0] % FEE_VAULT_SHARDS
+}
+
+/// Slot and hash of the newest entry of the SlotHashes sysvar `data`, which holds the
+/// number of entries, then (slot, hash) pairs from newest to oldest.
+pub fn newest_slot_hash(data: &[u8]) -> Option<(u64, &[u8])> {
+    if data.get(..8)? == 0u64.to_le_bytes() {
+        return None;
+    }
+
+    let entry = data.get(8..48)?;
+    Some((
+        u64::from_le_bytes(entry[..8].try_into().unwrap()),
+        &entry[8..],
+    ))
+}
+
+/// Number a random draw among the Solmaps in `first..end` starts from. The slot hash is
+/// mixed with the minter so wallets drawing in the same slot start apart.
+pub fn random_draw_start(first: u64, end: u64, slot_hash: &[u8], minter: &Pubkey) -> u64 {
+    let seed = hashv(&[slot_hash, minter.as_ref()]).to_bytes();
+
+    first + u64::from_le_bytes(seed[..8].try_into().unwrap()) % (end - first)
+}
+
+/// Runs of Solmaps a random draw from `start` scans in order, from `start` up to `end`
+/// and then from `first` up to `start`, split so each run is tracked by one slot index
+/// page.
+pub fn random_draw_runs(first: u64, start: u64, end: u64) -> Vec<(u64, u64)> {
+    let mut runs = Vec::new();
+    for (mut from, to) in [(start, end), (first, start)] {
+        while from < to {
+            let page_end = (slot_index_page(from) as u64 + 1) * SLOT_INDEX_PAGE_BITS;
+            runs.push((from, to.min(page_end)));
+            from = to.min(page_end);
+        }
+    }
+
+    runs
+}
+
+/// Maximum number of wallets swept fees can be split across.
+pub const MAX_FEE_RECIPIENTS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct FeeRecipient {
+    pub address: Pubkey,
+
+    /// Share of every sweep in basis points.
+    pub bps: u16,
+}
+
+/// How fee vaults are split when swept. Without it everything goes to the treasury.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSplit {
+    #[max_len(MAX_FEE_RECIPIENTS)]
+    pub recipients: Vec<FeeRecipient>,
+
+    pub bump: u8,
+}
+
+impl FeeSplit {
+    /// Checks the recipients are distinct and their shares add up to 100%.
+    pub fn validate(recipients: &[FeeRecipient]) -> Result<()> {
+        if recipients.is_empty() || recipients.len() > MAX_FEE_RECIPIENTS {
+            return Err(SolmapError::InvalidFeeSplit.into());
+        }
+        if (1..recipients.len()).any(|i| {
+            recipients[..i]
+                .iter()
+                .any(|r| r.address == recipients[i].address)
+        }) {
+            return Err(SolmapError::InvalidFeeSplit.into());
+        }
+        if recipients.iter().any(|r| r.bps == 0)
+            || recipients.iter().map(|r| r.bps as u32).sum::<u32>() != 10_000
+        {
+            return Err(SolmapError::InvalidFeeSplit.into());
+        }
+
+        Ok(())
+    }
+
+    /// Amount each recipient gets out of `amount`. Rounding dust goes to the first one.
+    pub fn shares(&self, amount: u64) -> Vec<u64> {
+        let mut shares: Vec<u64> = self
+            .recipients
+            .iter()
+            .map(|r| (amount as u128 * r.bps as u128 / 10_000) as u64)
+            .collect();
+        shares[0] += amount - shares.iter().sum::<u64>();
+
+        shares
+    }
+}
+
+/// SPL token accepted for the community grant fee, paid into the treasury's token account.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeToken {
+    pub mint: Pubkey,
+
+    /// Fee in the token's base units, paid instead of the season's lamport fee.
+    pub amount: u64,
+
+    pub enabled: bool,
+
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FeeTokenArgs {
+    pub amount: u64,
+    pub enabled: bool,
+}
+
+/// Mints referred by a wallet and the rewards it earned from them.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralStats {
+    pub referrer: Pubkey,
+
+    pub mints: u64,
+
+    /// Lamports paid to the referrer out of referred grant fees.
+    pub earned: u64,
+
+    pub bump: u8,
+}
+
+impl ReferralStats {
+    pub fn record(&mut self, referrer: Pubkey, reward: u64, bump: u8) {
+        self.referrer = referrer;
+        self.mints += 1;
+        self.earned += reward;
+        self.bump = bump;
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    /// Signs all admin instructions.
+    pub admin: Pubkey,
+
+    /// Receives the community grant fee.
+    pub treasury: Pubkey,
+
+    /// Metaplex collection every Solmap NFT is verified into.
+    pub mcc: Pubkey,
+
+    /// Whether any wallet may call `mint`.
+    pub public_mint_enabled: bool,
+
+    /// Admin proposed with `propose_authority`, waiting to accept.
+    pub pending_admin: Option<Pubkey>,
+
+    /// Unix timestamp from which `pending_admin` can accept.
+    pub pending_admin_eta: i64,
+
+    /// Seconds between proposing a new admin and the proposal taking effect.
+    pub admin_timelock: i64,
+
+    /// `admin_timelock` set with `update_config`, waiting for the current timelock.
+    pub pending_admin_timelock: Option<i64>,
+
+    /// Unix timestamp from which `pending_admin_timelock` takes effect.
+    pub pending_admin_timelock_eta: i64,
+
+    /// Slots a reservation has to be finalized in before anyone can release it.
+    pub reservation_expiry_slots: u64,
+
+    /// Slots between committing to a Solmap number and revealing it.
+    pub reveal_delay_slots: u64,
+
+    /// Whether Solmaps can only be claimed through `commit_solmap` and `reveal_solmap`,
+    /// so pending mints don't show which numbers are being taken.
+    pub commit_reveal_only: bool,
+
+    /// Share of the lamport grant fee paid to the referrer of a mint, in basis points.
+    pub referral_bps: u16,
+
+    /// End of the highest season range. New seasons start from here so their ranges
+    /// never overlap.
+    pub seasons_end: u64,
+
+    pub bump: u8,
+}
+
+impl Config {
+    /// Applies a pending `admin_timelock` once the timelock it replaces has passed.
+    pub fn settle_admin_timelock(&mut self, now: i64) {
+        if let Some(admin_timelock) = self.pending_admin_timelock {
+            if now >= self.pending_admin_timelock_eta {
+                self.admin_timelock = admin_timelock;
+                self.pending_admin_timelock = None;
+                self.pending_admin_timelock_eta = 0;
+            }
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfigArgs {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub mcc: Pubkey,
+    pub admin_timelock: i64,
+    pub reservation_expiry_slots: u64,
+    pub reveal_delay_slots: u64,
+}
+
+/// The admin is rotated with `propose_authority` and `accept_authority` instead. A new
+/// `admin_timelock` only takes effect after the current one, so it can't be used to skip
+/// the timelock of a rotation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct UpdateConfigArgs {
+    pub treasury: Option<Pubkey>,
+    pub mcc: Option<Pubkey>,
+    pub admin_timelock: Option<i64>,
+    pub reservation_expiry_slots: Option<u64>,
+    pub reveal_delay_slots: Option<u64>,
+    pub commit_reveal_only: Option<bool>,
+    pub referral_bps: Option<u16>,
+}
+
+/// A range of Solmap numbers that can be minted once the season is open.
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub id: u16,
+
+    /// First Solmap number in the season.
+    pub first_solmap: u64,
+
+    /// Solmap numbers must be below this value.
+    pub end_solmap: u64,
+
+    /// Unix timestamp before which minting is rejected.
+    pub start_time: i64,
+
+    /// Off-chain metadata URI set on every Solmap NFT minted in the season.
+    #[max_len(MAX_URI_LENGTH)]
+    pub uri: String,
+
+    /// Community grant fee in lamports paid on every mint, the base of `pricing`.
+    pub fee: u64,
+
+    pub pricing: PricingMode,
+
+    pub open: bool,
+
+    /// Root of the allowlist Merkle tree, see `merkle`. All zeros when the season has
+    /// no presale.
+    pub allowlist_root: [u8; 32],
+
+    /// Unix timestamp from which allowlisted wallets can mint.
+    pub allowlist_start_time: i64,
+
+    /// Most Solmaps a wallet can mint in the presale, 0 for no limit.
+    pub allowlist_wallet_limit: u32,
+
+    /// Most Solmaps a wallet can mint once the season is live, 0 for no limit.
+    pub public_wallet_limit: u32,
+
+    /// Solmaps minted or reserved in the season, which `Linear` and `Exponential` pricing
+    /// follow. Held and auctioned Solmaps are counted once they are claimed. Only mints
+    /// made while the pricing counts them are counted.
+    pub minted: u32,
+
+    pub bump: u8,
+}
+
+impl Season {
+    /// Community grant fee of the next mint in the season, at unix timestamp `now`.
+    pub fn mint_fee(&self, now: i64) -> u64 {
+        let minted = self.minted;
+        match self.pricing {
+            PricingMode::Flat => self.fee,
+            PricingMode::Linear { increment } => self
+                .fee
+                .saturating_add(increment.saturating_mul(minted as u64)),
+            PricingMode::Exponential { growth_bps, every } => {
+                grow_fee(self.fee, growth_bps, minted / every)
+            }
+            PricingMode::Dutch {
+                start_fee,
+                duration,
+            } => {
+                let end_time = self.start_time.saturating_add(duration);
+                let remaining = end_time.saturating_sub(now).clamp(0, duration);
+                let decline = (start_fee - self.fee) as u128 * remaining as u128 / duration as u128;
+
+                self.fee + decline as u64
+            }
+        }
+    }
+
+    /// End of the season numbers whose slot exists at `slot`.
+    pub fn mintable_end(&self, slot: u64) -> u64 {
+        self.end_solmap.min(slot / 1000)
+    }
+
+    pub fn wallet_limit(&self, phase: MintPhase) -> u32 {
+        match phase {
+            MintPhase::Allowlist => self.allowlist_wallet_limit,
+            MintPhase::Public => self.public_wallet_limit,
+        }
+    }
+
+    pub fn contains(&self, solmap_number: u64) -> bool {
+        (self.first_solmap..self.end_solmap).contains(&solmap_number)
+    }
+}
+
+/// How a season's community grant fee changes as Solmaps are minted or time passes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PricingMode {
+    /// `Season::fee` for every Solmap.
+    Flat,
+    /// `Season::fee` plus `increment` for every Solmap already minted.
+    Linear { increment: u64 },
+    /// `Season::fee` compounded by `growth_bps` for every `every` Solmaps already minted.
+    Exponential { growth_bps: u16, every: u32 },
+    /// Starts at `start_fee` when the season goes live and declines linearly to
+    /// `Season::fee` over `duration` seconds.
+    Dutch { start_fee: u64, duration: i64 },
+}
+
+impl PricingMode {
+    pub fn validate(&self, season: &Season) -> Result<()> {
+        let valid = match *self {
+            PricingMode::Flat | PricingMode::Linear { .. } => true,
+            PricingMode::Exponential { every, .. } => every > 0,
+            PricingMode::Dutch {
+                start_fee,
+                duration,
+            } => duration > 0 && start_fee >= season.fee,
+        };
+        if !valid {
+            return Err(SolmapError::InvalidPricing.into());
+        }
+
+        Ok(())
+    }
+
+    /// Whether the fee follows `Season::minted`. Mints only write the season account
+    /// under these modes, so mints of other seasons don't lock it.
+    pub fn counts_mints(&self) -> bool {
+        matches!(
+            self,
+            PricingMode::Linear { .. } | PricingMode::Exponential { .. }
+        )
+    }
+}
+
+/// Fixed point scale used to compound fees.
+const FEE_SCALE: u128 = 1_000_000_000_000;
+
+/// `fee` compounded `periods` times by `growth_bps`, saturating at `u64::MAX`.
+fn grow_fee(fee: u64, growth_bps: u16, periods: u32) -> u64 {
+    let mul = |a: u128, b: u128| a.checked_mul(b).map(|product| product / FEE_SCALE);
+
+    // Exponentiation by squaring keeps this to a few dozen multiplications.
+    let mut factor = Some(FEE_SCALE);
+    let mut base = Some(FEE_SCALE * (10_000 + growth_bps as u128) / 10_000);
+    let mut periods = periods;
+    while periods > 0 {
+        if periods & 1 == 1 {
+            factor = factor.zip(base).and_then(|(f, b)| mul(f, b));
+        }
+        periods >>= 1;
+        if periods > 0 {
+            base = base.and_then(|b| mul(b, b));
+        }
+    }
+
+    factor
+        .and_then(|factor| (fee as u128).checked_mul(factor))
+        .and_then(|grown| u64::try_from(grown / FEE_SCALE).ok())
+        .unwrap_or(u64::MAX)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllowlistArgs {
+    pub root: [u8; 32],
+    pub start_time: i64,
+}
+
+/// Phase of a season a Solmap is claimed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MintPhase {
+    /// Presale open to the wallets in the season's allowlist from `allowlist_start_time`.
+    Allowlist,
+    /// Open to any wallet from `start_time`, while public minting is enabled.
+    Public,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct WalletLimitArgs {
+    pub allowlist: u32,
+    pub public: u32,
+}
+
+/// Number of Solmaps a wallet has claimed in each phase of a season, including
+/// reserved ones that were released later.
+#[account]
+#[derive(InitSpace)]
+pub struct WalletMints {
+    pub season: u16,
+
+    pub wallet: Pubkey,
+
+    pub allowlist_minted: u32,
+
+    pub public_minted: u32,
+
+    pub bump: u8,
+}
+
+impl WalletMints {
+    pub fn minted(&self, phase: MintPhase) -> u32 {
+        match phase {
+            MintPhase::Allowlist => self.allowlist_minted,
+            MintPhase::Public => self.public_minted,
+        }
+    }
+
+    /// Counts `count` more Solmaps claimed by `wallet` in `phase`, up to the season's
+    /// wallet limit for the phase.
+    pub fn record(
+        &mut self,
+        season: &Season,
+        wallet: Pubkey,
+        phase: MintPhase,
+        count: u32,
+        bump: u8,
+    ) -> Result<()> {
+        let limit = season.wallet_limit(phase);
+        let minted = self.minted(phase).saturating_add(count);
+        if limit != 0 && minted > limit {
+            return Err(SolmapError::WalletMintLimitReached.into());
+        }
+
+        match phase {
+            MintPhase::Allowlist => self.allowlist_minted = minted,
+            MintPhase::Public => self.public_minted = minted,
+        }
+        self.season = season.id;
+        self.wallet = wallet;
+        self.bump = bump;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SeasonArgs {
+    pub first_solmap: u64,
+    pub end_solmap: u64,
+    pub start_time: i64,
+    pub uri: String,
+    pub fee: u64,
+}
+
+/// Maximum number of members in the governance admin set.
+pub const MAX_GOVERNANCE_MEMBERS: usize = 10;
+
+/// Maximum number of accounts a proposed instruction can take.
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 24;
+
+/// Maximum length of the data of a proposed instruction.
+pub const MAX_PROPOSAL_DATA_LENGTH: usize = 512;
+
+/// M-of-N admin set. Once created it becomes `Config::admin`, so admin instructions only
+/// run through proposals approved by `threshold` members.
+#[account]
+#[derive(InitSpace)]
+pub struct Governance {
+    #[max_len(MAX_GOVERNANCE_MEMBERS)]
+    pub members: Vec<Pubkey>,
+
+    /// Number of member approvals a proposal needs before it can be executed.
+    pub threshold: u8,
+
+    /// Id of the next proposal.
+    pub proposal_count: u64,
+
+    pub bump: u8,
+}
+
+impl Governance {
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    /// Checks the admin set is not empty and its threshold can be met.
+    pub fn validate(members: &[Pubkey], threshold: u8) -> Result<()> {
+        if members.is_empty() || members.len() > MAX_GOVERNANCE_MEMBERS {
+            return Err(SolmapError::InvalidGovernanceMembers.into());
+        }
+        if (1..members.len()).any(|i| members[..i].contains(&members[i])) {
+            return Err(SolmapError::InvalidGovernanceMembers.into());
+        }
+        if threshold == 0 || threshold as usize > members.len() {
+            return Err(SolmapError::InvalidGovernanceThreshold.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GovernanceArgs {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// An admin instruction of this program waiting for member approvals.
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub id: u64,
+
+    pub proposer: Pubkey,
+
+    /// Accounts of the proposed instruction. The governance account signs it.
+    #[max_len(MAX_PROPOSAL_ACCOUNTS)]
+    pub accounts: Vec<ProposalAccount>,
+
+    /// Data of the proposed instruction, including its discriminator.
+    #[max_len(MAX_PROPOSAL_DATA_LENGTH)]
+    pub data: Vec<u8>,
+
+    /// Members that approved the proposal.
+    #[max_len(MAX_GOVERNANCE_MEMBERS)]
+    pub approvals: Vec<Pubkey>,
+
+    pub executed: bool,
+
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Number of approvals from wallets that are still members.
+    pub fn approval_count(&self, governance: &Governance) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| governance.is_member(approver))
+            .count()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposalArgs {
+    pub accounts: Vec<ProposalAccount>,
+    pub data: Vec<u8>,
+}
@@ -1,3 +1,5 @@
+use crate::error::SolmapError;
+use mpl_token_metadata::accounts::Metadata;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -9,6 +11,39 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
+/// Checks that the first creator is the verified FVCA every Solmap is created with.
+pub fn verify_solmap_creator(metadata: &Metadata, fvca: &Pubkey) -> anchor_lang::Result<()> {
+    let first_creator = metadata.creators.as_ref().and_then(|c| c.first());
+
+    match first_creator {
+        Some(creator) if creator.address == *fvca && creator.verified => Ok(()),
+        _ => Err(SolmapError::InvalidSolmapNFT.into()),
+    }
+}
+
+/// Checks that the NFT is verified into the Solmap collection.
+pub fn verify_solmap_collection(metadata: &Metadata, mcc: &Pubkey) -> anchor_lang::Result<()> {
+    match &metadata.collection {
+        Some(collection) if collection.key == *mcc && collection.verified => Ok(()),
+        _ => Err(SolmapError::InvalidSolmapNFT.into()),
+    }
+}
+
+/// Parses a Solmap name such as `12345.solmap`, ignoring the null padding Token Metadata
+/// adds. Numbers with leading zeros are rejected so every Solmap has a single name.
+pub fn parse_solmap_name(name: &str) -> Option<u64> {
+    let number = name.trim_end_matches('\0').strip_suffix(".solmap")?;
+
+    if number.is_empty()
+        || !number.bytes().all(|b| b.is_ascii_digit())
+        || (number.len() > 1 && number.starts_with('0'))
+    {
+        return None;
+    }
+
+    number.parse().ok()
+}
+
 pub fn resize_or_reallocate_account_raw<'a>(
     target_account: &AccountInfo<'a>,
     funding_account: &AccountInfo<'a>,
@@ -0,0 +1,43 @@
+//! Helpers for programs that check Solmaps on-chain through CPI.
+//!
+//! ```ignore
+//! let solmap_number = solmap::verify::verify_solmap(
+//!     ctx.accounts.solmap_program.to_account_info(),
+//!     solmap::cpi::accounts::VerifySolmap {
+//!         config: ctx.accounts.solmap_config.to_account_info(),
+//!         mint: ctx.accounts.mint.to_account_info(),
+//!         metadata: ctx.accounts.metadata.to_account_info(),
+//!         fvca: ctx.accounts.solmap_fvca.to_account_info(),
+//!     },
+//! )?;
+//! ```
+use anchor_lang::prelude::*;
+use mpl_token_metadata::accounts::Metadata;
+
+use crate::cpi::accounts::VerifySolmap;
+
+/// Address of the Solmap config account.
+pub fn find_config_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"config"], &crate::ID).0
+}
+
+/// Address of the FVCA, the verified creator and update authority of every Solmap.
+pub fn find_fvca_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"fvca"], &crate::ID).0
+}
+
+/// Address of the Token Metadata account of `mint`.
+pub fn find_metadata_address(mint: &Pubkey) -> Pubkey {
+    Metadata::find_pda(mint).0
+}
+
+/// Checks that `accounts.mint` is a genuine Solmap and returns its number. Fails with
+/// `SolmapError::InvalidSolmapNFT` otherwise.
+pub fn verify_solmap<'info>(
+    solmap_program: AccountInfo<'info>,
+    accounts: VerifySolmap<'info>,
+) -> Result<u64> {
+    let solmap_number = crate::cpi::verify_solmap(CpiContext::new(solmap_program, accounts))?.get();
+
+    Ok(solmap_number)
+}
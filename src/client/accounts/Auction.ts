@@ -0,0 +1,153 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AuctionFields {
+  solmapNumber: BN
+  season: number
+  kind: types.AuctionKindKind
+  reservePrice: BN
+  startTime: BN
+  endTime: BN
+  bidder: PublicKey | null
+  bid: BN
+  bump: number
+}
+
+export interface AuctionJSON {
+  solmapNumber: string
+  season: number
+  kind: types.AuctionKindJSON
+  reservePrice: string
+  startTime: string
+  endTime: string
+  bidder: string | null
+  bid: string
+  bump: number
+}
+
+export class Auction {
+  readonly solmapNumber: BN
+  readonly season: number
+  readonly kind: types.AuctionKindKind
+  readonly reservePrice: BN
+  readonly startTime: BN
+  readonly endTime: BN
+  readonly bidder: PublicKey | null
+  readonly bid: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    218, 94, 247, 242, 126, 233, 131, 81,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("solmapNumber"),
+    borsh.u16("season"),
+    types.AuctionKind.layout("kind"),
+    borsh.u64("reservePrice"),
+    borsh.i64("startTime"),
+    borsh.i64("endTime"),
+    borsh.option(borsh.publicKey(), "bidder"),
+    borsh.u64("bid"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: AuctionFields) {
+    this.solmapNumber = fields.solmapNumber
+    this.season = fields.season
+    this.kind = fields.kind
+    this.reservePrice = fields.reservePrice
+    this.startTime = fields.startTime
+    this.endTime = fields.endTime
+    this.bidder = fields.bidder
+    this.bid = fields.bid
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Auction | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Auction | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Auction {
+    if (!data.slice(0, 8).equals(Auction.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Auction.layout.decode(data.slice(8))
+
+    return new Auction({
+      solmapNumber: dec.solmapNumber,
+      season: dec.season,
+      kind: types.AuctionKind.fromDecoded(dec.kind),
+      reservePrice: dec.reservePrice,
+      startTime: dec.startTime,
+      endTime: dec.endTime,
+      bidder: dec.bidder,
+      bid: dec.bid,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): AuctionJSON {
+    return {
+      solmapNumber: this.solmapNumber.toString(),
+      season: this.season,
+      kind: this.kind.toJSON(),
+      reservePrice: this.reservePrice.toString(),
+      startTime: this.startTime.toString(),
+      endTime: this.endTime.toString(),
+      bidder: (this.bidder && this.bidder.toString()) || null,
+      bid: this.bid.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: AuctionJSON): Auction {
+    return new Auction({
+      solmapNumber: new BN(obj.solmapNumber),
+      season: obj.season,
+      kind: types.AuctionKind.fromJSON(obj.kind),
+      reservePrice: new BN(obj.reservePrice),
+      startTime: new BN(obj.startTime),
+      endTime: new BN(obj.endTime),
+      bidder: (obj.bidder && new PublicKey(obj.bidder)) || null,
+      bid: new BN(obj.bid),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CommitmentFields {
+  owner: PublicKey
+  hash: Array<number>
+  committedSlot: BN
+  bump: number
+}
+
+export interface CommitmentJSON {
+  owner: string
+  hash: Array<number>
+  committedSlot: string
+  bump: number
+}
+
+export class Commitment {
+  readonly owner: PublicKey
+  readonly hash: Array<number>
+  readonly committedSlot: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    61, 112, 129, 128, 24, 147, 77, 87,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("owner"),
+    borsh.array(borsh.u8(), 32, "hash"),
+    borsh.u64("committedSlot"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: CommitmentFields) {
+    this.owner = fields.owner
+    this.hash = fields.hash
+    this.committedSlot = fields.committedSlot
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Commitment | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Commitment | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Commitment {
+    if (!data.slice(0, 8).equals(Commitment.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Commitment.layout.decode(data.slice(8))
+
+    return new Commitment({
+      owner: dec.owner,
+      hash: dec.hash,
+      committedSlot: dec.committedSlot,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): CommitmentJSON {
+    return {
+      owner: this.owner.toString(),
+      hash: this.hash,
+      committedSlot: this.committedSlot.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: CommitmentJSON): Commitment {
+    return new Commitment({
+      owner: new PublicKey(obj.owner),
+      hash: obj.hash,
+      committedSlot: new BN(obj.committedSlot),
+      bump: obj.bump,
+    })
+  }
+}
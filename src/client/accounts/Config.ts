@@ -0,0 +1,206 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ConfigFields {
+  admin: PublicKey
+  treasury: PublicKey
+  mcc: PublicKey
+  publicMintEnabled: boolean
+  pendingAdmin: PublicKey | null
+  pendingAdminEta: BN
+  adminTimelock: BN
+  pendingAdminTimelock: BN | null
+  pendingAdminTimelockEta: BN
+  reservationExpirySlots: BN
+  revealDelaySlots: BN
+  commitRevealOnly: boolean
+  referralBps: number
+  seasonsEnd: BN
+  bump: number
+}
+
+export interface ConfigJSON {
+  admin: string
+  treasury: string
+  mcc: string
+  publicMintEnabled: boolean
+  pendingAdmin: string | null
+  pendingAdminEta: string
+  adminTimelock: string
+  pendingAdminTimelock: string | null
+  pendingAdminTimelockEta: string
+  reservationExpirySlots: string
+  revealDelaySlots: string
+  commitRevealOnly: boolean
+  referralBps: number
+  seasonsEnd: string
+  bump: number
+}
+
+export class Config {
+  readonly admin: PublicKey
+  readonly treasury: PublicKey
+  readonly mcc: PublicKey
+  readonly publicMintEnabled: boolean
+  readonly pendingAdmin: PublicKey | null
+  readonly pendingAdminEta: BN
+  readonly adminTimelock: BN
+  readonly pendingAdminTimelock: BN | null
+  readonly pendingAdminTimelockEta: BN
+  readonly reservationExpirySlots: BN
+  readonly revealDelaySlots: BN
+  readonly commitRevealOnly: boolean
+  readonly referralBps: number
+  readonly seasonsEnd: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    155, 12, 170, 224, 30, 250, 204, 130,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("admin"),
+    borsh.publicKey("treasury"),
+    borsh.publicKey("mcc"),
+    borsh.bool("publicMintEnabled"),
+    borsh.option(borsh.publicKey(), "pendingAdmin"),
+    borsh.i64("pendingAdminEta"),
+    borsh.i64("adminTimelock"),
+    borsh.option(borsh.i64(), "pendingAdminTimelock"),
+    borsh.i64("pendingAdminTimelockEta"),
+    borsh.u64("reservationExpirySlots"),
+    borsh.u64("revealDelaySlots"),
+    borsh.bool("commitRevealOnly"),
+    borsh.u16("referralBps"),
+    borsh.u64("seasonsEnd"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ConfigFields) {
+    this.admin = fields.admin
+    this.treasury = fields.treasury
+    this.mcc = fields.mcc
+    this.publicMintEnabled = fields.publicMintEnabled
+    this.pendingAdmin = fields.pendingAdmin
+    this.pendingAdminEta = fields.pendingAdminEta
+    this.adminTimelock = fields.adminTimelock
+    this.pendingAdminTimelock = fields.pendingAdminTimelock
+    this.pendingAdminTimelockEta = fields.pendingAdminTimelockEta
+    this.reservationExpirySlots = fields.reservationExpirySlots
+    this.revealDelaySlots = fields.revealDelaySlots
+    this.commitRevealOnly = fields.commitRevealOnly
+    this.referralBps = fields.referralBps
+    this.seasonsEnd = fields.seasonsEnd
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Config | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Config | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Config {
+    if (!data.slice(0, 8).equals(Config.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Config.layout.decode(data.slice(8))
+
+    return new Config({
+      admin: dec.admin,
+      treasury: dec.treasury,
+      mcc: dec.mcc,
+      publicMintEnabled: dec.publicMintEnabled,
+      pendingAdmin: dec.pendingAdmin,
+      pendingAdminEta: dec.pendingAdminEta,
+      adminTimelock: dec.adminTimelock,
+      pendingAdminTimelock: dec.pendingAdminTimelock,
+      pendingAdminTimelockEta: dec.pendingAdminTimelockEta,
+      reservationExpirySlots: dec.reservationExpirySlots,
+      revealDelaySlots: dec.revealDelaySlots,
+      commitRevealOnly: dec.commitRevealOnly,
+      referralBps: dec.referralBps,
+      seasonsEnd: dec.seasonsEnd,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ConfigJSON {
+    return {
+      admin: this.admin.toString(),
+      treasury: this.treasury.toString(),
+      mcc: this.mcc.toString(),
+      publicMintEnabled: this.publicMintEnabled,
+      pendingAdmin: (this.pendingAdmin && this.pendingAdmin.toString()) || null,
+      pendingAdminEta: this.pendingAdminEta.toString(),
+      adminTimelock: this.adminTimelock.toString(),
+      pendingAdminTimelock:
+        (this.pendingAdminTimelock &&
+          this.pendingAdminTimelock.toString()) ||
+        null,
+      pendingAdminTimelockEta: this.pendingAdminTimelockEta.toString(),
+      reservationExpirySlots: this.reservationExpirySlots.toString(),
+      revealDelaySlots: this.revealDelaySlots.toString(),
+      commitRevealOnly: this.commitRevealOnly,
+      referralBps: this.referralBps,
+      seasonsEnd: this.seasonsEnd.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ConfigJSON): Config {
+    return new Config({
+      admin: new PublicKey(obj.admin),
+      treasury: new PublicKey(obj.treasury),
+      mcc: new PublicKey(obj.mcc),
+      publicMintEnabled: obj.publicMintEnabled,
+      pendingAdmin:
+        (obj.pendingAdmin && new PublicKey(obj.pendingAdmin)) || null,
+      pendingAdminEta: new BN(obj.pendingAdminEta),
+      adminTimelock: new BN(obj.adminTimelock),
+      pendingAdminTimelock:
+        (obj.pendingAdminTimelock && new BN(obj.pendingAdminTimelock)) || null,
+      pendingAdminTimelockEta: new BN(obj.pendingAdminTimelockEta),
+      reservationExpirySlots: new BN(obj.reservationExpirySlots),
+      revealDelaySlots: new BN(obj.revealDelaySlots),
+      commitRevealOnly: obj.commitRevealOnly,
+      referralBps: obj.referralBps,
+      seasonsEnd: new BN(obj.seasonsEnd),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,105 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FeeSplitFields {
+  recipients: Array<types.FeeRecipientFields>
+  bump: number
+}
+
+export interface FeeSplitJSON {
+  recipients: Array<types.FeeRecipientJSON>
+  bump: number
+}
+
+export class FeeSplit {
+  readonly recipients: Array<types.FeeRecipient>
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    86, 16, 21, 61, 101, 156, 140, 9,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(types.FeeRecipient.layout(), "recipients"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: FeeSplitFields) {
+    this.recipients = fields.recipients.map(
+      (item) => new types.FeeRecipient({ ...item })
+    )
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<FeeSplit | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<FeeSplit | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): FeeSplit {
+    if (!data.slice(0, 8).equals(FeeSplit.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = FeeSplit.layout.decode(data.slice(8))
+
+    return new FeeSplit({
+      recipients: dec.recipients.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.FeeRecipient.fromDecoded(item)
+      ),
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): FeeSplitJSON {
+    return {
+      recipients: this.recipients.map((item) => item.toJSON()),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: FeeSplitJSON): FeeSplit {
+    return new FeeSplit({
+      recipients: obj.recipients.map((item) =>
+        types.FeeRecipient.fromJSON(item)
+      ),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FeeTokenFields {
+  mint: PublicKey
+  amount: BN
+  enabled: boolean
+  bump: number
+}
+
+export interface FeeTokenJSON {
+  mint: string
+  amount: string
+  enabled: boolean
+  bump: number
+}
+
+export class FeeToken {
+  readonly mint: PublicKey
+  readonly amount: BN
+  readonly enabled: boolean
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    50, 25, 215, 164, 14, 173, 245, 0,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("mint"),
+    borsh.u64("amount"),
+    borsh.bool("enabled"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: FeeTokenFields) {
+    this.mint = fields.mint
+    this.amount = fields.amount
+    this.enabled = fields.enabled
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<FeeToken | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<FeeToken | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): FeeToken {
+    if (!data.slice(0, 8).equals(FeeToken.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = FeeToken.layout.decode(data.slice(8))
+
+    return new FeeToken({
+      mint: dec.mint,
+      amount: dec.amount,
+      enabled: dec.enabled,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): FeeTokenJSON {
+    return {
+      mint: this.mint.toString(),
+      amount: this.amount.toString(),
+      enabled: this.enabled,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: FeeTokenJSON): FeeToken {
+    return new FeeToken({
+      mint: new PublicKey(obj.mint),
+      amount: new BN(obj.amount),
+      enabled: obj.enabled,
+      bump: obj.bump,
+    })
+  }
+}
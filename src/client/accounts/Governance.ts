@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GovernanceFields {
+  members: Array<PublicKey>
+  threshold: number
+  proposalCount: BN
+  bump: number
+}
+
+export interface GovernanceJSON {
+  members: Array<string>
+  threshold: number
+  proposalCount: string
+  bump: number
+}
+
+export class Governance {
+  readonly members: Array<PublicKey>
+  readonly threshold: number
+  readonly proposalCount: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    18, 143, 88, 13, 73, 217, 47, 49,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(borsh.publicKey(), "members"),
+    borsh.u8("threshold"),
+    borsh.u64("proposalCount"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: GovernanceFields) {
+    this.members = fields.members
+    this.threshold = fields.threshold
+    this.proposalCount = fields.proposalCount
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Governance | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Governance | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Governance {
+    if (!data.slice(0, 8).equals(Governance.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Governance.layout.decode(data.slice(8))
+
+    return new Governance({
+      members: dec.members,
+      threshold: dec.threshold,
+      proposalCount: dec.proposalCount,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): GovernanceJSON {
+    return {
+      members: this.members.map((item) => item.toString()),
+      threshold: this.threshold,
+      proposalCount: this.proposalCount.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: GovernanceJSON): Governance {
+    return new Governance({
+      members: obj.members.map((item) => new PublicKey(item)),
+      threshold: obj.threshold,
+      proposalCount: new BN(obj.proposalCount),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,150 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProposalFields {
+  id: BN
+  proposer: PublicKey
+  accounts: Array<types.ProposalAccountFields>
+  data: Uint8Array
+  approvals: Array<PublicKey>
+  executed: boolean
+  bump: number
+}
+
+export interface ProposalJSON {
+  id: string
+  proposer: string
+  accounts: Array<types.ProposalAccountJSON>
+  data: Array<number>
+  approvals: Array<string>
+  executed: boolean
+  bump: number
+}
+
+export class Proposal {
+  readonly id: BN
+  readonly proposer: PublicKey
+  readonly accounts: Array<types.ProposalAccount>
+  readonly data: Uint8Array
+  readonly approvals: Array<PublicKey>
+  readonly executed: boolean
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    26, 94, 189, 187, 116, 136, 53, 33,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("id"),
+    borsh.publicKey("proposer"),
+    borsh.vec(types.ProposalAccount.layout(), "accounts"),
+    borsh.vecU8("data"),
+    borsh.vec(borsh.publicKey(), "approvals"),
+    borsh.bool("executed"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ProposalFields) {
+    this.id = fields.id
+    this.proposer = fields.proposer
+    this.accounts = fields.accounts.map(
+      (item) => new types.ProposalAccount({ ...item })
+    )
+    this.data = fields.data
+    this.approvals = fields.approvals
+    this.executed = fields.executed
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Proposal | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Proposal | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Proposal {
+    if (!data.slice(0, 8).equals(Proposal.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Proposal.layout.decode(data.slice(8))
+
+    return new Proposal({
+      id: dec.id,
+      proposer: dec.proposer,
+      accounts: dec.accounts.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.ProposalAccount.fromDecoded(item)
+      ),
+      data: new Uint8Array(
+        dec.data.buffer.slice(
+          dec.data.byteOffset,
+          dec.data.byteOffset + dec.data.length
+        )
+      ),
+      approvals: dec.approvals,
+      executed: dec.executed,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ProposalJSON {
+    return {
+      id: this.id.toString(),
+      proposer: this.proposer.toString(),
+      accounts: this.accounts.map((item) => item.toJSON()),
+      data: Array.from(this.data.values()),
+      approvals: this.approvals.map((item) => item.toString()),
+      executed: this.executed,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ProposalJSON): Proposal {
+    return new Proposal({
+      id: new BN(obj.id),
+      proposer: new PublicKey(obj.proposer),
+      accounts: obj.accounts.map((item) =>
+        types.ProposalAccount.fromJSON(item)
+      ),
+      data: Uint8Array.from(obj.data),
+      approvals: obj.approvals.map((item) => new PublicKey(item)),
+      executed: obj.executed,
+      bump: obj.bump,
+    })
+  }
+}
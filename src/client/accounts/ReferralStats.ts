@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ReferralStatsFields {
+  referrer: PublicKey
+  mints: BN
+  earned: BN
+  bump: number
+}
+
+export interface ReferralStatsJSON {
+  referrer: string
+  mints: string
+  earned: string
+  bump: number
+}
+
+export class ReferralStats {
+  readonly referrer: PublicKey
+  readonly mints: BN
+  readonly earned: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    1, 195, 51, 123, 240, 93, 99, 240,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("referrer"),
+    borsh.u64("mints"),
+    borsh.u64("earned"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ReferralStatsFields) {
+    this.referrer = fields.referrer
+    this.mints = fields.mints
+    this.earned = fields.earned
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ReferralStats | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ReferralStats | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ReferralStats {
+    if (!data.slice(0, 8).equals(ReferralStats.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ReferralStats.layout.decode(data.slice(8))
+
+    return new ReferralStats({
+      referrer: dec.referrer,
+      mints: dec.mints,
+      earned: dec.earned,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ReferralStatsJSON {
+    return {
+      referrer: this.referrer.toString(),
+      mints: this.mints.toString(),
+      earned: this.earned.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ReferralStatsJSON): ReferralStats {
+    return new ReferralStats({
+      referrer: new PublicKey(obj.referrer),
+      mints: new BN(obj.mints),
+      earned: new BN(obj.earned),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,153 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ReservationFields {
+  owner: PublicKey
+  season: number
+  start: BN
+  count: number
+  finalized: BN
+  fee: BN
+  reservedSlot: BN
+  expirySlot: BN
+  bump: number
+}
+
+export interface ReservationJSON {
+  owner: string
+  season: number
+  start: string
+  count: number
+  finalized: string
+  fee: string
+  reservedSlot: string
+  expirySlot: string
+  bump: number
+}
+
+export class Reservation {
+  readonly owner: PublicKey
+  readonly season: number
+  readonly start: BN
+  readonly count: number
+  readonly finalized: BN
+  readonly fee: BN
+  readonly reservedSlot: BN
+  readonly expirySlot: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    188, 235, 0, 111, 208, 253, 247, 212,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("owner"),
+    borsh.u16("season"),
+    borsh.u64("start"),
+    borsh.u8("count"),
+    borsh.u64("finalized"),
+    borsh.u64("fee"),
+    borsh.u64("reservedSlot"),
+    borsh.u64("expirySlot"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ReservationFields) {
+    this.owner = fields.owner
+    this.season = fields.season
+    this.start = fields.start
+    this.count = fields.count
+    this.finalized = fields.finalized
+    this.fee = fields.fee
+    this.reservedSlot = fields.reservedSlot
+    this.expirySlot = fields.expirySlot
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Reservation | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Reservation | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Reservation {
+    if (!data.slice(0, 8).equals(Reservation.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Reservation.layout.decode(data.slice(8))
+
+    return new Reservation({
+      owner: dec.owner,
+      season: dec.season,
+      start: dec.start,
+      count: dec.count,
+      finalized: dec.finalized,
+      fee: dec.fee,
+      reservedSlot: dec.reservedSlot,
+      expirySlot: dec.expirySlot,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ReservationJSON {
+    return {
+      owner: this.owner.toString(),
+      season: this.season,
+      start: this.start.toString(),
+      count: this.count,
+      finalized: this.finalized.toString(),
+      fee: this.fee.toString(),
+      reservedSlot: this.reservedSlot.toString(),
+      expirySlot: this.expirySlot.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ReservationJSON): Reservation {
+    return new Reservation({
+      owner: new PublicKey(obj.owner),
+      season: obj.season,
+      start: new BN(obj.start),
+      count: obj.count,
+      finalized: new BN(obj.finalized),
+      fee: new BN(obj.fee),
+      reservedSlot: new BN(obj.reservedSlot),
+      expirySlot: new BN(obj.expirySlot),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,193 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SeasonFields {
+  id: number
+  firstSolmap: BN
+  endSolmap: BN
+  startTime: BN
+  uri: string
+  fee: BN
+  pricing: types.PricingModeKind
+  open: boolean
+  allowlistRoot: Array<number>
+  allowlistStartTime: BN
+  allowlistWalletLimit: number
+  publicWalletLimit: number
+  minted: number
+  bump: number
+}
+
+export interface SeasonJSON {
+  id: number
+  firstSolmap: string
+  endSolmap: string
+  startTime: string
+  uri: string
+  fee: string
+  pricing: types.PricingModeJSON
+  open: boolean
+  allowlistRoot: Array<number>
+  allowlistStartTime: string
+  allowlistWalletLimit: number
+  publicWalletLimit: number
+  minted: number
+  bump: number
+}
+
+export class Season {
+  readonly id: number
+  readonly firstSolmap: BN
+  readonly endSolmap: BN
+  readonly startTime: BN
+  readonly uri: string
+  readonly fee: BN
+  readonly pricing: types.PricingModeKind
+  readonly open: boolean
+  readonly allowlistRoot: Array<number>
+  readonly allowlistStartTime: BN
+  readonly allowlistWalletLimit: number
+  readonly publicWalletLimit: number
+  readonly minted: number
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    76, 67, 93, 156, 180, 157, 248, 47,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u16("id"),
+    borsh.u64("firstSolmap"),
+    borsh.u64("endSolmap"),
+    borsh.i64("startTime"),
+    borsh.str("uri"),
+    borsh.u64("fee"),
+    types.PricingMode.layout("pricing"),
+    borsh.bool("open"),
+    borsh.array(borsh.u8(), 32, "allowlistRoot"),
+    borsh.i64("allowlistStartTime"),
+    borsh.u32("allowlistWalletLimit"),
+    borsh.u32("publicWalletLimit"),
+    borsh.u32("minted"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: SeasonFields) {
+    this.id = fields.id
+    this.firstSolmap = fields.firstSolmap
+    this.endSolmap = fields.endSolmap
+    this.startTime = fields.startTime
+    this.uri = fields.uri
+    this.fee = fields.fee
+    this.pricing = fields.pricing
+    this.open = fields.open
+    this.allowlistRoot = fields.allowlistRoot
+    this.allowlistStartTime = fields.allowlistStartTime
+    this.allowlistWalletLimit = fields.allowlistWalletLimit
+    this.publicWalletLimit = fields.publicWalletLimit
+    this.minted = fields.minted
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Season | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Season | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Season {
+    if (!data.slice(0, 8).equals(Season.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Season.layout.decode(data.slice(8))
+
+    return new Season({
+      id: dec.id,
+      firstSolmap: dec.firstSolmap,
+      endSolmap: dec.endSolmap,
+      startTime: dec.startTime,
+      uri: dec.uri,
+      fee: dec.fee,
+      pricing: types.PricingMode.fromDecoded(dec.pricing),
+      open: dec.open,
+      allowlistRoot: dec.allowlistRoot,
+      allowlistStartTime: dec.allowlistStartTime,
+      allowlistWalletLimit: dec.allowlistWalletLimit,
+      publicWalletLimit: dec.publicWalletLimit,
+      minted: dec.minted,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): SeasonJSON {
+    return {
+      id: this.id,
+      firstSolmap: this.firstSolmap.toString(),
+      endSolmap: this.endSolmap.toString(),
+      startTime: this.startTime.toString(),
+      uri: this.uri,
+      fee: this.fee.toString(),
+      pricing: this.pricing.toJSON(),
+      open: this.open,
+      allowlistRoot: this.allowlistRoot,
+      allowlistStartTime: this.allowlistStartTime.toString(),
+      allowlistWalletLimit: this.allowlistWalletLimit,
+      publicWalletLimit: this.publicWalletLimit,
+      minted: this.minted,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: SeasonJSON): Season {
+    return new Season({
+      id: obj.id,
+      firstSolmap: new BN(obj.firstSolmap),
+      endSolmap: new BN(obj.endSolmap),
+      startTime: new BN(obj.startTime),
+      uri: obj.uri,
+      fee: new BN(obj.fee),
+      pricing: types.PricingMode.fromJSON(obj.pricing),
+      open: obj.open,
+      allowlistRoot: obj.allowlistRoot,
+      allowlistStartTime: new BN(obj.allowlistStartTime),
+      allowlistWalletLimit: obj.allowlistWalletLimit,
+      publicWalletLimit: obj.publicWalletLimit,
+      minted: obj.minted,
+      bump: obj.bump,
+    })
+  }
+}
@@ -1,20 +1,56 @@
 import { PublicKey, Connection } from "@solana/web3.js"
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
-export interface SlotIndexFields {}
+export interface SlotIndexFields {
+  version: number
+  page: number
+  minted: number
+  capacity: number
+  lastMintedSlot: BN
+  bump: number
+}
 
-export interface SlotIndexJSON {}
+export interface SlotIndexJSON {
+  version: number
+  page: number
+  minted: number
+  capacity: number
+  lastMintedSlot: string
+  bump: number
+}
 
 export class SlotIndex {
+  readonly version: number
+  readonly page: number
+  readonly minted: number
+  readonly capacity: number
+  readonly lastMintedSlot: BN
+  readonly bump: number
+
   static readonly discriminator = Buffer.from([
     181, 158, 126, 89, 169, 94, 138, 55,
   ])
 
-  static readonly layout = borsh.struct([])
+  static readonly layout = borsh.struct([
+    borsh.u8("version"),
+    borsh.u32("page"),
+    borsh.u32("minted"),
+    borsh.u32("capacity"),
+    borsh.u64("lastMintedSlot"),
+    borsh.u8("bump"),
+  ])
 
-  constructor(fields: SlotIndexFields) {}
+  constructor(fields: SlotIndexFields) {
+    this.version = fields.version
+    this.page = fields.page
+    this.minted = fields.minted
+    this.capacity = fields.capacity
+    this.lastMintedSlot = fields.lastMintedSlot
+    this.bump = fields.bump
+  }
 
   static async fetch(
     c: Connection,
@@ -59,14 +95,35 @@ export class SlotIndex {
 
     const dec = SlotIndex.layout.decode(data.slice(8))
 
-    return new SlotIndex({})
+    return new SlotIndex({
+      version: dec.version,
+      page: dec.page,
+      minted: dec.minted,
+      capacity: dec.capacity,
+      lastMintedSlot: dec.lastMintedSlot,
+      bump: dec.bump,
+    })
   }
 
   toJSON(): SlotIndexJSON {
-    return {}
+    return {
+      version: this.version,
+      page: this.page,
+      minted: this.minted,
+      capacity: this.capacity,
+      lastMintedSlot: this.lastMintedSlot.toString(),
+      bump: this.bump,
+    }
   }
 
   static fromJSON(obj: SlotIndexJSON): SlotIndex {
-    return new SlotIndex({})
+    return new SlotIndex({
+      version: obj.version,
+      page: obj.page,
+      minted: obj.minted,
+      capacity: obj.capacity,
+      lastMintedSlot: new BN(obj.lastMintedSlot),
+      bump: obj.bump,
+    })
   }
 }
@@ -0,0 +1,145 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SolmapRecordFields {
+  solmapNumber: BN
+  mint: PublicKey
+  owner: PublicKey
+  payer: PublicKey
+  slot: BN
+  timestamp: BN
+  inscription: PublicKey
+  bump: number
+}
+
+export interface SolmapRecordJSON {
+  solmapNumber: string
+  mint: string
+  owner: string
+  payer: string
+  slot: string
+  timestamp: string
+  inscription: string
+  bump: number
+}
+
+export class SolmapRecord {
+  readonly solmapNumber: BN
+  readonly mint: PublicKey
+  readonly owner: PublicKey
+  readonly payer: PublicKey
+  readonly slot: BN
+  readonly timestamp: BN
+  readonly inscription: PublicKey
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    151, 54, 49, 143, 142, 155, 129, 141,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("solmapNumber"),
+    borsh.publicKey("mint"),
+    borsh.publicKey("owner"),
+    borsh.publicKey("payer"),
+    borsh.u64("slot"),
+    borsh.i64("timestamp"),
+    borsh.publicKey("inscription"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: SolmapRecordFields) {
+    this.solmapNumber = fields.solmapNumber
+    this.mint = fields.mint
+    this.owner = fields.owner
+    this.payer = fields.payer
+    this.slot = fields.slot
+    this.timestamp = fields.timestamp
+    this.inscription = fields.inscription
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<SolmapRecord | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<SolmapRecord | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): SolmapRecord {
+    if (!data.slice(0, 8).equals(SolmapRecord.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = SolmapRecord.layout.decode(data.slice(8))
+
+    return new SolmapRecord({
+      solmapNumber: dec.solmapNumber,
+      mint: dec.mint,
+      owner: dec.owner,
+      payer: dec.payer,
+      slot: dec.slot,
+      timestamp: dec.timestamp,
+      inscription: dec.inscription,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): SolmapRecordJSON {
+    return {
+      solmapNumber: this.solmapNumber.toString(),
+      mint: this.mint.toString(),
+      owner: this.owner.toString(),
+      payer: this.payer.toString(),
+      slot: this.slot.toString(),
+      timestamp: this.timestamp.toString(),
+      inscription: this.inscription.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: SolmapRecordJSON): SolmapRecord {
+    return new SolmapRecord({
+      solmapNumber: new BN(obj.solmapNumber),
+      mint: new PublicKey(obj.mint),
+      owner: new PublicKey(obj.owner),
+      payer: new PublicKey(obj.payer),
+      slot: new BN(obj.slot),
+      timestamp: new BN(obj.timestamp),
+      inscription: new PublicKey(obj.inscription),
+      bump: obj.bump,
+    })
+  }
+}
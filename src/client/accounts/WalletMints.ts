@@ -0,0 +1,121 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface WalletMintsFields {
+  season: number
+  wallet: PublicKey
+  allowlistMinted: number
+  publicMinted: number
+  bump: number
+}
+
+export interface WalletMintsJSON {
+  season: number
+  wallet: string
+  allowlistMinted: number
+  publicMinted: number
+  bump: number
+}
+
+export class WalletMints {
+  readonly season: number
+  readonly wallet: PublicKey
+  readonly allowlistMinted: number
+  readonly publicMinted: number
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    21, 250, 224, 93, 206, 86, 60, 18,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u16("season"),
+    borsh.publicKey("wallet"),
+    borsh.u32("allowlistMinted"),
+    borsh.u32("publicMinted"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: WalletMintsFields) {
+    this.season = fields.season
+    this.wallet = fields.wallet
+    this.allowlistMinted = fields.allowlistMinted
+    this.publicMinted = fields.publicMinted
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<WalletMints | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<WalletMints | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): WalletMints {
+    if (!data.slice(0, 8).equals(WalletMints.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = WalletMints.layout.decode(data.slice(8))
+
+    return new WalletMints({
+      season: dec.season,
+      wallet: dec.wallet,
+      allowlistMinted: dec.allowlistMinted,
+      publicMinted: dec.publicMinted,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): WalletMintsJSON {
+    return {
+      season: this.season,
+      wallet: this.wallet.toString(),
+      allowlistMinted: this.allowlistMinted,
+      publicMinted: this.publicMinted,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: WalletMintsJSON): WalletMints {
+    return new WalletMints({
+      season: obj.season,
+      wallet: new PublicKey(obj.wallet),
+      allowlistMinted: obj.allowlistMinted,
+      publicMinted: obj.publicMinted,
+      bump: obj.bump,
+    })
+  }
+}
@@ -1,2 +1,26 @@
 export { SlotIndex } from "./SlotIndex"
 export type { SlotIndexFields, SlotIndexJSON } from "./SlotIndex"
+export { SolmapRecord } from "./SolmapRecord"
+export type { SolmapRecordFields, SolmapRecordJSON } from "./SolmapRecord"
+export { Reservation } from "./Reservation"
+export type { ReservationFields, ReservationJSON } from "./Reservation"
+export { Commitment } from "./Commitment"
+export type { CommitmentFields, CommitmentJSON } from "./Commitment"
+export { Auction } from "./Auction"
+export type { AuctionFields, AuctionJSON } from "./Auction"
+export { FeeSplit } from "./FeeSplit"
+export type { FeeSplitFields, FeeSplitJSON } from "./FeeSplit"
+export { FeeToken } from "./FeeToken"
+export type { FeeTokenFields, FeeTokenJSON } from "./FeeToken"
+export { ReferralStats } from "./ReferralStats"
+export type { ReferralStatsFields, ReferralStatsJSON } from "./ReferralStats"
+export { Config } from "./Config"
+export type { ConfigFields, ConfigJSON } from "./Config"
+export { Season } from "./Season"
+export type { SeasonFields, SeasonJSON } from "./Season"
+export { WalletMints } from "./WalletMints"
+export type { WalletMintsFields, WalletMintsJSON } from "./WalletMints"
+export { Governance } from "./Governance"
+export type { GovernanceFields, GovernanceJSON } from "./Governance"
+export { Proposal } from "./Proposal"
+export type { ProposalFields, ProposalJSON } from "./Proposal"
@@ -2,6 +2,59 @@ export type CustomError =
   | MintingNotLiveYet
   | InvalidSolmapNumber
   | SolmapAlreadyMinted
+  | InvalidSolmapNFT
+  | PublicMintDisabled
+  | Unauthorized
+  | UriTooLong
+  | SeasonClosed
+  | InvalidSeasonRange
+  | SlotIndexPageAlreadyInitialized
+  | SolmapBeyondIndexCapacity
+  | SolmapNotMinted
+  | InvalidGovernanceMembers
+  | InvalidGovernanceThreshold
+  | NotGovernanceMember
+  | ProposalTooLarge
+  | ProposalAlreadyApproved
+  | ProposalAlreadyExecuted
+  | ProposalThresholdNotMet
+  | ProposalAccountMissing
+  | InvalidTimelock
+  | AuthorityTimelockActive
+  | InvalidReservationRange
+  | SolmapNotReserved
+  | SolmapAlreadyFinalized
+  | ReservationExpired
+  | ReservationNotExpired
+  | InvalidCommitment
+  | RevealTooEarly
+  | CommitRevealRequired
+  | InvalidAllowlistProof
+  | AllowlistQuotaExceeded
+  | WalletMintLimitReached
+  | InvalidPricing
+  | InvalidFeeSplit
+  | FeeRecipientMismatch
+  | FeeTokenNotAccepted
+  | InvalidFeeTokenAccounts
+  | InvalidReferralShare
+  | InvalidReferrer
+  | NoSolmapAvailable
+  | ReservationNotHeld
+  | InvalidAuction
+  | AuctionNotOpen
+  | BidTooLow
+  | BidderMismatch
+  | AuctionNotOver
+  | AuctionHasNoBids
+  | AuctionHasBids
+  | SlotHashUnavailable
+  | SlotIndexNotOwned
+  | SolmapReserved
+  | RandomDrawPageMissing
+  | SeasonOverlap
+  | SeasonNotWritable
+  | SlotIndexVersionMismatch
 
 export class MintingNotLiveYet extends Error {
   static readonly code = 6000
@@ -36,6 +89,604 @@ export class SolmapAlreadyMinted extends Error {
   }
 }
 
+export class InvalidSolmapNFT extends Error {
+  static readonly code = 6003
+  readonly code = 6003
+  readonly name = "InvalidSolmapNFT"
+  readonly msg = "Invalid Solmap NFT"
+
+  constructor(readonly logs?: string[]) {
+    super("6003: Invalid Solmap NFT")
+  }
+}
+
+export class PublicMintDisabled extends Error {
+  static readonly code = 6004
+  readonly code = 6004
+  readonly name = "PublicMintDisabled"
+  readonly msg = "Public minting is disabled"
+
+  constructor(readonly logs?: string[]) {
+    super("6004: Public minting is disabled")
+  }
+}
+
+export class Unauthorized extends Error {
+  static readonly code = 6005
+  readonly code = 6005
+  readonly name = "Unauthorized"
+  readonly msg = "Signer is not authorized"
+
+  constructor(readonly logs?: string[]) {
+    super("6005: Signer is not authorized")
+  }
+}
+
+export class UriTooLong extends Error {
+  static readonly code = 6006
+  readonly code = 6006
+  readonly name = "UriTooLong"
+  readonly msg = "URI is too long"
+
+  constructor(readonly logs?: string[]) {
+    super("6006: URI is too long")
+  }
+}
+
+export class SeasonClosed extends Error {
+  static readonly code = 6007
+  readonly code = 6007
+  readonly name = "SeasonClosed"
+  readonly msg = "Season is closed"
+
+  constructor(readonly logs?: string[]) {
+    super("6007: Season is closed")
+  }
+}
+
+export class InvalidSeasonRange extends Error {
+  static readonly code = 6008
+  readonly code = 6008
+  readonly name = "InvalidSeasonRange"
+  readonly msg = "Season range is invalid"
+
+  constructor(readonly logs?: string[]) {
+    super("6008: Season range is invalid")
+  }
+}
+
+export class SlotIndexPageAlreadyInitialized extends Error {
+  static readonly code = 6009
+  readonly code = 6009
+  readonly name = "SlotIndexPageAlreadyInitialized"
+  readonly msg = "Slot index page is already initialized"
+
+  constructor(readonly logs?: string[]) {
+    super("6009: Slot index page is already initialized")
+  }
+}
+
+export class SolmapBeyondIndexCapacity extends Error {
+  static readonly code = 6010
+  readonly code = 6010
+  readonly name = "SolmapBeyondIndexCapacity"
+  readonly msg = "Solmap number is beyond the slot index capacity"
+
+  constructor(readonly logs?: string[]) {
+    super("6010: Solmap number is beyond the slot index capacity")
+  }
+}
+
+export class SolmapNotMinted extends Error {
+  static readonly code = 6011
+  readonly code = 6011
+  readonly name = "SolmapNotMinted"
+  readonly msg = "Solmap is not minted"
+
+  constructor(readonly logs?: string[]) {
+    super("6011: Solmap is not minted")
+  }
+}
+
+export class InvalidGovernanceMembers extends Error {
+  static readonly code = 6012
+  readonly code = 6012
+  readonly name = "InvalidGovernanceMembers"
+  readonly msg = "Governance members are empty, duplicated or too many"
+
+  constructor(readonly logs?: string[]) {
+    super("6012: Governance members are empty, duplicated or too many")
+  }
+}
+
+export class InvalidGovernanceThreshold extends Error {
+  static readonly code = 6013
+  readonly code = 6013
+  readonly name = "InvalidGovernanceThreshold"
+  readonly msg =
+    "Governance threshold must be between 1 and the number of members"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6013: Governance threshold must be between 1 and the number of members"
+    )
+  }
+}
+
+export class NotGovernanceMember extends Error {
+  static readonly code = 6014
+  readonly code = 6014
+  readonly name = "NotGovernanceMember"
+  readonly msg = "Signer is not a governance member"
+
+  constructor(readonly logs?: string[]) {
+    super("6014: Signer is not a governance member")
+  }
+}
+
+export class ProposalTooLarge extends Error {
+  static readonly code = 6015
+  readonly code = 6015
+  readonly name = "ProposalTooLarge"
+  readonly msg = "Proposed instruction is too large"
+
+  constructor(readonly logs?: string[]) {
+    super("6015: Proposed instruction is too large")
+  }
+}
+
+export class ProposalAlreadyApproved extends Error {
+  static readonly code = 6016
+  readonly code = 6016
+  readonly name = "ProposalAlreadyApproved"
+  readonly msg = "Proposal was already approved by this member"
+
+  constructor(readonly logs?: string[]) {
+    super("6016: Proposal was already approved by this member")
+  }
+}
+
+export class ProposalAlreadyExecuted extends Error {
+  static readonly code = 6017
+  readonly code = 6017
+  readonly name = "ProposalAlreadyExecuted"
+  readonly msg = "Proposal was already executed"
+
+  constructor(readonly logs?: string[]) {
+    super("6017: Proposal was already executed")
+  }
+}
+
+export class ProposalThresholdNotMet extends Error {
+  static readonly code = 6018
+  readonly code = 6018
+  readonly name = "ProposalThresholdNotMet"
+  readonly msg = "Proposal does not have enough approvals"
+
+  constructor(readonly logs?: string[]) {
+    super("6018: Proposal does not have enough approvals")
+  }
+}
+
+export class ProposalAccountMissing extends Error {
+  static readonly code = 6019
+  readonly code = 6019
+  readonly name = "ProposalAccountMissing"
+  readonly msg = "An account of the proposed instruction is missing"
+
+  constructor(readonly logs?: string[]) {
+    super("6019: An account of the proposed instruction is missing")
+  }
+}
+
+export class InvalidTimelock extends Error {
+  static readonly code = 6020
+  readonly code = 6020
+  readonly name = "InvalidTimelock"
+  readonly msg = "Timelock cannot be negative"
+
+  constructor(readonly logs?: string[]) {
+    super("6020: Timelock cannot be negative")
+  }
+}
+
+export class AuthorityTimelockActive extends Error {
+  static readonly code = 6021
+  readonly code = 6021
+  readonly name = "AuthorityTimelockActive"
+  readonly msg = "New authority cannot be accepted before its timelock expires"
+
+  constructor(readonly logs?: string[]) {
+    super("6021: New authority cannot be accepted before its timelock expires")
+  }
+}
+
+export class InvalidReservationRange extends Error {
+  static readonly code = 6022
+  readonly code = 6022
+  readonly name = "InvalidReservationRange"
+  readonly msg =
+    "Reservations hold 1 to 64 Solmaps tracked by the same slot index page"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6022: Reservations hold 1 to 64 Solmaps tracked by the same slot index page"
+    )
+  }
+}
+
+export class SolmapNotReserved extends Error {
+  static readonly code = 6023
+  readonly code = 6023
+  readonly name = "SolmapNotReserved"
+  readonly msg = "Solmap is not part of the reservation"
+
+  constructor(readonly logs?: string[]) {
+    super("6023: Solmap is not part of the reservation")
+  }
+}
+
+export class SolmapAlreadyFinalized extends Error {
+  static readonly code = 6024
+  readonly code = 6024
+  readonly name = "SolmapAlreadyFinalized"
+  readonly msg = "Solmap is already finalized"
+
+  constructor(readonly logs?: string[]) {
+    super("6024: Solmap is already finalized")
+  }
+}
+
+export class ReservationExpired extends Error {
+  static readonly code = 6025
+  readonly code = 6025
+  readonly name = "ReservationExpired"
+  readonly msg = "Reservation has expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6025: Reservation has expired")
+  }
+}
+
+export class ReservationNotExpired extends Error {
+  static readonly code = 6026
+  readonly code = 6026
+  readonly name = "ReservationNotExpired"
+  readonly msg = "Reservation has not expired yet"
+
+  constructor(readonly logs?: string[]) {
+    super("6026: Reservation has not expired yet")
+  }
+}
+
+export class InvalidCommitment extends Error {
+  static readonly code = 6027
+  readonly code = 6027
+  readonly name = "InvalidCommitment"
+  readonly msg = "Revealed Solmap number and salt don't match the commitment"
+
+  constructor(readonly logs?: string[]) {
+    super("6027: Revealed Solmap number and salt don't match the commitment")
+  }
+}
+
+export class RevealTooEarly extends Error {
+  static readonly code = 6028
+  readonly code = 6028
+  readonly name = "RevealTooEarly"
+  readonly msg = "Commitment cannot be revealed yet"
+
+  constructor(readonly logs?: string[]) {
+    super("6028: Commitment cannot be revealed yet")
+  }
+}
+
+export class CommitRevealRequired extends Error {
+  static readonly code = 6029
+  readonly code = 6029
+  readonly name = "CommitRevealRequired"
+  readonly msg = "Solmaps can only be minted through commit and reveal"
+
+  constructor(readonly logs?: string[]) {
+    super("6029: Solmaps can only be minted through commit and reveal")
+  }
+}
+
+export class InvalidAllowlistProof extends Error {
+  static readonly code = 6030
+  readonly code = 6030
+  readonly name = "InvalidAllowlistProof"
+  readonly msg = "Wallet and quota are not in the allowlist"
+
+  constructor(readonly logs?: string[]) {
+    super("6030: Wallet and quota are not in the allowlist")
+  }
+}
+
+export class AllowlistQuotaExceeded extends Error {
+  static readonly code = 6031
+  readonly code = 6031
+  readonly name = "AllowlistQuotaExceeded"
+  readonly msg = "Allowlist quota exceeded"
+
+  constructor(readonly logs?: string[]) {
+    super("6031: Allowlist quota exceeded")
+  }
+}
+
+export class WalletMintLimitReached extends Error {
+  static readonly code = 6032
+  readonly code = 6032
+  readonly name = "WalletMintLimitReached"
+  readonly msg = "Wallet has reached its mint limit for this phase"
+
+  constructor(readonly logs?: string[]) {
+    super("6032: Wallet has reached its mint limit for this phase")
+  }
+}
+
+export class InvalidPricing extends Error {
+  static readonly code = 6033
+  readonly code = 6033
+  readonly name = "InvalidPricing"
+  readonly msg = "Pricing mode parameters are invalid for the season"
+
+  constructor(readonly logs?: string[]) {
+    super("6033: Pricing mode parameters are invalid for the season")
+  }
+}
+
+export class InvalidFeeSplit extends Error {
+  static readonly code = 6034
+  readonly code = 6034
+  readonly name = "InvalidFeeSplit"
+  readonly msg =
+    "Fee split needs 1 to 8 distinct recipients whose shares add up to 10000 bps"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6034: Fee split needs 1 to 8 distinct recipients whose shares add up to 10000 bps"
+    )
+  }
+}
+
+export class FeeRecipientMismatch extends Error {
+  static readonly code = 6035
+  readonly code = 6035
+  readonly name = "FeeRecipientMismatch"
+  readonly msg = "Fee recipients don't match the fee split"
+
+  constructor(readonly logs?: string[]) {
+    super("6035: Fee recipients don't match the fee split")
+  }
+}
+
+export class FeeTokenNotAccepted extends Error {
+  static readonly code = 6036
+  readonly code = 6036
+  readonly name = "FeeTokenNotAccepted"
+  readonly msg = "Token is not accepted for the grant fee"
+
+  constructor(readonly logs?: string[]) {
+    super("6036: Token is not accepted for the grant fee")
+  }
+}
+
+export class InvalidFeeTokenAccounts extends Error {
+  static readonly code = 6037
+  readonly code = 6037
+  readonly name = "InvalidFeeTokenAccounts"
+  readonly msg =
+    "Fee token accounts are missing or don't match the fee token and treasury"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6037: Fee token accounts are missing or don't match the fee token and treasury"
+    )
+  }
+}
+
+export class InvalidReferralShare extends Error {
+  static readonly code = 6038
+  readonly code = 6038
+  readonly name = "InvalidReferralShare"
+  readonly msg = "Referral share cannot exceed 10000 bps"
+
+  constructor(readonly logs?: string[]) {
+    super("6038: Referral share cannot exceed 10000 bps")
+  }
+}
+
+export class InvalidReferrer extends Error {
+  static readonly code = 6039
+  readonly code = 6039
+  readonly name = "InvalidReferrer"
+  readonly msg =
+    "Referrer is missing its stats account or referred its own mint"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "6039: Referrer is missing its stats account or referred its own mint"
+    )
+  }
+}
+
+export class NoSolmapAvailable extends Error {
+  static readonly code = 6040
+  readonly code = 6040
+  readonly name = "NoSolmapAvailable"
+  readonly msg = "No unminted Solmap is available to draw"
+
+  constructor(readonly logs?: string[]) {
+    super("6040: No unminted Solmap is available to draw")
+  }
+}
+
+export class ReservationNotHeld extends Error {
+  static readonly code = 6041
+  readonly code = 6041
+  readonly name = "ReservationNotHeld"
+  readonly msg = "Reservation was not held by the admin for a claimant"
+
+  constructor(readonly logs?: string[]) {
+    super("6041: Reservation was not held by the admin for a claimant")
+  }
+}
+
+export class InvalidAuction extends Error {
+  static readonly code = 6042
+  readonly code = 6042
+  readonly name = "InvalidAuction"
+  readonly msg = "Auction prices or times are invalid"
+
+  constructor(readonly logs?: string[]) {
+    super("6042: Auction prices or times are invalid")
+  }
+}
+
+export class AuctionNotOpen extends Error {
+  static readonly code = 6043
+  readonly code = 6043
+  readonly name = "AuctionNotOpen"
+  readonly msg = "Auction is not taking bids"
+
+  constructor(readonly logs?: string[]) {
+    super("6043: Auction is not taking bids")
+  }
+}
+
+export class BidTooLow extends Error {
+  static readonly code = 6044
+  readonly code = 6044
+  readonly name = "BidTooLow"
+  readonly msg = "Bid is below the auction's minimum bid"
+
+  constructor(readonly logs?: string[]) {
+    super("6044: Bid is below the auction's minimum bid")
+  }
+}
+
+export class BidderMismatch extends Error {
+  static readonly code = 6045
+  readonly code = 6045
+  readonly name = "BidderMismatch"
+  readonly msg = "Previous bidder doesn't match the auction's highest bidder"
+
+  constructor(readonly logs?: string[]) {
+    super("6045: Previous bidder doesn't match the auction's highest bidder")
+  }
+}
+
+export class AuctionNotOver extends Error {
+  static readonly code = 6046
+  readonly code = 6046
+  readonly name = "AuctionNotOver"
+  readonly msg = "Auction has not ended yet"
+
+  constructor(readonly logs?: string[]) {
+    super("6046: Auction has not ended yet")
+  }
+}
+
+export class AuctionHasNoBids extends Error {
+  static readonly code = 6047
+  readonly code = 6047
+  readonly name = "AuctionHasNoBids"
+  readonly msg = "Auction ended without bids"
+
+  constructor(readonly logs?: string[]) {
+    super("6047: Auction ended without bids")
+  }
+}
+
+export class AuctionHasBids extends Error {
+  static readonly code = 6048
+  readonly code = 6048
+  readonly name = "AuctionHasBids"
+  readonly msg = "Auction already has bids"
+
+  constructor(readonly logs?: string[]) {
+    super("6048: Auction already has bids")
+  }
+}
+
+export class SlotHashUnavailable extends Error {
+  static readonly code = 6049
+  readonly code = 6049
+  readonly name = "SlotHashUnavailable"
+  readonly msg = "No recent slot hash to draw from"
+
+  constructor(readonly logs?: string[]) {
+    super("6049: No recent slot hash to draw from")
+  }
+}
+
+export class SlotIndexNotOwned extends Error {
+  static readonly code = 6050
+  readonly code = 6050
+  readonly name = "SlotIndexNotOwned"
+  readonly msg = "Slot index page is not owned by the program"
+
+  constructor(readonly logs?: string[]) {
+    super("6050: Slot index page is not owned by the program")
+  }
+}
+
+export class SolmapReserved extends Error {
+  static readonly code = 6051
+  readonly code = 6051
+  readonly name = "SolmapReserved"
+  readonly msg = "Solmap is reserved for a designated claimant"
+
+  constructor(readonly logs?: string[]) {
+    super("6051: Solmap is reserved for a designated claimant")
+  }
+}
+
+export class RandomDrawPageMissing extends Error {
+  static readonly code = 6052
+  readonly code = 6052
+  readonly name = "RandomDrawPageMissing"
+  readonly msg = "A slot index page the random draw scans is missing"
+
+  constructor(readonly logs?: string[]) {
+    super("6052: A slot index page the random draw scans is missing")
+  }
+}
+
+export class SeasonOverlap extends Error {
+  static readonly code = 6053
+  readonly code = 6053
+  readonly name = "SeasonOverlap"
+  readonly msg = "Season range overlaps an existing season"
+
+  constructor(readonly logs?: string[]) {
+    super("6053: Season range overlaps an existing season")
+  }
+}
+
+export class SeasonNotWritable extends Error {
+  static readonly code = 6054
+  readonly code = 6054
+  readonly name = "SeasonNotWritable"
+  readonly msg = "Season must be writable when its pricing counts mints"
+
+  constructor(readonly logs?: string[]) {
+    super("6054: Season must be writable when its pricing counts mints")
+  }
+}
+
+export class SlotIndexVersionMismatch extends Error {
+  static readonly code = 6055
+  readonly code = 6055
+  readonly name = "SlotIndexVersionMismatch"
+  readonly msg = "Slot index page has an older layout version"
+
+  constructor(readonly logs?: string[]) {
+    super("6055: Slot index page has an older layout version")
+  }
+}
+
 export function fromCode(code: number, logs?: string[]): CustomError | null {
   switch (code) {
     case 6000:
@@ -44,6 +695,112 @@ export function fromCode(code: number, logs?: string[]): CustomError | null {
       return new InvalidSolmapNumber(logs)
     case 6002:
       return new SolmapAlreadyMinted(logs)
+    case 6003:
+      return new InvalidSolmapNFT(logs)
+    case 6004:
+      return new PublicMintDisabled(logs)
+    case 6005:
+      return new Unauthorized(logs)
+    case 6006:
+      return new UriTooLong(logs)
+    case 6007:
+      return new SeasonClosed(logs)
+    case 6008:
+      return new InvalidSeasonRange(logs)
+    case 6009:
+      return new SlotIndexPageAlreadyInitialized(logs)
+    case 6010:
+      return new SolmapBeyondIndexCapacity(logs)
+    case 6011:
+      return new SolmapNotMinted(logs)
+    case 6012:
+      return new InvalidGovernanceMembers(logs)
+    case 6013:
+      return new InvalidGovernanceThreshold(logs)
+    case 6014:
+      return new NotGovernanceMember(logs)
+    case 6015:
+      return new ProposalTooLarge(logs)
+    case 6016:
+      return new ProposalAlreadyApproved(logs)
+    case 6017:
+      return new ProposalAlreadyExecuted(logs)
+    case 6018:
+      return new ProposalThresholdNotMet(logs)
+    case 6019:
+      return new ProposalAccountMissing(logs)
+    case 6020:
+      return new InvalidTimelock(logs)
+    case 6021:
+      return new AuthorityTimelockActive(logs)
+    case 6022:
+      return new InvalidReservationRange(logs)
+    case 6023:
+      return new SolmapNotReserved(logs)
+    case 6024:
+      return new SolmapAlreadyFinalized(logs)
+    case 6025:
+      return new ReservationExpired(logs)
+    case 6026:
+      return new ReservationNotExpired(logs)
+    case 6027:
+      return new InvalidCommitment(logs)
+    case 6028:
+      return new RevealTooEarly(logs)
+    case 6029:
+      return new CommitRevealRequired(logs)
+    case 6030:
+      return new InvalidAllowlistProof(logs)
+    case 6031:
+      return new AllowlistQuotaExceeded(logs)
+    case 6032:
+      return new WalletMintLimitReached(logs)
+    case 6033:
+      return new InvalidPricing(logs)
+    case 6034:
+      return new InvalidFeeSplit(logs)
+    case 6035:
+      return new FeeRecipientMismatch(logs)
+    case 6036:
+      return new FeeTokenNotAccepted(logs)
+    case 6037:
+      return new InvalidFeeTokenAccounts(logs)
+    case 6038:
+      return new InvalidReferralShare(logs)
+    case 6039:
+      return new InvalidReferrer(logs)
+    case 6040:
+      return new NoSolmapAvailable(logs)
+    case 6041:
+      return new ReservationNotHeld(logs)
+    case 6042:
+      return new InvalidAuction(logs)
+    case 6043:
+      return new AuctionNotOpen(logs)
+    case 6044:
+      return new BidTooLow(logs)
+    case 6045:
+      return new BidderMismatch(logs)
+    case 6046:
+      return new AuctionNotOver(logs)
+    case 6047:
+      return new AuctionHasNoBids(logs)
+    case 6048:
+      return new AuctionHasBids(logs)
+    case 6049:
+      return new SlotHashUnavailable(logs)
+    case 6050:
+      return new SlotIndexNotOwned(logs)
+    case 6051:
+      return new SolmapReserved(logs)
+    case 6052:
+      return new RandomDrawPageMissing(logs)
+    case 6053:
+      return new SeasonOverlap(logs)
+    case 6054:
+      return new SeasonNotWritable(logs)
+    case 6055:
+      return new SlotIndexVersionMismatch(logs)
   }
 
   return null
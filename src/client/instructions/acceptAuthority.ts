@@ -0,0 +1,24 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AcceptAuthorityAccounts {
+  newAdmin: PublicKey
+  config: PublicKey
+}
+
+export function acceptAuthority(
+  accounts: AcceptAuthorityAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.newAdmin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([107, 86, 198, 91, 33, 12, 107, 160])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -1,10 +1,13 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface AddMccAccounts {
   authority: PublicKey
+  payer: PublicKey
+  config: PublicKey
   mint: PublicKey
   metadata: PublicKey
   mcc: PublicKey
@@ -23,17 +26,19 @@ export function addMcc(
   programId: PublicKey = PROGRAM_ID
 ) {
   const keys: Array<AccountMeta> = [
-    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
     { pubkey: accounts.mint, isSigner: false, isWritable: false },
     { pubkey: accounts.metadata, isSigner: false, isWritable: true },
     { pubkey: accounts.mcc, isSigner: false, isWritable: false },
-    { pubkey: accounts.collectionMetadata, isSigner: false, isWritable: true },
+    { pubkey: accounts.collectionMetadata, isSigner: false, isWritable: false },
     {
       pubkey: accounts.collectionMasterEdition,
       isSigner: false,
       isWritable: false,
     },
-    { pubkey: accounts.fvca, isSigner: false, isWritable: true },
+    { pubkey: accounts.fvca, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.sysvarInstructions, isSigner: false, isWritable: false },
     {
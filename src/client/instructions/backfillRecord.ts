@@ -0,0 +1,59 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface BackfillRecordArgs {
+  solmapNumber: BN
+  args: types.BackfillRecordArgsFields
+}
+
+export interface BackfillRecordAccounts {
+  authority: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  slotIndex: PublicKey
+  solmapRecord: PublicKey
+  mint: PublicKey
+  metadata: PublicKey
+  fvca: PublicKey
+  inscriptionV3: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("solmapNumber"),
+  types.BackfillRecordArgs.layout("args"),
+])
+
+export function backfillRecord(
+  args: BackfillRecordArgs,
+  accounts: BackfillRecordAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.slotIndex, isSigner: false, isWritable: false },
+    { pubkey: accounts.solmapRecord, isSigner: false, isWritable: true },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.metadata, isSigner: false, isWritable: false },
+    { pubkey: accounts.fvca, isSigner: false, isWritable: false },
+    { pubkey: accounts.inscriptionV3, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([47, 234, 193, 62, 114, 243, 131, 211])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      solmapNumber: args.solmapNumber,
+      args: types.BackfillRecordArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface BidArgs {
+  amount: BN
+}
+
+export interface BidAccounts {
+  bidder: PublicKey
+  auction: PublicKey
+  previousBidder?: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("amount")])
+
+export function bid(
+  args: BidArgs,
+  accounts: BidAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.bidder, isSigner: true, isWritable: true },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    accounts.previousBidder
+      ? { pubkey: accounts.previousBidder, isSigner: false, isWritable: true }
+      : { pubkey: programId, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([199, 56, 85, 38, 146, 243, 37, 158])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
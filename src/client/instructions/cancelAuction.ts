@@ -0,0 +1,28 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CancelAuctionAccounts {
+  admin: PublicKey
+  config: PublicKey
+  auction: PublicKey
+  slotIndex: PublicKey
+}
+
+export function cancelAuction(
+  accounts: CancelAuctionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([156, 43, 197, 110, 218, 105, 143, 182])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,40 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CommitSolmapArgs {
+  hash: Array<number>
+}
+
+export interface CommitSolmapAccounts {
+  owner: PublicKey
+  commitment: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.array(borsh.u8(), 32, "hash")])
+
+export function commitSolmap(
+  args: CommitSolmapArgs,
+  accounts: CommitSolmapAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.commitment, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([20, 179, 16, 204, 133, 97, 216, 157])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      hash: args.hash,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,53 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CreateAuctionArgs {
+  solmap: BN
+  args: types.AuctionArgsFields
+}
+
+export interface CreateAuctionAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  season: PublicKey
+  slotIndex: PublicKey
+  auction: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("solmap"),
+  types.AuctionArgs.layout("args"),
+])
+
+export function createAuction(
+  args: CreateAuctionArgs,
+  accounts: CreateAuctionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: false },
+    { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([234, 6, 201, 246, 47, 219, 176, 107])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      solmap: args.solmap,
+      args: types.AuctionArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
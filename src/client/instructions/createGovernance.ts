@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CreateGovernanceArgs {
+  args: types.GovernanceArgsFields
+}
+
+export interface CreateGovernanceAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  governance: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.GovernanceArgs.layout("args")])
+
+export function createGovernance(
+  args: CreateGovernanceArgs,
+  accounts: CreateGovernanceAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+    { pubkey: accounts.governance, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([134, 46, 105, 91, 27, 91, 99, 157])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.GovernanceArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,49 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CreateSeasonArgs {
+  id: number
+  args: types.SeasonArgsFields
+}
+
+export interface CreateSeasonAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  season: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u16("id"),
+  types.SeasonArgs.layout("args"),
+])
+
+export function createSeason(
+  args: CreateSeasonArgs,
+  accounts: CreateSeasonAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+    { pubkey: accounts.season, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([38, 108, 29, 127, 60, 126, 101, 3])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      id: args.id,
+      args: types.SeasonArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,28 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ExecuteProposalAccounts {
+  executor: PublicKey
+  governance: PublicKey
+  proposal: PublicKey
+  solmapProgram: PublicKey
+}
+
+export function executeProposal(
+  accounts: ExecuteProposalAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.executor, isSigner: true, isWritable: false },
+    { pubkey: accounts.governance, isSigner: false, isWritable: false },
+    { pubkey: accounts.proposal, isSigner: false, isWritable: true },
+    { pubkey: accounts.solmapProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([186, 60, 116, 133, 108, 128, 111, 28])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
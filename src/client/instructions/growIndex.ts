@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GrowIndexArgs {
+  solmapNumber: BN
+}
+
+export interface GrowIndexAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  slotIndex: PublicKey
+  legacySlotIndex: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("solmapNumber")])
+
+export function growIndex(
+  args: GrowIndexArgs,
+  accounts: GrowIndexAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.legacySlotIndex, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([222, 32, 78, 153, 148, 86, 108, 168])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      solmapNumber: args.solmapNumber,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
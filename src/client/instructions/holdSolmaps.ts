@@ -0,0 +1,56 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface HoldSolmapsArgs {
+  start: BN
+  count: number
+  claimant: PublicKey
+}
+
+export interface HoldSolmapsAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  season: PublicKey
+  slotIndex: PublicKey
+  reservation: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("start"),
+  borsh.u8("count"),
+  borsh.publicKey("claimant"),
+])
+
+export function holdSolmaps(
+  args: HoldSolmapsArgs,
+  accounts: HoldSolmapsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: false },
+    { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.reservation, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([28, 221, 70, 236, 39, 102, 49, 206])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      start: args.start,
+      count: args.count,
+      claimant: args.claimant,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
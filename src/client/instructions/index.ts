@@ -1,6 +1,122 @@
 export { initIndex } from "./initIndex"
-export type { InitIndexAccounts } from "./initIndex"
+export type { InitIndexArgs, InitIndexAccounts } from "./initIndex"
+export { growIndex } from "./growIndex"
+export type { GrowIndexArgs, GrowIndexAccounts } from "./growIndex"
+export { sweepFeeVault } from "./sweepFeeVault"
+export type { SweepFeeVaultArgs, SweepFeeVaultAccounts } from "./sweepFeeVault"
+export { setFeeToken } from "./setFeeToken"
+export type { SetFeeTokenArgs, SetFeeTokenAccounts } from "./setFeeToken"
+export { setFeeSplit } from "./setFeeSplit"
+export type { SetFeeSplitArgs, SetFeeSplitAccounts } from "./setFeeSplit"
 export { addMcc } from "./addMcc"
 export type { AddMccAccounts } from "./addMcc"
+export { verifySolmap } from "./verifySolmap"
+export type { VerifySolmapAccounts } from "./verifySolmap"
+export { quote } from "./quote"
+export type { QuoteArgs, QuoteAccounts } from "./quote"
+export { backfillRecord } from "./backfillRecord"
+export type {
+  BackfillRecordArgs,
+  BackfillRecordAccounts,
+} from "./backfillRecord"
+export { initializeConfig } from "./initializeConfig"
+export type {
+  InitializeConfigArgs,
+  InitializeConfigAccounts,
+} from "./initializeConfig"
+export { updateConfig } from "./updateConfig"
+export type { UpdateConfigArgs, UpdateConfigAccounts } from "./updateConfig"
+export { proposeAuthority } from "./proposeAuthority"
+export type {
+  ProposeAuthorityArgs,
+  ProposeAuthorityAccounts,
+} from "./proposeAuthority"
+export { acceptAuthority } from "./acceptAuthority"
+export type { AcceptAuthorityAccounts } from "./acceptAuthority"
+export { setCollectionUpdateAuthority } from "./setCollectionUpdateAuthority"
+export type {
+  SetCollectionUpdateAuthorityArgs,
+  SetCollectionUpdateAuthorityAccounts,
+} from "./setCollectionUpdateAuthority"
+export { setPublicMint } from "./setPublicMint"
+export type { SetPublicMintArgs, SetPublicMintAccounts } from "./setPublicMint"
+export { createGovernance } from "./createGovernance"
+export type {
+  CreateGovernanceArgs,
+  CreateGovernanceAccounts,
+} from "./createGovernance"
+export { setGovernance } from "./setGovernance"
+export type { SetGovernanceArgs, SetGovernanceAccounts } from "./setGovernance"
+export { createProposal } from "./createProposal"
+export type {
+  CreateProposalArgs,
+  CreateProposalAccounts,
+} from "./createProposal"
+export { approveProposal } from "./approveProposal"
+export type { ApproveProposalAccounts } from "./approveProposal"
+export { executeProposal } from "./executeProposal"
+export type { ExecuteProposalAccounts } from "./executeProposal"
+export { createSeason } from "./createSeason"
+export type { CreateSeasonArgs, CreateSeasonAccounts } from "./createSeason"
+export { openSeason } from "./openSeason"
+export type { OpenSeasonAccounts } from "./openSeason"
+export { closeSeason } from "./closeSeason"
+export type { CloseSeasonAccounts } from "./closeSeason"
+export { setSeasonAllowlist } from "./setSeasonAllowlist"
+export type {
+  SetSeasonAllowlistArgs,
+  SetSeasonAllowlistAccounts,
+} from "./setSeasonAllowlist"
+export { setSeasonPricing } from "./setSeasonPricing"
+export type {
+  SetSeasonPricingArgs,
+  SetSeasonPricingAccounts,
+} from "./setSeasonPricing"
+export { setSeasonWalletLimits } from "./setSeasonWalletLimits"
+export type {
+  SetSeasonWalletLimitsArgs,
+  SetSeasonWalletLimitsAccounts,
+} from "./setSeasonWalletLimits"
 export { mint } from "./mint"
 export type { MintArgs, MintAccounts } from "./mint"
+export { mintPda } from "./mintPda"
+export type { MintPdaArgs, MintPdaAccounts } from "./mintPda"
+export { reserveSolmap } from "./reserveSolmap"
+export type { ReserveSolmapArgs, ReserveSolmapAccounts } from "./reserveSolmap"
+export { reserveSolmaps } from "./reserveSolmaps"
+export type {
+  ReserveSolmapsArgs,
+  ReserveSolmapsAccounts,
+} from "./reserveSolmaps"
+export { releaseReservation } from "./releaseReservation"
+export type { ReleaseReservationAccounts } from "./releaseReservation"
+export { mintRandom } from "./mintRandom"
+export type { MintRandomAccounts } from "./mintRandom"
+export { reserveAllowlist } from "./reserveAllowlist"
+export type {
+  ReserveAllowlistArgs,
+  ReserveAllowlistAccounts,
+} from "./reserveAllowlist"
+export { commitSolmap } from "./commitSolmap"
+export type { CommitSolmapArgs, CommitSolmapAccounts } from "./commitSolmap"
+export { revealSolmap } from "./revealSolmap"
+export type { RevealSolmapArgs, RevealSolmapAccounts } from "./revealSolmap"
+export { cancelCommitment } from "./cancelCommitment"
+export type { CancelCommitmentAccounts } from "./cancelCommitment"
+export { finalizeSolmap } from "./finalizeSolmap"
+export type {
+  FinalizeSolmapArgs,
+  FinalizeSolmapAccounts,
+} from "./finalizeSolmap"
+export { holdSolmaps } from "./holdSolmaps"
+export type { HoldSolmapsArgs, HoldSolmapsAccounts } from "./holdSolmaps"
+export { claimReserved } from "./claimReserved"
+export type { ClaimReservedArgs, ClaimReservedAccounts } from "./claimReserved"
+export { createAuction } from "./createAuction"
+export type { CreateAuctionArgs, CreateAuctionAccounts } from "./createAuction"
+export { bid } from "./bid"
+export type { BidArgs, BidAccounts } from "./bid"
+export { settleAuction } from "./settleAuction"
+export type { SettleAuctionAccounts } from "./settleAuction"
+export { cancelAuction } from "./cancelAuction"
+export type { CancelAuctionAccounts } from "./cancelAuction"
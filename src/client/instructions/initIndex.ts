@@ -1,25 +1,46 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
+export interface InitIndexArgs {
+  page: number
+}
+
 export interface InitIndexAccounts {
+  admin: PublicKey
   payer: PublicKey
+  config: PublicKey
   slotIndex: PublicKey
+  legacySlotIndex: PublicKey
   systemProgram: PublicKey
 }
 
+export const layout = borsh.struct([borsh.u32("page")])
+
 export function initIndex(
+  args: InitIndexArgs,
   accounts: InitIndexAccounts,
   programId: PublicKey = PROGRAM_ID
 ) {
   const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
     { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
     { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.legacySlotIndex, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([206, 236, 58, 58, 171, 221, 237, 57])
-  const data = identifier
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      page: args.page,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
   const ix = new TransactionInstruction({ keys, programId, data })
   return ix
 }
@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface InitializeConfigArgs {
+  args: types.ConfigArgsFields
+}
+
+export interface InitializeConfigAccounts {
+  authority: PublicKey
+  config: PublicKey
+  program: PublicKey
+  programData: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.ConfigArgs.layout("args")])
+
+export function initializeConfig(
+  args: InitializeConfigArgs,
+  accounts: InitializeConfigAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+    { pubkey: accounts.programData, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([208, 127, 21, 1, 194, 190, 196, 70])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.ConfigArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -1,6 +1,7 @@
 import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
 export interface MintArgs {
@@ -9,9 +10,14 @@ export interface MintArgs {
 
 export interface MintAccounts {
   minter: PublicKey
+  recipient: PublicKey
+  config: PublicKey
+  season: PublicKey
   slotIndex: PublicKey
-  treasury: PublicKey
+  feeVault: PublicKey
+  walletMints: PublicKey
   mint: PublicKey
+  solmapRecord: PublicKey
   tokenAccount: PublicKey
   metadata: PublicKey
   masterEdition: PublicKey
@@ -28,6 +34,11 @@ export interface MintAccounts {
   tokenMetadataProgram: PublicKey
   tokenProgram: PublicKey
   associatedTokenProgram: PublicKey
+  feeToken?: PublicKey
+  minterTokenAccount?: PublicKey
+  treasuryTokenAccount?: PublicKey
+  referrer?: PublicKey
+  referralStats?: PublicKey
 }
 
 export const layout = borsh.struct([borsh.u64("solmap")])
@@ -39,9 +50,14 @@ export function mint(
 ) {
   const keys: Array<AccountMeta> = [
     { pubkey: accounts.minter, isSigner: true, isWritable: true },
+    { pubkey: accounts.recipient, isSigner: false, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: false },
     { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
-    { pubkey: accounts.treasury, isSigner: false, isWritable: true },
+    { pubkey: accounts.feeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.walletMints, isSigner: false, isWritable: true },
     { pubkey: accounts.mint, isSigner: true, isWritable: true },
+    { pubkey: accounts.solmapRecord, isSigner: false, isWritable: true },
     { pubkey: accounts.tokenAccount, isSigner: false, isWritable: true },
     { pubkey: accounts.metadata, isSigner: false, isWritable: true },
     { pubkey: accounts.masterEdition, isSigner: false, isWritable: true },
@@ -74,6 +90,29 @@ export function mint(
       isSigner: false,
       isWritable: false,
     },
+    accounts.feeToken
+      ? { pubkey: accounts.feeToken, isSigner: false, isWritable: false }
+      : { pubkey: programId, isSigner: false, isWritable: false },
+    accounts.minterTokenAccount
+      ? {
+          pubkey: accounts.minterTokenAccount,
+          isSigner: false,
+          isWritable: true,
+        }
+      : { pubkey: programId, isSigner: false, isWritable: true },
+    accounts.treasuryTokenAccount
+      ? {
+          pubkey: accounts.treasuryTokenAccount,
+          isSigner: false,
+          isWritable: true,
+        }
+      : { pubkey: programId, isSigner: false, isWritable: true },
+    accounts.referrer
+      ? { pubkey: accounts.referrer, isSigner: false, isWritable: true }
+      : { pubkey: programId, isSigner: false, isWritable: true },
+    accounts.referralStats
+      ? { pubkey: accounts.referralStats, isSigner: false, isWritable: true }
+      : { pubkey: programId, isSigner: false, isWritable: true },
   ]
   const identifier = Buffer.from([51, 57, 225, 47, 182, 146, 137, 166])
   const buffer = Buffer.alloc(1000)
@@ -0,0 +1,36 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MintRandomAccounts {
+  owner: PublicKey
+  config: PublicKey
+  season: PublicKey
+  feeVault: PublicKey
+  walletMints: PublicKey
+  reservation: PublicKey
+  slotHashes: PublicKey
+  systemProgram: PublicKey
+}
+
+export function mintRandom(
+  accounts: MintRandomAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.walletMints, isSigner: false, isWritable: true },
+    { pubkey: accounts.reservation, isSigner: false, isWritable: true },
+    { pubkey: accounts.slotHashes, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([72, 235, 182, 124, 22, 102, 103, 135])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,38 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProposeAuthorityArgs {
+  newAdmin: PublicKey
+}
+
+export interface ProposeAuthorityAccounts {
+  admin: PublicKey
+  config: PublicKey
+}
+
+export const layout = borsh.struct([borsh.publicKey("newAdmin")])
+
+export function proposeAuthority(
+  args: ProposeAuthorityArgs,
+  accounts: ProposeAuthorityAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([20, 148, 236, 198, 76, 119, 99, 142])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      newAdmin: args.newAdmin,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,36 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface QuoteArgs {
+  solmap: BN
+}
+
+export interface QuoteAccounts {
+  season: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u64("solmap")])
+
+export function quote(
+  args: QuoteArgs,
+  accounts: QuoteAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.season, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([149, 42, 109, 247, 134, 146, 213, 123])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      solmap: args.solmap,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
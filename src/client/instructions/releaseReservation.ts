@@ -0,0 +1,28 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ReleaseReservationAccounts {
+  owner: PublicKey
+  reservation: PublicKey
+  slotIndex: PublicKey
+  season: PublicKey
+}
+
+export function releaseReservation(
+  accounts: ReleaseReservationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: false, isWritable: true },
+    { pubkey: accounts.reservation, isSigner: false, isWritable: true },
+    { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.season, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([57, 15, 101, 135, 113, 21, 168, 247])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
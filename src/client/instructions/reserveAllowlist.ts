@@ -0,0 +1,61 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ReserveAllowlistArgs {
+  start: BN
+  count: number
+  quota: number
+  proof: Array<Array<number>>
+}
+
+export interface ReserveAllowlistAccounts {
+  owner: PublicKey
+  config: PublicKey
+  season: PublicKey
+  slotIndex: PublicKey
+  feeVault: PublicKey
+  walletMints: PublicKey
+  reservation: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("start"),
+  borsh.u8("count"),
+  borsh.u32("quota"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "proof"),
+])
+
+export function reserveAllowlist(
+  args: ReserveAllowlistArgs,
+  accounts: ReserveAllowlistAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: false },
+    { pubkey: accounts.slotIndex, isSigner: false, isWritable: true },
+    { pubkey: accounts.feeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.walletMints, isSigner: false, isWritable: true },
+    { pubkey: accounts.reservation, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([175, 140, 210, 230, 234, 199, 100, 0])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      start: args.start,
+      count: args.count,
+      quota: args.quota,
+      proof: args.proof,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
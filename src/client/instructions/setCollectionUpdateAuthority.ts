@@ -0,0 +1,62 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetCollectionUpdateAuthorityArgs {
+  newUpdateAuthority: PublicKey
+}
+
+export interface SetCollectionUpdateAuthorityAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  mcc: PublicKey
+  collectionMetadata: PublicKey
+  collectionMasterEdition: PublicKey
+  fvca: PublicKey
+  systemProgram: PublicKey
+  sysvarInstructions: PublicKey
+  tokenMetadataProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.publicKey("newUpdateAuthority")])
+
+export function setCollectionUpdateAuthority(
+  args: SetCollectionUpdateAuthorityArgs,
+  accounts: SetCollectionUpdateAuthorityAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.mcc, isSigner: false, isWritable: false },
+    { pubkey: accounts.collectionMetadata, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.collectionMasterEdition,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.fvca, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.sysvarInstructions, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.tokenMetadataProgram,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([8, 139, 31, 243, 59, 214, 80, 110])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      newUpdateAuthority: args.newUpdateAuthority,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
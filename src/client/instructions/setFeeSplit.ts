@@ -0,0 +1,48 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetFeeSplitArgs {
+  recipients: Array<types.FeeRecipientFields>
+}
+
+export interface SetFeeSplitAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  feeSplit: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.vec(types.FeeRecipient.layout(), "recipients"),
+])
+
+export function setFeeSplit(
+  args: SetFeeSplitArgs,
+  accounts: SetFeeSplitAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeSplit, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([248, 186, 180, 130, 109, 11, 93, 203])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      recipients: args.recipients.map((item) =>
+        types.FeeRecipient.toEncodable(item)
+      ),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetFeeTokenArgs {
+  args: types.FeeTokenArgsFields
+}
+
+export interface SetFeeTokenAccounts {
+  admin: PublicKey
+  payer: PublicKey
+  config: PublicKey
+  mint: PublicKey
+  feeToken: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.FeeTokenArgs.layout("args")])
+
+export function setFeeToken(
+  args: SetFeeTokenArgs,
+  accounts: SetFeeTokenAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeToken, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([52, 90, 163, 123, 220, 202, 90, 217])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.FeeTokenArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
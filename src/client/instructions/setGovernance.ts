@@ -0,0 +1,40 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetGovernanceArgs {
+  args: types.GovernanceArgsFields
+}
+
+export interface SetGovernanceAccounts {
+  admin: PublicKey
+  config: PublicKey
+  governance: PublicKey
+}
+
+export const layout = borsh.struct([types.GovernanceArgs.layout("args")])
+
+export function setGovernance(
+  args: SetGovernanceArgs,
+  accounts: SetGovernanceAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.governance, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([34, 71, 128, 245, 179, 42, 140, 137])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.GovernanceArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
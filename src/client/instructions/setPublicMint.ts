@@ -0,0 +1,38 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetPublicMintArgs {
+  enabled: boolean
+}
+
+export interface SetPublicMintAccounts {
+  admin: PublicKey
+  config: PublicKey
+}
+
+export const layout = borsh.struct([borsh.bool("enabled")])
+
+export function setPublicMint(
+  args: SetPublicMintArgs,
+  accounts: SetPublicMintAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([72, 43, 92, 13, 53, 130, 193, 49])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      enabled: args.enabled,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
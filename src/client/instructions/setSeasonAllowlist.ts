@@ -0,0 +1,40 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetSeasonAllowlistArgs {
+  args: types.AllowlistArgsFields
+}
+
+export interface SetSeasonAllowlistAccounts {
+  admin: PublicKey
+  config: PublicKey
+  season: PublicKey
+}
+
+export const layout = borsh.struct([types.AllowlistArgs.layout("args")])
+
+export function setSeasonAllowlist(
+  args: SetSeasonAllowlistArgs,
+  accounts: SetSeasonAllowlistAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([137, 34, 89, 176, 159, 178, 116, 235])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.AllowlistArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,40 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetSeasonPricingArgs {
+  pricing: types.PricingModeKind
+}
+
+export interface SetSeasonPricingAccounts {
+  admin: PublicKey
+  config: PublicKey
+  season: PublicKey
+}
+
+export const layout = borsh.struct([types.PricingMode.layout("pricing")])
+
+export function setSeasonPricing(
+  args: SetSeasonPricingArgs,
+  accounts: SetSeasonPricingAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([101, 45, 247, 217, 147, 155, 75, 212])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      pricing: args.pricing.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
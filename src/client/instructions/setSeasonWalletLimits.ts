@@ -0,0 +1,40 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetSeasonWalletLimitsArgs {
+  args: types.WalletLimitArgsFields
+}
+
+export interface SetSeasonWalletLimitsAccounts {
+  admin: PublicKey
+  config: PublicKey
+  season: PublicKey
+}
+
+export const layout = borsh.struct([types.WalletLimitArgs.layout("args")])
+
+export function setSeasonWalletLimits(
+  args: SetSeasonWalletLimitsArgs,
+  accounts: SetSeasonWalletLimitsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.season, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([214, 247, 57, 31, 95, 137, 44, 160])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.WalletLimitArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
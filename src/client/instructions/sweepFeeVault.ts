@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SweepFeeVaultArgs {
+  shard: number
+}
+
+export interface SweepFeeVaultAccounts {
+  config: PublicKey
+  feeVault: PublicKey
+  treasury: PublicKey
+  feeSplit: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([borsh.u8("shard")])
+
+export function sweepFeeVault(
+  args: SweepFeeVaultArgs,
+  accounts: SweepFeeVaultAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.treasury, isSigner: false, isWritable: true },
+    { pubkey: accounts.feeSplit, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([254, 87, 106, 70, 153, 200, 235, 150])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      shard: args.shard,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
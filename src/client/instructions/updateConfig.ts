@@ -0,0 +1,38 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UpdateConfigArgs {
+  args: types.UpdateConfigArgsFields
+}
+
+export interface UpdateConfigAccounts {
+  admin: PublicKey
+  config: PublicKey
+}
+
+export const layout = borsh.struct([types.UpdateConfigArgs.layout("args")])
+
+export function updateConfig(
+  args: UpdateConfigArgs,
+  accounts: UpdateConfigAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([29, 158, 252, 191, 10, 83, 219, 99])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.UpdateConfigArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,28 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VerifySolmapAccounts {
+  config: PublicKey
+  mint: PublicKey
+  metadata: PublicKey
+  fvca: PublicKey
+}
+
+export function verifySolmap(
+  accounts: VerifySolmapAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.metadata, isSigner: false, isWritable: false },
+    { pubkey: accounts.fvca, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([253, 44, 175, 228, 67, 202, 205, 26])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
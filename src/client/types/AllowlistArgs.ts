@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AllowlistArgsFields {
+  root: Array<number>
+  startTime: BN
+}
+
+export interface AllowlistArgsJSON {
+  root: Array<number>
+  startTime: string
+}
+
+export class AllowlistArgs {
+  readonly root: Array<number>
+  readonly startTime: BN
+
+  constructor(fields: AllowlistArgsFields) {
+    this.root = fields.root
+    this.startTime = fields.startTime
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.array(borsh.u8(), 32, "root"), borsh.i64("startTime")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AllowlistArgs({
+      root: obj.root,
+      startTime: obj.startTime,
+    })
+  }
+
+  static toEncodable(fields: AllowlistArgsFields) {
+    return {
+      root: fields.root,
+      startTime: fields.startTime,
+    }
+  }
+
+  toJSON(): AllowlistArgsJSON {
+    return {
+      root: this.root,
+      startTime: this.startTime.toString(),
+    }
+  }
+
+  static fromJSON(obj: AllowlistArgsJSON): AllowlistArgs {
+    return new AllowlistArgs({
+      root: obj.root,
+      startTime: new BN(obj.startTime),
+    })
+  }
+
+  toEncodable() {
+    return AllowlistArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,85 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AuctionArgsFields {
+  kind: types.AuctionKindKind
+  reservePrice: BN
+  startTime: BN
+  endTime: BN
+}
+
+export interface AuctionArgsJSON {
+  kind: types.AuctionKindJSON
+  reservePrice: string
+  startTime: string
+  endTime: string
+}
+
+export class AuctionArgs {
+  readonly kind: types.AuctionKindKind
+  readonly reservePrice: BN
+  readonly startTime: BN
+  readonly endTime: BN
+
+  constructor(fields: AuctionArgsFields) {
+    this.kind = fields.kind
+    this.reservePrice = fields.reservePrice
+    this.startTime = fields.startTime
+    this.endTime = fields.endTime
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.AuctionKind.layout("kind"),
+        borsh.u64("reservePrice"),
+        borsh.i64("startTime"),
+        borsh.i64("endTime"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AuctionArgs({
+      kind: types.AuctionKind.fromDecoded(obj.kind),
+      reservePrice: obj.reservePrice,
+      startTime: obj.startTime,
+      endTime: obj.endTime,
+    })
+  }
+
+  static toEncodable(fields: AuctionArgsFields) {
+    return {
+      kind: fields.kind.toEncodable(),
+      reservePrice: fields.reservePrice,
+      startTime: fields.startTime,
+      endTime: fields.endTime,
+    }
+  }
+
+  toJSON(): AuctionArgsJSON {
+    return {
+      kind: this.kind.toJSON(),
+      reservePrice: this.reservePrice.toString(),
+      startTime: this.startTime.toString(),
+      endTime: this.endTime.toString(),
+    }
+  }
+
+  static fromJSON(obj: AuctionArgsJSON): AuctionArgs {
+    return new AuctionArgs({
+      kind: types.AuctionKind.fromJSON(obj.kind),
+      reservePrice: new BN(obj.reservePrice),
+      startTime: new BN(obj.startTime),
+      endTime: new BN(obj.endTime),
+    })
+  }
+
+  toEncodable() {
+    return AuctionArgs.toEncodable(this)
+  }
+}
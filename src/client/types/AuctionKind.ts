@@ -0,0 +1,142 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export type DutchFields = {
+  startPrice: BN
+}
+export type DutchValue = {
+  startPrice: BN
+}
+
+export interface DutchJSON {
+  kind: "Dutch"
+  value: {
+    startPrice: string
+  }
+}
+
+export class Dutch {
+  static readonly discriminator = 0
+  static readonly kind = "Dutch"
+  readonly discriminator = 0
+  readonly kind = "Dutch"
+  readonly value: DutchValue
+
+  constructor(value: DutchFields) {
+    this.value = {
+      startPrice: value.startPrice,
+    }
+  }
+
+  toJSON(): DutchJSON {
+    return {
+      kind: "Dutch",
+      value: {
+        startPrice: this.value.startPrice.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      Dutch: {
+        startPrice: this.value.startPrice,
+      },
+    }
+  }
+}
+
+export type EnglishFields = {
+  minIncrement: BN
+}
+export type EnglishValue = {
+  minIncrement: BN
+}
+
+export interface EnglishJSON {
+  kind: "English"
+  value: {
+    minIncrement: string
+  }
+}
+
+export class English {
+  static readonly discriminator = 1
+  static readonly kind = "English"
+  readonly discriminator = 1
+  readonly kind = "English"
+  readonly value: EnglishValue
+
+  constructor(value: EnglishFields) {
+    this.value = {
+      minIncrement: value.minIncrement,
+    }
+  }
+
+  toJSON(): EnglishJSON {
+    return {
+      kind: "English",
+      value: {
+        minIncrement: this.value.minIncrement.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      English: {
+        minIncrement: this.value.minIncrement,
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.AuctionKindKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Dutch" in obj) {
+    const val = obj["Dutch"]
+    return new Dutch({
+      startPrice: val["startPrice"],
+    })
+  }
+  if ("English" in obj) {
+    const val = obj["English"]
+    return new English({
+      minIncrement: val["minIncrement"],
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.AuctionKindJSON): types.AuctionKindKind {
+  switch (obj.kind) {
+    case "Dutch": {
+      return new Dutch({
+        startPrice: new BN(obj.value.startPrice),
+      })
+    }
+    case "English": {
+      return new English({
+        minIncrement: new BN(obj.value.minIncrement),
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([borsh.u64("startPrice")], "Dutch"),
+    borsh.struct([borsh.u64("minIncrement")], "English"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,85 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface BackfillRecordArgsFields {
+  owner: PublicKey
+  payer: PublicKey
+  slot: BN
+  timestamp: BN
+}
+
+export interface BackfillRecordArgsJSON {
+  owner: string
+  payer: string
+  slot: string
+  timestamp: string
+}
+
+export class BackfillRecordArgs {
+  readonly owner: PublicKey
+  readonly payer: PublicKey
+  readonly slot: BN
+  readonly timestamp: BN
+
+  constructor(fields: BackfillRecordArgsFields) {
+    this.owner = fields.owner
+    this.payer = fields.payer
+    this.slot = fields.slot
+    this.timestamp = fields.timestamp
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("owner"),
+        borsh.publicKey("payer"),
+        borsh.u64("slot"),
+        borsh.i64("timestamp"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new BackfillRecordArgs({
+      owner: obj.owner,
+      payer: obj.payer,
+      slot: obj.slot,
+      timestamp: obj.timestamp,
+    })
+  }
+
+  static toEncodable(fields: BackfillRecordArgsFields) {
+    return {
+      owner: fields.owner,
+      payer: fields.payer,
+      slot: fields.slot,
+      timestamp: fields.timestamp,
+    }
+  }
+
+  toJSON(): BackfillRecordArgsJSON {
+    return {
+      owner: this.owner.toString(),
+      payer: this.payer.toString(),
+      slot: this.slot.toString(),
+      timestamp: this.timestamp.toString(),
+    }
+  }
+
+  static fromJSON(obj: BackfillRecordArgsJSON): BackfillRecordArgs {
+    return new BackfillRecordArgs({
+      owner: new PublicKey(obj.owner),
+      payer: new PublicKey(obj.payer),
+      slot: new BN(obj.slot),
+      timestamp: new BN(obj.timestamp),
+    })
+  }
+
+  toEncodable() {
+    return BackfillRecordArgs.toEncodable(this)
+  }
+}
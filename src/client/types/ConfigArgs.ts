@@ -0,0 +1,103 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ConfigArgsFields {
+  admin: PublicKey
+  treasury: PublicKey
+  mcc: PublicKey
+  adminTimelock: BN
+  reservationExpirySlots: BN
+  revealDelaySlots: BN
+}
+
+export interface ConfigArgsJSON {
+  admin: string
+  treasury: string
+  mcc: string
+  adminTimelock: string
+  reservationExpirySlots: string
+  revealDelaySlots: string
+}
+
+export class ConfigArgs {
+  readonly admin: PublicKey
+  readonly treasury: PublicKey
+  readonly mcc: PublicKey
+  readonly adminTimelock: BN
+  readonly reservationExpirySlots: BN
+  readonly revealDelaySlots: BN
+
+  constructor(fields: ConfigArgsFields) {
+    this.admin = fields.admin
+    this.treasury = fields.treasury
+    this.mcc = fields.mcc
+    this.adminTimelock = fields.adminTimelock
+    this.reservationExpirySlots = fields.reservationExpirySlots
+    this.revealDelaySlots = fields.revealDelaySlots
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("admin"),
+        borsh.publicKey("treasury"),
+        borsh.publicKey("mcc"),
+        borsh.i64("adminTimelock"),
+        borsh.u64("reservationExpirySlots"),
+        borsh.u64("revealDelaySlots"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ConfigArgs({
+      admin: obj.admin,
+      treasury: obj.treasury,
+      mcc: obj.mcc,
+      adminTimelock: obj.adminTimelock,
+      reservationExpirySlots: obj.reservationExpirySlots,
+      revealDelaySlots: obj.revealDelaySlots,
+    })
+  }
+
+  static toEncodable(fields: ConfigArgsFields) {
+    return {
+      admin: fields.admin,
+      treasury: fields.treasury,
+      mcc: fields.mcc,
+      adminTimelock: fields.adminTimelock,
+      reservationExpirySlots: fields.reservationExpirySlots,
+      revealDelaySlots: fields.revealDelaySlots,
+    }
+  }
+
+  toJSON(): ConfigArgsJSON {
+    return {
+      admin: this.admin.toString(),
+      treasury: this.treasury.toString(),
+      mcc: this.mcc.toString(),
+      adminTimelock: this.adminTimelock.toString(),
+      reservationExpirySlots: this.reservationExpirySlots.toString(),
+      revealDelaySlots: this.revealDelaySlots.toString(),
+    }
+  }
+
+  static fromJSON(obj: ConfigArgsJSON): ConfigArgs {
+    return new ConfigArgs({
+      admin: new PublicKey(obj.admin),
+      treasury: new PublicKey(obj.treasury),
+      mcc: new PublicKey(obj.mcc),
+      adminTimelock: new BN(obj.adminTimelock),
+      reservationExpirySlots: new BN(obj.reservationExpirySlots),
+      revealDelaySlots: new BN(obj.revealDelaySlots),
+    })
+  }
+
+  toEncodable() {
+    return ConfigArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface FeeRecipientFields {
+  address: PublicKey
+  bps: number
+}
+
+export interface FeeRecipientJSON {
+  address: string
+  bps: number
+}
+
+export class FeeRecipient {
+  readonly address: PublicKey
+  readonly bps: number
+
+  constructor(fields: FeeRecipientFields) {
+    this.address = fields.address
+    this.bps = fields.bps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.publicKey("address"), borsh.u16("bps")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new FeeRecipient({
+      address: obj.address,
+      bps: obj.bps,
+    })
+  }
+
+  static toEncodable(fields: FeeRecipientFields) {
+    return {
+      address: fields.address,
+      bps: fields.bps,
+    }
+  }
+
+  toJSON(): FeeRecipientJSON {
+    return {
+      address: this.address.toString(),
+      bps: this.bps,
+    }
+  }
+
+  static fromJSON(obj: FeeRecipientJSON): FeeRecipient {
+    return new FeeRecipient({
+      address: new PublicKey(obj.address),
+      bps: obj.bps,
+    })
+  }
+
+  toEncodable() {
+    return FeeRecipient.toEncodable(this)
+  }
+}
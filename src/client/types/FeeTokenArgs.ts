@@ -0,0 +1,61 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface FeeTokenArgsFields {
+  amount: BN
+  enabled: boolean
+}
+
+export interface FeeTokenArgsJSON {
+  amount: string
+  enabled: boolean
+}
+
+export class FeeTokenArgs {
+  readonly amount: BN
+  readonly enabled: boolean
+
+  constructor(fields: FeeTokenArgsFields) {
+    this.amount = fields.amount
+    this.enabled = fields.enabled
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("amount"), borsh.bool("enabled")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new FeeTokenArgs({
+      amount: obj.amount,
+      enabled: obj.enabled,
+    })
+  }
+
+  static toEncodable(fields: FeeTokenArgsFields) {
+    return {
+      amount: fields.amount,
+      enabled: fields.enabled,
+    }
+  }
+
+  toJSON(): FeeTokenArgsJSON {
+    return {
+      amount: this.amount.toString(),
+      enabled: this.enabled,
+    }
+  }
+
+  static fromJSON(obj: FeeTokenArgsJSON): FeeTokenArgs {
+    return new FeeTokenArgs({
+      amount: new BN(obj.amount),
+      enabled: obj.enabled,
+    })
+  }
+
+  toEncodable() {
+    return FeeTokenArgs.toEncodable(this)
+  }
+}
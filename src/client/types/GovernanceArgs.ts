@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface GovernanceArgsFields {
+  members: Array<PublicKey>
+  threshold: number
+}
+
+export interface GovernanceArgsJSON {
+  members: Array<string>
+  threshold: number
+}
+
+export class GovernanceArgs {
+  readonly members: Array<PublicKey>
+  readonly threshold: number
+
+  constructor(fields: GovernanceArgsFields) {
+    this.members = fields.members
+    this.threshold = fields.threshold
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.vec(borsh.publicKey(), "members"), borsh.u8("threshold")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GovernanceArgs({
+      members: obj.members,
+      threshold: obj.threshold,
+    })
+  }
+
+  static toEncodable(fields: GovernanceArgsFields) {
+    return {
+      members: fields.members,
+      threshold: fields.threshold,
+    }
+  }
+
+  toJSON(): GovernanceArgsJSON {
+    return {
+      members: this.members.map((item) => item.toString()),
+      threshold: this.threshold,
+    }
+  }
+
+  static fromJSON(obj: GovernanceArgsJSON): GovernanceArgs {
+    return new GovernanceArgs({
+      members: obj.members.map((item) => new PublicKey(item)),
+      threshold: obj.threshold,
+    })
+  }
+
+  toEncodable() {
+    return GovernanceArgs.toEncodable(this)
+  }
+}
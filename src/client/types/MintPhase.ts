@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AllowlistJSON {
+  kind: "Allowlist"
+}
+
+export class Allowlist {
+  static readonly discriminator = 0
+  static readonly kind = "Allowlist"
+  readonly discriminator = 0
+  readonly kind = "Allowlist"
+
+  toJSON(): AllowlistJSON {
+    return {
+      kind: "Allowlist",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Allowlist: {},
+    }
+  }
+}
+
+export interface PublicJSON {
+  kind: "Public"
+}
+
+export class Public {
+  static readonly discriminator = 1
+  static readonly kind = "Public"
+  readonly discriminator = 1
+  readonly kind = "Public"
+
+  toJSON(): PublicJSON {
+    return {
+      kind: "Public",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Public: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.MintPhaseKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Allowlist" in obj) {
+    return new Allowlist()
+  }
+  if ("Public" in obj) {
+    return new Public()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.MintPhaseJSON): types.MintPhaseKind {
+  switch (obj.kind) {
+    case "Allowlist": {
+      return new Allowlist()
+    }
+    case "Public": {
+      return new Public()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Allowlist"),
+    borsh.struct([], "Public"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
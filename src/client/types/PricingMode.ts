@@ -0,0 +1,245 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface FlatJSON {
+  kind: "Flat"
+}
+
+export class Flat {
+  static readonly discriminator = 0
+  static readonly kind = "Flat"
+  readonly discriminator = 0
+  readonly kind = "Flat"
+
+  toJSON(): FlatJSON {
+    return {
+      kind: "Flat",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Flat: {},
+    }
+  }
+}
+
+export type LinearFields = {
+  increment: BN
+}
+export type LinearValue = {
+  increment: BN
+}
+
+export interface LinearJSON {
+  kind: "Linear"
+  value: {
+    increment: string
+  }
+}
+
+export class Linear {
+  static readonly discriminator = 1
+  static readonly kind = "Linear"
+  readonly discriminator = 1
+  readonly kind = "Linear"
+  readonly value: LinearValue
+
+  constructor(value: LinearFields) {
+    this.value = {
+      increment: value.increment,
+    }
+  }
+
+  toJSON(): LinearJSON {
+    return {
+      kind: "Linear",
+      value: {
+        increment: this.value.increment.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      Linear: {
+        increment: this.value.increment,
+      },
+    }
+  }
+}
+
+export type ExponentialFields = {
+  growthBps: number
+  every: number
+}
+export type ExponentialValue = {
+  growthBps: number
+  every: number
+}
+
+export interface ExponentialJSON {
+  kind: "Exponential"
+  value: {
+    growthBps: number
+    every: number
+  }
+}
+
+export class Exponential {
+  static readonly discriminator = 2
+  static readonly kind = "Exponential"
+  readonly discriminator = 2
+  readonly kind = "Exponential"
+  readonly value: ExponentialValue
+
+  constructor(value: ExponentialFields) {
+    this.value = {
+      growthBps: value.growthBps,
+      every: value.every,
+    }
+  }
+
+  toJSON(): ExponentialJSON {
+    return {
+      kind: "Exponential",
+      value: {
+        growthBps: this.value.growthBps,
+        every: this.value.every,
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      Exponential: {
+        growthBps: this.value.growthBps,
+        every: this.value.every,
+      },
+    }
+  }
+}
+
+export type DutchFields = {
+  startFee: BN
+  duration: BN
+}
+export type DutchValue = {
+  startFee: BN
+  duration: BN
+}
+
+export interface DutchJSON {
+  kind: "Dutch"
+  value: {
+    startFee: string
+    duration: string
+  }
+}
+
+export class Dutch {
+  static readonly discriminator = 3
+  static readonly kind = "Dutch"
+  readonly discriminator = 3
+  readonly kind = "Dutch"
+  readonly value: DutchValue
+
+  constructor(value: DutchFields) {
+    this.value = {
+      startFee: value.startFee,
+      duration: value.duration,
+    }
+  }
+
+  toJSON(): DutchJSON {
+    return {
+      kind: "Dutch",
+      value: {
+        startFee: this.value.startFee.toString(),
+        duration: this.value.duration.toString(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      Dutch: {
+        startFee: this.value.startFee,
+        duration: this.value.duration,
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.PricingModeKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Flat" in obj) {
+    return new Flat()
+  }
+  if ("Linear" in obj) {
+    const val = obj["Linear"]
+    return new Linear({
+      increment: val["increment"],
+    })
+  }
+  if ("Exponential" in obj) {
+    const val = obj["Exponential"]
+    return new Exponential({
+      growthBps: val["growthBps"],
+      every: val["every"],
+    })
+  }
+  if ("Dutch" in obj) {
+    const val = obj["Dutch"]
+    return new Dutch({
+      startFee: val["startFee"],
+      duration: val["duration"],
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.PricingModeJSON): types.PricingModeKind {
+  switch (obj.kind) {
+    case "Flat": {
+      return new Flat()
+    }
+    case "Linear": {
+      return new Linear({
+        increment: new BN(obj.value.increment),
+      })
+    }
+    case "Exponential": {
+      return new Exponential({
+        growthBps: obj.value.growthBps,
+        every: obj.value.every,
+      })
+    }
+    case "Dutch": {
+      return new Dutch({
+        startFee: new BN(obj.value.startFee),
+        duration: new BN(obj.value.duration),
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Flat"),
+    borsh.struct([borsh.u64("increment")], "Linear"),
+    borsh.struct([borsh.u16("growthBps"), borsh.u32("every")], "Exponential"),
+    borsh.struct([borsh.u64("startFee"), borsh.i64("duration")], "Dutch"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,76 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ProposalAccountFields {
+  pubkey: PublicKey
+  isSigner: boolean
+  isWritable: boolean
+}
+
+export interface ProposalAccountJSON {
+  pubkey: string
+  isSigner: boolean
+  isWritable: boolean
+}
+
+export class ProposalAccount {
+  readonly pubkey: PublicKey
+  readonly isSigner: boolean
+  readonly isWritable: boolean
+
+  constructor(fields: ProposalAccountFields) {
+    this.pubkey = fields.pubkey
+    this.isSigner = fields.isSigner
+    this.isWritable = fields.isWritable
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("pubkey"),
+        borsh.bool("isSigner"),
+        borsh.bool("isWritable"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ProposalAccount({
+      pubkey: obj.pubkey,
+      isSigner: obj.isSigner,
+      isWritable: obj.isWritable,
+    })
+  }
+
+  static toEncodable(fields: ProposalAccountFields) {
+    return {
+      pubkey: fields.pubkey,
+      isSigner: fields.isSigner,
+      isWritable: fields.isWritable,
+    }
+  }
+
+  toJSON(): ProposalAccountJSON {
+    return {
+      pubkey: this.pubkey.toString(),
+      isSigner: this.isSigner,
+      isWritable: this.isWritable,
+    }
+  }
+
+  static fromJSON(obj: ProposalAccountJSON): ProposalAccount {
+    return new ProposalAccount({
+      pubkey: new PublicKey(obj.pubkey),
+      isSigner: obj.isSigner,
+      isWritable: obj.isWritable,
+    })
+  }
+
+  toEncodable() {
+    return ProposalAccount.toEncodable(this)
+  }
+}
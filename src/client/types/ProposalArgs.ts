@@ -0,0 +1,86 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ProposalArgsFields {
+  accounts: Array<types.ProposalAccountFields>
+  data: Uint8Array
+}
+
+export interface ProposalArgsJSON {
+  accounts: Array<types.ProposalAccountJSON>
+  data: Array<number>
+}
+
+export class ProposalArgs {
+  readonly accounts: Array<types.ProposalAccount>
+  readonly data: Uint8Array
+
+  constructor(fields: ProposalArgsFields) {
+    this.accounts = fields.accounts.map(
+      (item) => new types.ProposalAccount({ ...item })
+    )
+    this.data = fields.data
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.vec(types.ProposalAccount.layout(), "accounts"),
+        borsh.vecU8("data"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ProposalArgs({
+      accounts: obj.accounts.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.ProposalAccount.fromDecoded(item)
+      ),
+      data: new Uint8Array(
+        obj.data.buffer.slice(
+          obj.data.byteOffset,
+          obj.data.byteOffset + obj.data.length
+        )
+      ),
+    })
+  }
+
+  static toEncodable(fields: ProposalArgsFields) {
+    return {
+      accounts: fields.accounts.map((item) =>
+        types.ProposalAccount.toEncodable(item)
+      ),
+      data: Buffer.from(
+        fields.data.buffer,
+        fields.data.byteOffset,
+        fields.data.length
+      ),
+    }
+  }
+
+  toJSON(): ProposalArgsJSON {
+    return {
+      accounts: this.accounts.map((item) => item.toJSON()),
+      data: Array.from(this.data.values()),
+    }
+  }
+
+  static fromJSON(obj: ProposalArgsJSON): ProposalArgs {
+    return new ProposalArgs({
+      accounts: obj.accounts.map((item) =>
+        types.ProposalAccount.fromJSON(item)
+      ),
+      data: Uint8Array.from(obj.data),
+    })
+  }
+
+  toEncodable() {
+    return ProposalArgs.toEncodable(this)
+  }
+}
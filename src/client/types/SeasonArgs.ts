@@ -0,0 +1,94 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SeasonArgsFields {
+  firstSolmap: BN
+  endSolmap: BN
+  startTime: BN
+  uri: string
+  fee: BN
+}
+
+export interface SeasonArgsJSON {
+  firstSolmap: string
+  endSolmap: string
+  startTime: string
+  uri: string
+  fee: string
+}
+
+export class SeasonArgs {
+  readonly firstSolmap: BN
+  readonly endSolmap: BN
+  readonly startTime: BN
+  readonly uri: string
+  readonly fee: BN
+
+  constructor(fields: SeasonArgsFields) {
+    this.firstSolmap = fields.firstSolmap
+    this.endSolmap = fields.endSolmap
+    this.startTime = fields.startTime
+    this.uri = fields.uri
+    this.fee = fields.fee
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("firstSolmap"),
+        borsh.u64("endSolmap"),
+        borsh.i64("startTime"),
+        borsh.str("uri"),
+        borsh.u64("fee"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SeasonArgs({
+      firstSolmap: obj.firstSolmap,
+      endSolmap: obj.endSolmap,
+      startTime: obj.startTime,
+      uri: obj.uri,
+      fee: obj.fee,
+    })
+  }
+
+  static toEncodable(fields: SeasonArgsFields) {
+    return {
+      firstSolmap: fields.firstSolmap,
+      endSolmap: fields.endSolmap,
+      startTime: fields.startTime,
+      uri: fields.uri,
+      fee: fields.fee,
+    }
+  }
+
+  toJSON(): SeasonArgsJSON {
+    return {
+      firstSolmap: this.firstSolmap.toString(),
+      endSolmap: this.endSolmap.toString(),
+      startTime: this.startTime.toString(),
+      uri: this.uri,
+      fee: this.fee.toString(),
+    }
+  }
+
+  static fromJSON(obj: SeasonArgsJSON): SeasonArgs {
+    return new SeasonArgs({
+      firstSolmap: new BN(obj.firstSolmap),
+      endSolmap: new BN(obj.endSolmap),
+      startTime: new BN(obj.startTime),
+      uri: obj.uri,
+      fee: new BN(obj.fee),
+    })
+  }
+
+  toEncodable() {
+    return SeasonArgs.toEncodable(this)
+  }
+}
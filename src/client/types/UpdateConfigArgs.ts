@@ -0,0 +1,121 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UpdateConfigArgsFields {
+  treasury: PublicKey | null
+  mcc: PublicKey | null
+  adminTimelock: BN | null
+  reservationExpirySlots: BN | null
+  revealDelaySlots: BN | null
+  commitRevealOnly: boolean | null
+  referralBps: number | null
+}
+
+export interface UpdateConfigArgsJSON {
+  treasury: string | null
+  mcc: string | null
+  adminTimelock: string | null
+  reservationExpirySlots: string | null
+  revealDelaySlots: string | null
+  commitRevealOnly: boolean | null
+  referralBps: number | null
+}
+
+export class UpdateConfigArgs {
+  readonly treasury: PublicKey | null
+  readonly mcc: PublicKey | null
+  readonly adminTimelock: BN | null
+  readonly reservationExpirySlots: BN | null
+  readonly revealDelaySlots: BN | null
+  readonly commitRevealOnly: boolean | null
+  readonly referralBps: number | null
+
+  constructor(fields: UpdateConfigArgsFields) {
+    this.treasury = fields.treasury
+    this.mcc = fields.mcc
+    this.adminTimelock = fields.adminTimelock
+    this.reservationExpirySlots = fields.reservationExpirySlots
+    this.revealDelaySlots = fields.revealDelaySlots
+    this.commitRevealOnly = fields.commitRevealOnly
+    this.referralBps = fields.referralBps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.option(borsh.publicKey(), "treasury"),
+        borsh.option(borsh.publicKey(), "mcc"),
+        borsh.option(borsh.i64(), "adminTimelock"),
+        borsh.option(borsh.u64(), "reservationExpirySlots"),
+        borsh.option(borsh.u64(), "revealDelaySlots"),
+        borsh.option(borsh.bool(), "commitRevealOnly"),
+        borsh.option(borsh.u16(), "referralBps"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UpdateConfigArgs({
+      treasury: obj.treasury,
+      mcc: obj.mcc,
+      adminTimelock: obj.adminTimelock,
+      reservationExpirySlots: obj.reservationExpirySlots,
+      revealDelaySlots: obj.revealDelaySlots,
+      commitRevealOnly: obj.commitRevealOnly,
+      referralBps: obj.referralBps,
+    })
+  }
+
+  static toEncodable(fields: UpdateConfigArgsFields) {
+    return {
+      treasury: fields.treasury,
+      mcc: fields.mcc,
+      adminTimelock: fields.adminTimelock,
+      reservationExpirySlots: fields.reservationExpirySlots,
+      revealDelaySlots: fields.revealDelaySlots,
+      commitRevealOnly: fields.commitRevealOnly,
+      referralBps: fields.referralBps,
+    }
+  }
+
+  toJSON(): UpdateConfigArgsJSON {
+    return {
+      treasury: (this.treasury && this.treasury.toString()) || null,
+      mcc: (this.mcc && this.mcc.toString()) || null,
+      adminTimelock:
+        (this.adminTimelock && this.adminTimelock.toString()) || null,
+      reservationExpirySlots:
+        (this.reservationExpirySlots &&
+          this.reservationExpirySlots.toString()) ||
+        null,
+      revealDelaySlots:
+        (this.revealDelaySlots && this.revealDelaySlots.toString()) || null,
+      commitRevealOnly: this.commitRevealOnly,
+      referralBps: this.referralBps,
+    }
+  }
+
+  static fromJSON(obj: UpdateConfigArgsJSON): UpdateConfigArgs {
+    return new UpdateConfigArgs({
+      treasury: (obj.treasury && new PublicKey(obj.treasury)) || null,
+      mcc: (obj.mcc && new PublicKey(obj.mcc)) || null,
+      adminTimelock: (obj.adminTimelock && new BN(obj.adminTimelock)) || null,
+      reservationExpirySlots:
+        (obj.reservationExpirySlots &&
+          new BN(obj.reservationExpirySlots)) ||
+        null,
+      revealDelaySlots:
+        (obj.revealDelaySlots && new BN(obj.revealDelaySlots)) || null,
+      commitRevealOnly: obj.commitRevealOnly,
+      referralBps: obj.referralBps,
+    })
+  }
+
+  toEncodable() {
+    return UpdateConfigArgs.toEncodable(this)
+  }
+}
@@ -0,0 +1,61 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface WalletLimitArgsFields {
+  allowlist: number
+  public: number
+}
+
+export interface WalletLimitArgsJSON {
+  allowlist: number
+  public: number
+}
+
+export class WalletLimitArgs {
+  readonly allowlist: number
+  readonly public: number
+
+  constructor(fields: WalletLimitArgsFields) {
+    this.allowlist = fields.allowlist
+    this.public = fields.public
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u32("allowlist"), borsh.u32("public")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new WalletLimitArgs({
+      allowlist: obj.allowlist,
+      public: obj.public,
+    })
+  }
+
+  static toEncodable(fields: WalletLimitArgsFields) {
+    return {
+      allowlist: fields.allowlist,
+      public: fields.public,
+    }
+  }
+
+  toJSON(): WalletLimitArgsJSON {
+    return {
+      allowlist: this.allowlist,
+      public: this.public,
+    }
+  }
+
+  static fromJSON(obj: WalletLimitArgsJSON): WalletLimitArgs {
+    return new WalletLimitArgs({
+      allowlist: obj.allowlist,
+      public: obj.public,
+    })
+  }
+
+  toEncodable() {
+    return WalletLimitArgs.toEncodable(this)
+  }
+}
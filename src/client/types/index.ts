@@ -0,0 +1,65 @@
+export { BackfillRecordArgs } from "./BackfillRecordArgs"
+export type {
+  BackfillRecordArgsFields,
+  BackfillRecordArgsJSON,
+} from "./BackfillRecordArgs"
+export { AuctionArgs } from "./AuctionArgs"
+export type { AuctionArgsFields, AuctionArgsJSON } from "./AuctionArgs"
+export { FeeRecipient } from "./FeeRecipient"
+export type { FeeRecipientFields, FeeRecipientJSON } from "./FeeRecipient"
+export { FeeTokenArgs } from "./FeeTokenArgs"
+export type { FeeTokenArgsFields, FeeTokenArgsJSON } from "./FeeTokenArgs"
+export { ConfigArgs } from "./ConfigArgs"
+export type { ConfigArgsFields, ConfigArgsJSON } from "./ConfigArgs"
+export { UpdateConfigArgs } from "./UpdateConfigArgs"
+export type {
+  UpdateConfigArgsFields,
+  UpdateConfigArgsJSON,
+} from "./UpdateConfigArgs"
+export { AllowlistArgs } from "./AllowlistArgs"
+export type { AllowlistArgsFields, AllowlistArgsJSON } from "./AllowlistArgs"
+export { WalletLimitArgs } from "./WalletLimitArgs"
+export type {
+  WalletLimitArgsFields,
+  WalletLimitArgsJSON,
+} from "./WalletLimitArgs"
+export { SeasonArgs } from "./SeasonArgs"
+export type { SeasonArgsFields, SeasonArgsJSON } from "./SeasonArgs"
+export { GovernanceArgs } from "./GovernanceArgs"
+export type { GovernanceArgsFields, GovernanceArgsJSON } from "./GovernanceArgs"
+export { ProposalAccount } from "./ProposalAccount"
+export type {
+  ProposalAccountFields,
+  ProposalAccountJSON,
+} from "./ProposalAccount"
+export { ProposalArgs } from "./ProposalArgs"
+export type { ProposalArgsFields, ProposalArgsJSON } from "./ProposalArgs"
+import * as AuctionKind from "./AuctionKind"
+
+export { AuctionKind }
+
+export type AuctionKindKind = AuctionKind.Dutch | AuctionKind.English
+export type AuctionKindJSON = AuctionKind.DutchJSON | AuctionKind.EnglishJSON
+
+import * as PricingMode from "./PricingMode"
+
+export { PricingMode }
+
+export type PricingModeKind =
+  | PricingMode.Flat
+  | PricingMode.Linear
+  | PricingMode.Exponential
+  | PricingMode.Dutch
+export type PricingModeJSON =
+  | PricingMode.FlatJSON
+  | PricingMode.LinearJSON
+  | PricingMode.ExponentialJSON
+  | PricingMode.DutchJSON
+
+import * as MintPhase from "./MintPhase"
+
+export { MintPhase }
+
+export type MintPhaseKind = MintPhase.Allowlist | MintPhase.Public
+export type MintPhaseJSON = MintPhase.AllowlistJSON | MintPhase.PublicJSON
+
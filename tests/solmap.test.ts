@@ -10,10 +10,17 @@ import {
   ComputeBudgetProgram,
   Connection,
   Keypair,
-  PublicKey
+  PublicKey,
+  TransactionInstruction
 } from "@solana/web3.js";
 import { assert, expect } from "chai";
 import { sha256 } from "@noble/hashes/sha256";
+import {
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo
+} from "@solana/spl-token";
 import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
 import {
   createAndMint,
@@ -44,7 +51,11 @@ const ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID = new PublicKey(
 const INSCRIPTION_PROGRAM_ID = new PublicKey(
   "inscokhJarcjaEs59QbQ7hYjrKz25LEPRfCbP8EmdUp"
 );
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
 const TREASURY = new PublicKey("72GEqCXZ5GLWnCWon5LBXjsZaoUh8jmarhXoBXnFr6CB");
+const FEE_VAULT_SHARDS = 16;
 const SOLMAP_URI =
   "https://arweave.net/o8sskjgVX80gn27pHPp_Q9DlCbIP8twSrHMwzLvm2ZI";
 
@@ -69,15 +80,88 @@ describe("solmap", () => {
     units: 400_000
   });
 
+  // Unix timestamp of the cluster clock, which auctions are timed by.
+  const chainTime = async () => {
+    const clock = await connection.getAccountInfo(
+      anchor.web3.SYSVAR_CLOCK_PUBKEY
+    );
+    return new anchor.BN(clock.data.subarray(32, 40), "le").toNumber();
+  };
+
+  // The validator starts at the warp slot, so wait for it to reach `slot` before minting
+  // Solmaps whose slot doesn't exist yet.
+  const waitForSlot = async (slot: number) => {
+    while ((await connection.getSlot()) < slot) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+  };
+
   // These don't change between tests and can be reused.
-  const slotIndex = PublicKey.findProgramAddressSync(
+  const legacySlotIndex = PublicKey.findProgramAddressSync(
     [Buffer.from("slot_index")],
     program.programId
   )[0];
+  const slotIndex = findSlotIndexPageKey(program.programId, 0);
   const fvca = PublicKey.findProgramAddressSync(
     [Buffer.from("fvca")],
     program.programId
   )[0];
+  const config = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  )[0];
+  const season = PublicKey.findProgramAddressSync(
+    [Buffer.from("season"), Buffer.from([1, 0])],
+    program.programId
+  )[0];
+  const feeSplit = PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_split")],
+    program.programId
+  )[0];
+  const walletMints = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("wallet_mints"),
+      Buffer.from([1, 0]),
+      payer.publicKey.toBuffer()
+    ],
+    program.programId
+  )[0];
+  const programData = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  )[0];
+
+  // NFTs minted before records were written on mint, see the fixtures in Anchor.toml.
+  // The legacy index marks #16391 as minted but not #16392.
+  const legacySolmapMints = {
+    16391: new PublicKey("AVd2td9GcMLEXBZePeQoGYj6rtWEoqK6Y6ga1tSKtW5X"),
+    16392: new PublicKey("DSUgpFScfWA9j6stKd2hFh49yBJBE6hDQyfyUrHHzp5S")
+  };
+  const backfillRecord = (solmapNum: number, mint: PublicKey) =>
+    program.methods
+      .backfillRecord(new anchor.BN(solmapNum), {
+        owner: payer.publicKey,
+        payer: payer.publicKey,
+        slot: new anchor.BN(16_400_000),
+        timestamp: new anchor.BN(1_700_000_000)
+      })
+      .accounts({
+        authority: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        slotIndex: findSlotIndexPageKey(program.programId, 1),
+        solmapRecord: findSolmapRecordKey(
+          program.programId,
+          new anchor.BN(solmapNum)
+        ),
+        mint,
+        metadata: findMetadataKey(mint),
+        fvca,
+        inscriptionV3: findInscriptionV3Key(mint),
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc({ skipPreflight: true });
 
   const mcc = anchor.web3.Keypair.fromSecretKey(Uint8Array.from(testMcc));
   const mccKeypair = umi.eddsa.createKeypairFromSecretKey(mcc.secretKey);
@@ -107,10 +191,32 @@ describe("solmap", () => {
     await new Promise((resolve) => setTimeout(resolve, 500));
 
     await program.methods
-      .initIndex()
+      .initializeConfig({
+        admin: payer.publicKey,
+        treasury: TREASURY,
+        mcc: mcc.publicKey,
+        adminTimelock: new anchor.BN(0),
+        reservationExpirySlots: new anchor.BN(9_000),
+        revealDelaySlots: new anchor.BN(0)
+      })
+      .accounts({
+        authority: payer.publicKey,
+        config,
+        program: program.programId,
+        programData,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    await program.methods
+      .initIndex(0)
       .accounts({
+        admin: payer.publicKey,
         payer: payer.publicKey,
+        config,
         slotIndex,
+        legacySlotIndex,
         systemProgram: anchor.web3.SystemProgram.programId
       })
       .signers([payer])
@@ -118,6 +224,43 @@ describe("solmap", () => {
         skipPreflight: true
       });
 
+    await program.methods
+      .createSeason(1, {
+        firstSolmap: new anchor.BN(0),
+        endSolmap: new anchor.BN(240_042),
+        startTime: new anchor.BN(0),
+        uri: SOLMAP_URI,
+        fee: new anchor.BN(30_000_000)
+      })
+      .accounts({
+        admin: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        season,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    await program.methods
+      .openSeason()
+      .accounts({
+        admin: payer.publicKey,
+        config,
+        season
+      })
+      .signers([payer])
+      .rpc();
+
+    await program.methods
+      .setPublicMint(true)
+      .accounts({
+        admin: payer.publicKey,
+        config
+      })
+      .signers([payer])
+      .rpc();
+
     // Mint MCC NFT
     await createAndMint(umi, {
       metadata: publicKey(mccMetadata),
@@ -212,9 +355,14 @@ describe("solmap", () => {
       .preInstructions([computeBudgetIx])
       .accounts({
         minter: payer.publicKey,
+        recipient: payer.publicKey,
+        config,
+        season,
         slotIndex,
-        treasury: TREASURY,
+        feeVault: findFeeVaultKey(program.programId, solmapNum),
+        walletMints,
         mint: mint.publicKey,
+        solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
         tokenAccount,
         metadata,
         masterEdition,
@@ -230,7 +378,12 @@ describe("solmap", () => {
         sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+        feeToken: null,
+        minterTokenAccount: null,
+        treasuryTokenAccount: null,
+        referrer: null,
+        referralStats: null
       })
       .signers([payer, mint])
       .rpc({
@@ -278,6 +431,70 @@ describe("solmap", () => {
     expect(inscriptionV3Account.data.slice(8, 40)).to.deep.equal(
       Buffer.alloc(32, 0)
     );
+
+    // The Solmap record points back at the NFT.
+    const record = await program.account.solmapRecord.fetch(
+      findSolmapRecordKey(program.programId, solmapNum)
+    );
+    expect(record.solmapNumber.toNumber()).to.equal(0);
+    expect(record.mint.toString()).to.equal(mint.publicKey.toString());
+    expect(record.owner.toString()).to.equal(payer.publicKey.toString());
+    expect(record.inscription.toString()).to.equal(inscriptionV3.toString());
+
+    // A SolmapMinted event was emitted.
+    const txDetails = await connection.getTransaction(tx, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0
+    });
+    const eventParser = new anchor.EventParser(
+      program.programId,
+      new anchor.BorshCoder(program.idl)
+    );
+    const events = [...eventParser.parseLogs(txDetails.meta.logMessages)];
+    const minted = events.find((e) => e.name === "SolmapMinted");
+
+    expect(minted).to.not.be.undefined;
+    expect(minted.data.solmapNumber.toNumber()).to.equal(0);
+    expect(minted.data.mint.toString()).to.equal(mint.publicKey.toString());
+    expect(minted.data.owner.toString()).to.equal(payer.publicKey.toString());
+    expect(minted.data.fee.toNumber()).to.equal(30_000_000);
+  });
+
+  it("rejects a season that overlaps an existing one", async () => {
+    const createSeason = (firstSolmap: number, endSolmap: number) =>
+      program.methods
+        .createSeason(2, {
+          firstSolmap: new anchor.BN(firstSolmap),
+          endSolmap: new anchor.BN(endSolmap),
+          startTime: new anchor.BN(0),
+          uri: SOLMAP_URI,
+          fee: new anchor.BN(30_000_000)
+        })
+        .accounts({
+          admin: payer.publicKey,
+          payer: payer.publicKey,
+          config,
+          season: PublicKey.findProgramAddressSync(
+            [Buffer.from("season"), Buffer.from([2, 0])],
+            program.programId
+          )[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+
+    // Season 1 covers Solmaps #0 to #240041.
+    try {
+      await createSeason(240_000, 300_000);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6053);
+    }
+
+    await createSeason(240_042, 300_000);
+    const configAccount = await program.account.config.fetch(config);
+    expect(configAccount.seasonsEnd.toNumber()).to.equal(300_000);
   });
 
   it("cannot mint a Solmap for a future slot", async () => {
@@ -290,9 +507,14 @@ describe("solmap", () => {
         .preInstructions([computeBudgetIx])
         .accounts({
           minter: payer.publicKey,
+          recipient: payer.publicKey,
+          config,
+          season,
           slotIndex,
-          treasury: TREASURY,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
           mint: mint.publicKey,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
           tokenAccount,
           metadata,
           masterEdition,
@@ -308,7 +530,12 @@ describe("solmap", () => {
           sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
           tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+          feeToken: null,
+          minterTokenAccount: null,
+          treasuryTokenAccount: null,
+          referrer: null,
+          referralStats: null
         })
         .signers([payer, mint])
         .rpc({
@@ -349,9 +576,14 @@ describe("solmap", () => {
       .preInstructions([computeBudgetIx])
       .accounts({
         minter: payer.publicKey,
+        recipient: payer.publicKey,
+        config,
+        season,
         slotIndex,
-        treasury: TREASURY,
+        feeVault: findFeeVaultKey(program.programId, solmapNum),
+        walletMints,
         mint: mint.publicKey,
+        solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
         tokenAccount,
         metadata,
         masterEdition,
@@ -367,7 +599,12 @@ describe("solmap", () => {
         sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+        feeToken: null,
+        minterTokenAccount: null,
+        treasuryTokenAccount: null,
+        referrer: null,
+        referralStats: null
       })
       .signers([payer, mint])
       .rpc();
@@ -430,9 +667,14 @@ describe("solmap", () => {
         .preInstructions([computeBudgetIx])
         .accounts({
           minter: payer.publicKey,
+          recipient: payer.publicKey,
+          config,
+          season,
           slotIndex,
-          treasury: TREASURY,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
           mint: secondMint.publicKey,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
           tokenAccount: secondTokenAccount,
           metadata: secondMetadata,
           masterEdition: secondMasterEdition,
@@ -448,7 +690,12 @@ describe("solmap", () => {
           sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
           tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+          feeToken: null,
+          minterTokenAccount: null,
+          treasuryTokenAccount: null,
+          referrer: null,
+          referralStats: null
         })
         .signers([payer, secondMint])
         .rpc({
@@ -473,9 +720,14 @@ describe("solmap", () => {
       .preInstructions([computeBudgetIx])
       .accounts({
         minter: payer.publicKey,
+        recipient: payer.publicKey,
+        config,
+        season,
         slotIndex,
-        treasury: TREASURY,
+        feeVault: findFeeVaultKey(program.programId, solmapNum),
+        walletMints,
         mint: mint.publicKey,
+        solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
         tokenAccount,
         metadata,
         masterEdition,
@@ -491,7 +743,12 @@ describe("solmap", () => {
         sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+        feeToken: null,
+        minterTokenAccount: null,
+        treasuryTokenAccount: null,
+        referrer: null,
+        referralStats: null
       })
       .signers([payer, mint])
       .rpc({
@@ -544,7 +801,2140 @@ describe("solmap", () => {
       Buffer.alloc(32, 0)
     );
   });
-});
+
+  it("mints a Solmap to the PDA mint derived from its number", async () => {
+    const solmapNum = new anchor.BN(3);
+
+    // Every address can be derived from the Solmap number alone.
+    const pdaMint = findSolmapMintKey(program.programId, solmapNum);
+    const pdaMetadata = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("metadata"),
+        TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+        pdaMint.toBuffer()
+      ],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+    const pdaMasterEdition = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("metadata"),
+        TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+        pdaMint.toBuffer(),
+        Buffer.from("edition")
+      ],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+    const pdaTokenAccount = PublicKey.findProgramAddressSync(
+      [
+        payer.publicKey.toBuffer(),
+        TOKEN_PROGRAM_ID.toBuffer(),
+        pdaMint.toBuffer()
+      ],
+      ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+    )[0];
+    const pdaInscriptionV3 = findInscriptionV3Key(pdaMint);
+    const pdaInscriptionData = findInscriptionDataKey(pdaMint);
+
+    await program.methods
+      .mintPda(solmapNum)
+      .preInstructions([computeBudgetIx])
+      .accounts({
+        minter: payer.publicKey,
+        recipient: payer.publicKey,
+        config,
+        season,
+        slotIndex,
+        feeVault: findFeeVaultKey(program.programId, solmapNum),
+        walletMints,
+        mint: pdaMint,
+        solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+        tokenAccount: pdaTokenAccount,
+        metadata: pdaMetadata,
+        masterEdition: pdaMasterEdition,
+        fvca,
+        mcc: mcc.publicKey,
+        collectionMetadata: mccMetadata,
+        collectionMasterEdition: mccMasterEdition,
+        inscriptionV3: pdaInscriptionV3,
+        inscriptionData: pdaInscriptionData,
+        inscriptionSummary,
+        inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+      })
+      .signers([payer])
+      .rpc({
+        skipPreflight: true
+      });
+
+    await new Promise((resolve) => setTimeout(resolve, 100));
+
+    const metadataAccount = await connection.getAccountInfo(pdaMetadata);
+    const inscriptionDataAccount = await connection.getAccountInfo(
+      pdaInscriptionData
+    );
+    expect(metadataAccount).to.not.be.null;
+
+    const mdDisc = Buffer.from(sha256("account:Metadata")).slice(0, 8);
+    metadataAccount.data = Buffer.concat([mdDisc, metadataAccount.data]);
+
+    const solmap = `${solmapNum}.solmap`;
+
+    const metadataStruct = tmCoder.decode("Metadata", metadataAccount.data);
+    expect(metadataStruct.data.name.replace(/\0/g, "")).to.equal(solmap);
+    expect(metadataStruct.collection.verified).to.equal(true);
+    expect(inscriptionDataAccount.data).to.deep.equal(
+      Buffer.from(solmap, "binary")
+    );
+
+    const record = await program.account.solmapRecord.fetch(
+      findSolmapRecordKey(program.programId, solmapNum)
+    );
+    expect(record.mint.toString()).to.equal(pdaMint.toString());
+    expect(record.inscription.toString()).to.equal(pdaInscriptionV3.toString());
+  });
+
+  it("verifies a Solmap and returns its number", async () => {
+    const solmapNum = new anchor.BN(3);
+    const pdaMint = findSolmapMintKey(program.programId, solmapNum);
+
+    const verified = await program.methods
+      .verifySolmap()
+      .accounts({
+        config,
+        mint: pdaMint,
+        metadata: findMetadataKey(pdaMint),
+        fvca
+      })
+      .view();
+
+    expect(verified.toNumber()).to.equal(3);
+  });
+
+  it("does not verify an NFT that is not a Solmap", async () => {
+    try {
+      await program.methods
+        .verifySolmap()
+        .accounts({
+          config,
+          mint: mcc.publicKey,
+          metadata: mccMetadata,
+          fvca
+        })
+        .rpc({
+          skipPreflight: true
+        });
+
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      const err: ProgramError = _err;
+      expect(err.code).to.equal(6003);
+    }
+  });
+
+  it("reserves a run of Solmaps and finalizes each NFT", async () => {
+    const start = new anchor.BN(4);
+    const count = 3;
+    const reservation = findReservationKey(program.programId, start);
+
+    await program.methods
+      .reserveSolmaps(start, count)
+      .accounts({
+        owner: payer.publicKey,
+        config,
+        season,
+        slotIndex,
+        feeVault: findFeeVaultKey(program.programId, start),
+        walletMints,
+        reservation,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    const reservationAccount = await program.account.reservation.fetch(
+      reservation
+    );
+    expect(reservationAccount.start.toNumber()).to.equal(4);
+    expect(reservationAccount.count).to.equal(3);
+    expect(reservationAccount.finalized.toNumber()).to.equal(0);
+    // The fee of the whole run is kept, not a truncated average.
+    expect(reservationAccount.fee.toNumber()).to.equal(count * 30_000_000);
+
+    for (let i = 0; i < count; i++) {
+      const solmapNum = start.addn(i);
+      const pdaMint = findSolmapMintKey(program.programId, solmapNum);
+
+      await program.methods
+        .finalizeSolmap(solmapNum)
+        .preInstructions([computeBudgetIx])
+        .accounts({
+          owner: payer.publicKey,
+          config,
+          reservation,
+          season,
+          slotIndex,
+          mint: pdaMint,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+          tokenAccount: PublicKey.findProgramAddressSync(
+            [
+              payer.publicKey.toBuffer(),
+              TOKEN_PROGRAM_ID.toBuffer(),
+              pdaMint.toBuffer()
+            ],
+            ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+          )[0],
+          metadata: findMetadataKey(pdaMint),
+          masterEdition: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("metadata"),
+              TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+              pdaMint.toBuffer(),
+              Buffer.from("edition")
+            ],
+            TOKEN_METADATA_PROGRAM_ID
+          )[0],
+          fvca,
+          mcc: mcc.publicKey,
+          collectionMetadata: mccMetadata,
+          collectionMasterEdition: mccMasterEdition,
+          inscriptionV3: findInscriptionV3Key(pdaMint),
+          inscriptionData: findInscriptionDataKey(pdaMint),
+          inscriptionSummary,
+          inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+        })
+        .signers([payer])
+        .rpc({
+          skipPreflight: true
+        });
+
+      const record = await program.account.solmapRecord.fetch(
+        findSolmapRecordKey(program.programId, solmapNum)
+      );
+      expect(record.mint.toString()).to.equal(pdaMint.toString());
+    }
+
+    // The reservation is closed once every Solmap is finalized.
+    expect(await connection.getAccountInfo(reservation)).to.be.null;
+  });
+
+  it("releases a reservation that was not finalized in time", async () => {
+    const solmapNum = new anchor.BN(7);
+    const reservation = findReservationKey(program.programId, solmapNum);
+
+    const setExpiry = (slots: number) =>
+      program.methods
+        .updateConfig({
+          treasury: null,
+          mcc: null,
+          adminTimelock: null,
+          reservationExpirySlots: new anchor.BN(slots),
+          revealDelaySlots: null,
+          commitRevealOnly: null,
+          referralBps: null
+        })
+        .accounts({ admin: payer.publicKey, config })
+        .signers([payer])
+        .rpc();
+
+    // Reservations expire as soon as they are made.
+    await setExpiry(0);
+
+    await program.methods
+      .reserveSolmap(solmapNum)
+      .accounts({
+        owner: payer.publicKey,
+        config,
+        season,
+        slotIndex,
+        feeVault: findFeeVaultKey(program.programId, solmapNum),
+        walletMints,
+        reservation,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    let page = await program.account.slotIndex.fetch(slotIndex);
+    const mintedWhileReserved = page.minted;
+
+    await program.methods
+      .releaseReservation()
+      .accounts({
+        owner: payer.publicKey,
+        reservation,
+        slotIndex,
+        season
+      })
+      .rpc();
+
+    page = await program.account.slotIndex.fetch(slotIndex);
+    expect(page.minted).to.equal(mintedWhileReserved - 1);
+    expect(await connection.getAccountInfo(reservation)).to.be.null;
+
+    await setExpiry(9_000);
+  });
+
+  it("reserves a Solmap through commit and reveal", async () => {
+    const solmapNum = new anchor.BN(7);
+    const salt = Keypair.generate().publicKey.toBuffer();
+    const hash = Buffer.from(
+      sha256(
+        Buffer.concat([
+          solmapNum.toArrayLike(Buffer, "le", 8),
+          salt,
+          payer.publicKey.toBuffer()
+        ])
+      )
+    );
+    const commitment = PublicKey.findProgramAddressSync(
+      [Buffer.from("commitment"), payer.publicKey.toBuffer(), hash],
+      program.programId
+    )[0];
+    const reservation = findReservationKey(program.programId, solmapNum);
+
+    const setCommitRevealOnly = (enabled: boolean) =>
+      program.methods
+        .updateConfig({
+          treasury: null,
+          mcc: null,
+          adminTimelock: null,
+          reservationExpirySlots: null,
+          revealDelaySlots: null,
+          commitRevealOnly: enabled,
+          referralBps: null
+        })
+        .accounts({ admin: payer.publicKey, config })
+        .signers([payer])
+        .rpc();
+    const reveal = (salt: Buffer) =>
+      program.methods
+        .revealSolmap(solmapNum, Array.from(salt))
+        .accounts({
+          owner: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
+          commitment,
+          reservation,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+
+    await setCommitRevealOnly(true);
+
+    try {
+      await program.methods
+        .reserveSolmap(solmapNum)
+        .accounts({
+          owner: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
+          reservation,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6029);
+    }
+
+    await program.methods
+      .commitSolmap(Array.from(hash))
+      .accounts({
+        owner: payer.publicKey,
+        commitment,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    try {
+      await reveal(Buffer.alloc(32));
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6027);
+    }
+
+    await reveal(salt);
+
+    const reservationAccount = await program.account.reservation.fetch(
+      reservation
+    );
+    expect(reservationAccount.start.toNumber()).to.equal(7);
+    expect(reservationAccount.count).to.equal(1);
+    expect(await connection.getAccountInfo(commitment)).to.be.null;
+
+    await setCommitRevealOnly(false);
+  });
+
+  it("cancels a commitment that won't be revealed", async () => {
+    // #7 was revealed above, so a second commitment to it can't be revealed anymore.
+    const solmapNum = new anchor.BN(7);
+    const salt = Buffer.alloc(32, 7);
+    const hash = Buffer.from(
+      sha256(
+        Buffer.concat([
+          solmapNum.toArrayLike(Buffer, "le", 8),
+          salt,
+          payer.publicKey.toBuffer()
+        ])
+      )
+    );
+    const commitment = PublicKey.findProgramAddressSync(
+      [Buffer.from("commitment"), payer.publicKey.toBuffer(), hash],
+      program.programId
+    )[0];
+
+    await program.methods
+      .commitSolmap(Array.from(hash))
+      .accounts({
+        owner: payer.publicKey,
+        commitment,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+    const balance = await connection.getBalance(payer.publicKey);
+
+    await program.methods
+      .cancelCommitment()
+      .accounts({ owner: payer.publicKey, commitment })
+      .signers([payer])
+      .rpc();
+
+    // The rent goes back to the owner.
+    expect(await connection.getAccountInfo(commitment)).to.be.null;
+    expect(await connection.getBalance(payer.publicKey)).to.be.greaterThan(
+      balance
+    );
+  });
+
+  it("reserves Solmaps in the allowlist presale up to the wallet quota", async () => {
+    const other = Keypair.generate().publicKey;
+    const leaf = (wallet: PublicKey, quota: number) => {
+      const quotaBytes = Buffer.alloc(4);
+      quotaBytes.writeUInt32LE(quota);
+      return Buffer.from(
+        sha256(Buffer.concat([Buffer.from([0]), wallet.toBuffer(), quotaBytes]))
+      );
+    };
+    const payerLeaf = leaf(payer.publicKey, 1);
+    const otherLeaf = leaf(other, 5);
+    const [left, right] = [payerLeaf, otherLeaf].sort(Buffer.compare);
+    const root = Buffer.from(
+      sha256(Buffer.concat([Buffer.from([1]), left, right]))
+    );
+
+    await program.methods
+      .setSeasonAllowlist({ root: Array.from(root), startTime: new anchor.BN(0) })
+      .accounts({ admin: payer.publicKey, config, season })
+      .signers([payer])
+      .rpc();
+    await program.methods
+      .setPublicMint(false)
+      .accounts({ admin: payer.publicKey, config })
+      .signers([payer])
+      .rpc();
+
+    const reserve = (solmapNum: anchor.BN, quota: number) =>
+      program.methods
+        .reserveAllowlist(solmapNum, 1, quota, [Array.from(otherLeaf)])
+        .accounts({
+          owner: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
+          reservation: findReservationKey(program.programId, solmapNum),
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+
+    try {
+      await reserve(new anchor.BN(8), 5);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6030);
+    }
+
+    // Allowlisted wallets have to commit first too when minting is commit-reveal only.
+    const setCommitRevealOnly = (enabled: boolean) =>
+      program.methods
+        .updateConfig({
+          treasury: null,
+          mcc: null,
+          adminTimelock: null,
+          reservationExpirySlots: null,
+          revealDelaySlots: null,
+          commitRevealOnly: enabled,
+          referralBps: null
+        })
+        .accounts({ admin: payer.publicKey, config })
+        .signers([payer])
+        .rpc();
+
+    await setCommitRevealOnly(true);
+    try {
+      await reserve(new anchor.BN(8), 1);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6029);
+    }
+    await setCommitRevealOnly(false);
+
+    await reserve(new anchor.BN(8), 1);
+
+    try {
+      await reserve(new anchor.BN(9), 1);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6031);
+    }
+
+    await program.methods
+      .setPublicMint(true)
+      .accounts({ admin: payer.publicKey, config })
+      .signers([payer])
+      .rpc();
+  });
+
+  it("stops a wallet at the public mint limit of the season", async () => {
+    const solmapNum = new anchor.BN(9);
+    const setWalletLimits = (limit: number) =>
+      program.methods
+        .setSeasonWalletLimits({ allowlist: 0, public: limit })
+        .accounts({ admin: payer.publicKey, config, season })
+        .signers([payer])
+        .rpc();
+
+    const counts = await program.account.walletMints.fetch(walletMints);
+    expect(counts.allowlistMinted).to.equal(1);
+    await setWalletLimits(counts.publicMinted);
+
+    try {
+      await program.methods
+        .reserveSolmap(solmapNum)
+        .accounts({
+          owner: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
+          reservation: findReservationKey(program.programId, solmapNum),
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6032);
+    }
+
+    await setWalletLimits(0);
+  });
+
+  it("quotes the mint fee of the season pricing mode", async () => {
+    const solmapNum = new anchor.BN(9);
+    const setPricing = (pricing: any) =>
+      program.methods
+        .setSeasonPricing(pricing)
+        .accounts({ admin: payer.publicKey, config, season })
+        .signers([payer])
+        .rpc();
+    const quote = () =>
+      program.methods
+        .quote(solmapNum)
+        .accounts({ season })
+        .view();
+
+    expect((await quote()).toNumber()).to.equal(30_000_000);
+
+    // Mints under flat pricing don't write the season, so linear pricing counts from
+    // the mints made once it is set.
+    await setPricing({ linear: { increment: new anchor.BN(1_000) } });
+    const { minted } = await program.account.season.fetch(season);
+    expect(minted).to.equal(0);
+    const fee = (await quote()).toNumber();
+    expect(fee).to.equal(30_000_000);
+
+    // A mint pays the quoted fee and raises it by the increment. The season has to be
+    // writable now that its pricing counts mints.
+    const reserved = new anchor.BN(17);
+    const feeVault = findFeeVaultKey(program.programId, reserved);
+    await waitForSlot((reserved.toNumber() + 1) * 1000);
+    const vaultBalance = await connection.getBalance(feeVault);
+    const reserve = program.methods.reserveSolmap(reserved).accounts({
+      owner: payer.publicKey,
+      config,
+      season,
+      slotIndex,
+      feeVault,
+      walletMints,
+      reservation: findReservationKey(program.programId, reserved),
+      systemProgram: anchor.web3.SystemProgram.programId
+    });
+    try {
+      await reserve.signers([payer]).rpc({ skipPreflight: true });
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6054);
+    }
+
+    const tx = await reserve.transaction();
+    tx.instructions[0].keys.find((key) => key.pubkey.equals(season)).isWritable =
+      true;
+    await program.provider.sendAndConfirm(tx, [payer]);
+
+    expect(await connection.getBalance(feeVault)).to.equal(vaultBalance + fee);
+    expect((await quote()).toNumber()).to.equal(fee + 1_000);
+
+    // A Dutch decline cannot start below the base fee.
+    try {
+      await program.methods
+        .setSeasonPricing({
+          dutch: { startFee: new anchor.BN(0), duration: new anchor.BN(60) }
+        })
+        .accounts({ admin: payer.publicKey, config, season })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6033);
+    }
+
+    await setPricing({ flat: {} });
+  });
+
+  it("cannot mint when public minting is disabled", async () => {
+    const solmapNum = new anchor.BN(3);
+
+    await program.methods
+      .setPublicMint(false)
+      .accounts({
+        admin: payer.publicKey,
+        config
+      })
+      .signers([payer])
+      .rpc();
+
+    try {
+      await program.methods
+        .mint(solmapNum)
+        .preInstructions([computeBudgetIx])
+        .accounts({
+          minter: payer.publicKey,
+          recipient: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
+          mint: mint.publicKey,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+          tokenAccount,
+          metadata,
+          masterEdition,
+          mcc: mcc.publicKey,
+          collectionMetadata: mccMetadata,
+          collectionMasterEdition: mccMasterEdition,
+          fvca,
+          inscriptionV3,
+          inscriptionData,
+          inscriptionSummary,
+          inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+          feeToken: null,
+          minterTokenAccount: null,
+          treasuryTokenAccount: null,
+          referrer: null,
+          referralStats: null
+        })
+        .signers([payer, mint])
+        .rpc({
+          skipPreflight: true // Skip preflight to get the nicer ProgramError.
+        });
+
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      const err: ProgramError = _err;
+      expect(err.code).to.equal(6004);
+      expect(err.msg).to.equal("Public minting is disabled");
+    }
+
+    await program.methods
+      .setPublicMint(true)
+      .accounts({
+        admin: payer.publicKey,
+        config
+      })
+      .signers([payer])
+      .rpc();
+  });
+
+  it("cannot mint from a closed season", async () => {
+    const solmapNum = new anchor.BN(3);
+
+    await program.methods
+      .closeSeason()
+      .accounts({
+        admin: payer.publicKey,
+        config,
+        season
+      })
+      .signers([payer])
+      .rpc();
+
+    try {
+      await program.methods
+        .mint(solmapNum)
+        .preInstructions([computeBudgetIx])
+        .accounts({
+          minter: payer.publicKey,
+          recipient: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
+          mint: mint.publicKey,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+          tokenAccount,
+          metadata,
+          masterEdition,
+          mcc: mcc.publicKey,
+          collectionMetadata: mccMetadata,
+          collectionMasterEdition: mccMasterEdition,
+          fvca,
+          inscriptionV3,
+          inscriptionData,
+          inscriptionSummary,
+          inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+          feeToken: null,
+          minterTokenAccount: null,
+          treasuryTokenAccount: null,
+          referrer: null,
+          referralStats: null
+        })
+        .signers([payer, mint])
+        .rpc({
+          skipPreflight: true // Skip preflight to get the nicer ProgramError.
+        });
+
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      const err: ProgramError = _err;
+      expect(err.code).to.equal(6007);
+      expect(err.msg).to.equal("Season is closed");
+    }
+
+    await program.methods
+      .openSeason()
+      .accounts({
+        admin: payer.publicKey,
+        config,
+        season
+      })
+      .signers([payer])
+      .rpc();
+  });
+
+  it("sweeps a fee vault to the treasury", async () => {
+    // Solmap #0 was minted above, so its fee vault holds one grant fee.
+    const feeVault = findFeeVaultKey(program.programId, new anchor.BN(0));
+
+    const vaultBalance = await connection.getBalance(feeVault);
+    const treasuryBalance = await connection.getBalance(TREASURY);
+    expect(vaultBalance).to.be.greaterThan(0);
+
+    await program.methods
+      .sweepFeeVault(0)
+      .accounts({
+        config,
+        feeVault,
+        treasury: TREASURY,
+        feeSplit,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .rpc();
+
+    expect(await connection.getBalance(feeVault)).to.equal(0);
+    expect(await connection.getBalance(TREASURY)).to.equal(
+      treasuryBalance + vaultBalance
+    );
+  });
+
+  it("splits a swept fee vault between the fee recipients", async () => {
+    const devFund = Keypair.generate().publicKey;
+    // Solmap #1 was minted above.
+    const feeVault = findFeeVaultKey(program.programId, new anchor.BN(1));
+
+    await program.methods
+      .setFeeSplit([
+        { address: TREASURY, bps: 7_000 },
+        { address: devFund, bps: 3_000 }
+      ])
+      .accounts({
+        admin: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        feeSplit,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    const vaultBalance = await connection.getBalance(feeVault);
+    const treasuryBalance = await connection.getBalance(TREASURY);
+    const sweep = (recipients: PublicKey[]) =>
+      program.methods
+        .sweepFeeVault(1)
+        .accounts({
+          config,
+          feeVault,
+          treasury: TREASURY,
+          feeSplit,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .remainingAccounts(
+          recipients.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true
+          }))
+        )
+        .rpc({ skipPreflight: true });
+
+    try {
+      await sweep([devFund, TREASURY]);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6035);
+    }
+
+    await sweep([TREASURY, devFund]);
+
+    const devShare = Math.floor((vaultBalance * 3_000) / 10_000);
+    expect(await connection.getBalance(devFund)).to.equal(devShare);
+    expect(await connection.getBalance(TREASURY)).to.equal(
+      treasuryBalance + vaultBalance - devShare
+    );
+  });
+
+  it("takes the mint fee in an accepted SPL token", async () => {
+    const solmapNum = new anchor.BN(9);
+    const feeVault = findFeeVaultKey(program.programId, solmapNum);
+    const feeMint = await createMint(
+      connection,
+      payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    const feeToken = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_token"), feeMint.toBuffer()],
+      program.programId
+    )[0];
+    const minterTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      payer,
+      feeMint,
+      payer.publicKey
+    );
+    const treasuryTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      payer,
+      feeMint,
+      TREASURY
+    );
+    await mintTo(
+      connection,
+      payer,
+      feeMint,
+      minterTokenAccount.address,
+      payer,
+      1_000_000
+    );
+
+    const setFeeToken = (enabled: boolean) =>
+      program.methods
+        .setFeeToken({ amount: new anchor.BN(250_000), enabled })
+        .accounts({
+          admin: payer.publicKey,
+          payer: payer.publicKey,
+          config,
+          mint: feeMint,
+          feeToken,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc();
+    const mintWithToken = () =>
+      program.methods
+        .mint(solmapNum)
+        .preInstructions([computeBudgetIx])
+        .accounts({
+          minter: payer.publicKey,
+          recipient: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault,
+          walletMints,
+          mint: mint.publicKey,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+          tokenAccount,
+          metadata,
+          masterEdition,
+          mcc: mcc.publicKey,
+          collectionMetadata: mccMetadata,
+          collectionMasterEdition: mccMasterEdition,
+          fvca,
+          inscriptionV3,
+          inscriptionData,
+          inscriptionSummary,
+          inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+          feeToken,
+          minterTokenAccount: minterTokenAccount.address,
+          treasuryTokenAccount: treasuryTokenAccount.address,
+          referrer: null,
+          referralStats: null
+        })
+        .signers([payer, mint])
+        .rpc({ skipPreflight: true });
+
+    await setFeeToken(false);
+    try {
+      await mintWithToken();
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6036);
+    }
+
+    const vaultBalance = await connection.getBalance(feeVault);
+    await setFeeToken(true);
+    await mintWithToken();
+
+    const treasuryAccount = await getAccount(
+      connection,
+      treasuryTokenAccount.address
+    );
+    expect(Number(treasuryAccount.amount)).to.equal(250_000);
+    expect(await connection.getBalance(feeVault)).to.equal(vaultBalance);
+  });
+
+  it("mints a Solmap to a recipient while the minter pays", async () => {
+    const solmapNum = new anchor.BN(10);
+    // The recipient neither signs nor pays.
+    const recipient = anchor.web3.Keypair.generate().publicKey;
+    const recipientTokenAccount = PublicKey.findProgramAddressSync(
+      [
+        recipient.toBuffer(),
+        TOKEN_PROGRAM_ID.toBuffer(),
+        mint.publicKey.toBuffer()
+      ],
+      ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+    )[0];
+    const recipientMints = PublicKey.findProgramAddressSync(
+      [Buffer.from("wallet_mints"), Buffer.from([1, 0]), recipient.toBuffer()],
+      program.programId
+    )[0];
+
+    await program.methods
+      .mint(solmapNum)
+      .preInstructions([computeBudgetIx])
+      .accounts({
+        minter: payer.publicKey,
+        recipient,
+        config,
+        season,
+        slotIndex,
+        feeVault: findFeeVaultKey(program.programId, solmapNum),
+        walletMints: recipientMints,
+        mint: mint.publicKey,
+        solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+        tokenAccount: recipientTokenAccount,
+        metadata,
+        masterEdition,
+        mcc: mcc.publicKey,
+        collectionMetadata: mccMetadata,
+        collectionMasterEdition: mccMasterEdition,
+        fvca,
+        inscriptionV3,
+        inscriptionData,
+        inscriptionSummary,
+        inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+        feeToken: null,
+        minterTokenAccount: null,
+        treasuryTokenAccount: null,
+        referrer: null,
+        referralStats: null
+      })
+      .signers([payer, mint])
+      .rpc({ skipPreflight: true });
+
+    // The recipient holds the NFT and is recorded, without paying anything.
+    const tokenBalance = await connection.getTokenAccountBalance(
+      recipientTokenAccount
+    );
+    expect(tokenBalance.value.amount).to.equal("1");
+    expect(await connection.getBalance(recipient)).to.equal(0);
+
+    const record = await program.account.solmapRecord.fetch(
+      findSolmapRecordKey(program.programId, solmapNum)
+    );
+    expect(record.owner.toString()).to.equal(recipient.toString());
+    expect(record.payer.toString()).to.equal(payer.publicKey.toString());
+
+    const counts = await program.account.walletMints.fetch(recipientMints);
+    expect(counts.wallet.toString()).to.equal(recipient.toString());
+    expect(counts.publicMinted).to.equal(1);
+  });
+
+  it("pays the referrer its share of the mint fee", async () => {
+    const solmapNum = new anchor.BN(11);
+    const feeVault = findFeeVaultKey(program.programId, solmapNum);
+    const findReferralStatsKey = (referrer: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("referral"), referrer.toBuffer()],
+        program.programId
+      )[0];
+    const mintWithReferrer = (referrer: PublicKey) =>
+      program.methods
+        .mint(solmapNum)
+        .preInstructions([computeBudgetIx])
+        .accounts({
+          minter: payer.publicKey,
+          recipient: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault,
+          walletMints,
+          mint: mint.publicKey,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+          tokenAccount,
+          metadata,
+          masterEdition,
+          mcc: mcc.publicKey,
+          collectionMetadata: mccMetadata,
+          collectionMasterEdition: mccMasterEdition,
+          fvca,
+          inscriptionV3,
+          inscriptionData,
+          inscriptionSummary,
+          inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+          feeToken: null,
+          minterTokenAccount: null,
+          treasuryTokenAccount: null,
+          referrer,
+          referralStats: findReferralStatsKey(referrer)
+        })
+        .signers([payer, mint])
+        .rpc({ skipPreflight: true });
+
+    await program.methods
+      .updateConfig({
+        treasury: null,
+        mcc: null,
+        adminTimelock: null,
+        reservationExpirySlots: null,
+        revealDelaySlots: null,
+        commitRevealOnly: null,
+        referralBps: 1_000
+      })
+      .accounts({ admin: payer.publicKey, config })
+      .signers([payer])
+      .rpc();
+
+    // Wallets cannot refer their own mints.
+    try {
+      await mintWithReferrer(payer.publicKey);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6039);
+    }
+
+    const referrer = anchor.web3.Keypair.generate().publicKey;
+    const vaultBalance = await connection.getBalance(feeVault);
+    await mintWithReferrer(referrer);
+
+    expect(await connection.getBalance(referrer)).to.equal(3_000_000);
+    expect(await connection.getBalance(feeVault)).to.equal(
+      vaultBalance + 27_000_000
+    );
+
+    const stats = await program.account.referralStats.fetch(
+      findReferralStatsKey(referrer)
+    );
+    expect(stats.referrer.toString()).to.equal(referrer.toString());
+    expect(stats.mints.toNumber()).to.equal(1);
+    expect(stats.earned.toNumber()).to.equal(3_000_000);
+  });
+
+  it("holds Solmaps back for a claimant to claim", async () => {
+    const start = new anchor.BN(12);
+    const reservation = findReservationKey(program.programId, start);
+    const claimant = Keypair.generate();
+    await connection.requestAirdrop(claimant.publicKey, 1000000000);
+    await new Promise((resolve) => setTimeout(resolve, 500));
+
+    const { minted } = await program.account.season.fetch(season);
+    const pageMinted = (await program.account.slotIndex.fetch(slotIndex)).minted;
+    await program.methods
+      .holdSolmaps(start, 2, claimant.publicKey)
+      .accounts({
+        admin: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        season,
+        slotIndex,
+        reservation,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    const held = await program.account.reservation.fetch(reservation);
+    expect(held.owner.toString()).to.equal(claimant.publicKey.toString());
+    expect(held.fee.toNumber()).to.equal(0);
+
+    // Held Solmaps are not sold, so they don't move the season's price.
+    const afterHold = await program.account.season.fetch(season);
+    expect(afterHold.minted).to.equal(minted);
+    expect((await program.account.slotIndex.fetch(slotIndex)).minted).to.equal(
+      pageMinted
+    );
+
+    // The public mint skips held numbers.
+    try {
+      await program.methods
+        .mint(start)
+        .preInstructions([computeBudgetIx])
+        .accounts({
+          minter: payer.publicKey,
+          recipient: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          feeVault: findFeeVaultKey(program.programId, start),
+          walletMints,
+          mint: mint.publicKey,
+          solmapRecord: findSolmapRecordKey(program.programId, start),
+          tokenAccount,
+          metadata,
+          masterEdition,
+          mcc: mcc.publicKey,
+          collectionMetadata: mccMetadata,
+          collectionMasterEdition: mccMasterEdition,
+          fvca,
+          inscriptionV3,
+          inscriptionData,
+          inscriptionSummary,
+          inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+          feeToken: null,
+          minterTokenAccount: null,
+          treasuryTokenAccount: null,
+          referrer: null,
+          referralStats: null
+        })
+        .signers([payer, mint])
+        .rpc({ skipPreflight: true });
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6051);
+    }
+
+    const claim = (owner: Keypair, solmapNum: anchor.BN) => {
+      const pdaMint = findSolmapMintKey(program.programId, solmapNum);
+      return program.methods
+        .claimReserved(solmapNum)
+        .preInstructions([computeBudgetIx])
+        .accounts({
+          owner: owner.publicKey,
+          config,
+          reservation,
+          season,
+          slotIndex,
+          mint: pdaMint,
+          solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+          tokenAccount: PublicKey.findProgramAddressSync(
+            [
+              owner.publicKey.toBuffer(),
+              TOKEN_PROGRAM_ID.toBuffer(),
+              pdaMint.toBuffer()
+            ],
+            ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+          )[0],
+          metadata: findMetadataKey(pdaMint),
+          masterEdition: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("metadata"),
+              TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+              pdaMint.toBuffer(),
+              Buffer.from("edition")
+            ],
+            TOKEN_METADATA_PROGRAM_ID
+          )[0],
+          fvca,
+          mcc: mcc.publicKey,
+          collectionMetadata: mccMetadata,
+          collectionMasterEdition: mccMasterEdition,
+          inscriptionV3: findInscriptionV3Key(pdaMint),
+          inscriptionData: findInscriptionDataKey(pdaMint),
+          inscriptionSummary,
+          inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+        })
+        .signers([owner])
+        .rpc({ skipPreflight: true });
+    };
+
+    // Only the designated claimant can claim them.
+    try {
+      await claim(payer, start);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6005);
+    }
+
+    await claim(claimant, start);
+    await claim(claimant, start.addn(1));
+
+    const record = await program.account.solmapRecord.fetch(
+      findSolmapRecordKey(program.programId, start)
+    );
+    expect(record.owner.toString()).to.equal(claimant.publicKey.toString());
+    expect(await connection.getAccountInfo(reservation)).to.be.null;
+
+    // Claimed Solmaps are counted as minted in their page. The season is flat priced
+    // again, so its count is left alone.
+    const afterClaim = await program.account.season.fetch(season);
+    expect(afterClaim.minted).to.equal(minted);
+    expect((await program.account.slotIndex.fetch(slotIndex)).minted).to.equal(
+      pageMinted + 2
+    );
+  });
+
+  it("auctions a premium Solmap to the highest bidder", async () => {
+    const solmapNum = new anchor.BN(14);
+    const auction = findAuctionKey(program.programId, solmapNum);
+    const reservation = findReservationKey(program.programId, solmapNum);
+    const rival = Keypair.generate();
+    await connection.requestAirdrop(rival.publicKey, 2000000000);
+    await new Promise((resolve) => setTimeout(resolve, 500));
+
+    const now = await chainTime();
+    const endTime = now + 10;
+    await program.methods
+      .createAuction(solmapNum, {
+        kind: { english: { minIncrement: new anchor.BN(100_000_000) } },
+        reservePrice: new anchor.BN(500_000_000),
+        startTime: new anchor.BN(now - 1),
+        endTime: new anchor.BN(endTime)
+      })
+      .accounts({
+        admin: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        season,
+        slotIndex,
+        auction,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    const bid = (
+      bidder: Keypair,
+      amount: number,
+      previous: PublicKey | null
+    ) =>
+      program.methods
+        .bid(new anchor.BN(amount))
+        .accounts({
+          bidder: bidder.publicKey,
+          auction,
+          previousBidder: previous,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([bidder])
+        .rpc();
+    const expectError = async (call: Promise<string>, code: number) => {
+      try {
+        await call;
+        assert.fail();
+      } catch (_err) {
+        assert.isTrue(_err instanceof ProgramError);
+        expect((_err as ProgramError).code).to.equal(code);
+      }
+    };
+
+    await bid(rival, 500_000_000, null);
+
+    // Bids must raise the highest one by the increment and refund its bidder.
+    await expectError(bid(payer, 550_000_000, rival.publicKey), 6044);
+    await expectError(bid(payer, 600_000_000, null), 6045);
+
+    const rivalBalance = await connection.getBalance(rival.publicKey);
+    await bid(payer, 600_000_000, rival.publicKey);
+    expect(await connection.getBalance(rival.publicKey)).to.equal(
+      rivalBalance + 500_000_000
+    );
+
+    const settle = () =>
+      program.methods
+        .settleAuction()
+        .accounts({
+          payer: payer.publicKey,
+          config,
+          auction,
+          treasury: TREASURY,
+          reservation,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc();
+
+    await expectError(settle(), 6046);
+    while ((await chainTime()) < endTime) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    const escrow = await connection.getBalance(auction);
+    const treasuryBalance = await connection.getBalance(TREASURY);
+    await settle();
+
+    expect(await connection.getBalance(TREASURY)).to.equal(
+      treasuryBalance + escrow
+    );
+    expect(await connection.getAccountInfo(auction)).to.be.null;
+
+    const held = await program.account.reservation.fetch(reservation);
+    expect(held.owner.toString()).to.equal(payer.publicKey.toString());
+    expect(held.fee.toNumber()).to.equal(600_000_000);
+
+    // The winner mints the Solmap like any held one, once its slot exists.
+    await waitForSlot((solmapNum.toNumber() + 1) * 1000);
+    const pdaMint = findSolmapMintKey(program.programId, solmapNum);
+    await program.methods
+      .claimReserved(solmapNum)
+      .preInstructions([computeBudgetIx])
+      .accounts({
+        owner: payer.publicKey,
+        config,
+        reservation,
+        season,
+        slotIndex,
+        mint: pdaMint,
+        solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+        tokenAccount: PublicKey.findProgramAddressSync(
+          [
+            payer.publicKey.toBuffer(),
+            TOKEN_PROGRAM_ID.toBuffer(),
+            pdaMint.toBuffer()
+          ],
+          ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+        )[0],
+        metadata: findMetadataKey(pdaMint),
+        masterEdition: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("metadata"),
+            TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            pdaMint.toBuffer(),
+            Buffer.from("edition")
+          ],
+          TOKEN_METADATA_PROGRAM_ID
+        )[0],
+        fvca,
+        mcc: mcc.publicKey,
+        collectionMetadata: mccMetadata,
+        collectionMasterEdition: mccMasterEdition,
+        inscriptionV3: findInscriptionV3Key(pdaMint),
+        inscriptionData: findInscriptionDataKey(pdaMint),
+        inscriptionSummary,
+        inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+      })
+      .signers([payer])
+      .rpc({ skipPreflight: true });
+
+    const record = await program.account.solmapRecord.fetch(
+      findSolmapRecordKey(program.programId, solmapNum)
+    );
+    expect(record.owner.toString()).to.equal(payer.publicKey.toString());
+  });
+
+  it("sells a Dutch auction at its current price", async () => {
+    const createAuction = async (solmapNum: anchor.BN, kind: object) => {
+      const now = await chainTime();
+      await program.methods
+        .createAuction(solmapNum, {
+          kind,
+          reservePrice: new anchor.BN(1_000_000_000),
+          startTime: new anchor.BN(now - 10),
+          endTime: new anchor.BN(now + 3600)
+        })
+        .accounts({
+          admin: payer.publicKey,
+          payer: payer.publicKey,
+          config,
+          season,
+          slotIndex,
+          auction: findAuctionKey(program.programId, solmapNum),
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc();
+    };
+    const cancel = (auction: PublicKey) =>
+      program.methods
+        .cancelAuction()
+        .accounts({ admin: payer.publicKey, config, auction, slotIndex })
+        .signers([payer])
+        .rpc();
+
+    const solmapNum = new anchor.BN(15);
+    const auction = findAuctionKey(program.programId, solmapNum);
+    await createAuction(solmapNum, {
+      dutch: { startPrice: new anchor.BN(2_000_000_000) }
+    });
+
+    const bid = (amount: number) =>
+      program.methods
+        .bid(new anchor.BN(amount))
+        .accounts({
+          bidder: payer.publicKey,
+          auction,
+          previousBidder: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc();
+
+    try {
+      await bid(1_000_000_000);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6044);
+    }
+
+    // The price has already declined a little from the start price.
+    const rent = await connection.getBalance(auction);
+    await bid(2_000_000_000);
+    const sold = await program.account.auction.fetch(auction);
+    expect(sold.bid.toNumber()).to.be.lessThan(2_000_000_000);
+    expect(sold.bid.toNumber()).to.be.greaterThan(1_000_000_000);
+    expect(await connection.getBalance(auction)).to.equal(
+      rent + sold.bid.toNumber()
+    );
+
+    try {
+      await bid(2_000_000_000);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6043);
+    }
+
+    try {
+      await cancel(auction);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6048);
+    }
+
+    // A sold Dutch auction can be settled before its end time.
+    const reservation = findReservationKey(program.programId, solmapNum);
+    await program.methods
+      .settleAuction()
+      .accounts({
+        payer: payer.publicKey,
+        config,
+        auction,
+        treasury: TREASURY,
+        reservation,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+    const held = await program.account.reservation.fetch(reservation);
+    expect(held.fee.toNumber()).to.equal(sold.bid.toNumber());
+
+    // An auction without bids gives its Solmap back to the public mint.
+    const unsold = new anchor.BN(16);
+    const minted = (await program.account.slotIndex.fetch(slotIndex)).minted;
+    await createAuction(unsold, {
+      english: { minIncrement: new anchor.BN(100_000_000) }
+    });
+    expect((await program.account.slotIndex.fetch(slotIndex)).minted).to.equal(
+      minted
+    );
+    await cancel(findAuctionKey(program.programId, unsold));
+    expect((await program.account.slotIndex.fetch(slotIndex)).minted).to.equal(
+      minted
+    );
+  });
+
+  it("mints a random Solmap that is not minted yet", async () => {
+    // #16 went back to the public mint above and can be drawn once its slot exists.
+    await waitForSlot(17_000);
+
+    // Each owner has a single pending draw, and its fee goes to the vault of the owner.
+    const reservation = PublicKey.findProgramAddressSync(
+      [Buffer.from("random_draw"), payer.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const mintRandom = () =>
+      program.methods
+        .mintRandom()
+        .accounts({
+          owner: payer.publicKey,
+          config,
+          season,
+          feeVault: findFeeVaultKey(
+            program.programId,
+            new anchor.BN(payer.publicKey.toBytes()[0])
+          ),
+          walletMints,
+          reservation,
+          slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .remainingAccounts([
+          { pubkey: slotIndex, isSigner: false, isWritable: true }
+        ])
+        .signers([payer])
+        .rpc();
+
+    const setCommitRevealOnly = (enabled: boolean) =>
+      program.methods
+        .updateConfig({
+          publicMintEnabled: null,
+          adminTimelock: null,
+          reservationExpirySlots: null,
+          revealDelaySlots: null,
+          commitRevealOnly: enabled,
+          referralBps: null
+        })
+        .accounts({ admin: payer.publicKey, config })
+        .signers([payer])
+        .rpc();
+
+    // A random draw skips commit and reveal, so it is closed when the reveal is required.
+    await setCommitRevealOnly(true);
+    try {
+      await mintRandom();
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6029);
+    }
+    await setCommitRevealOnly(false);
+
+    // The program draws the number and reserves it, so nothing is left to the minter.
+    await mintRandom();
+    const drawn = await program.account.reservation.fetch(reservation);
+    expect(drawn.owner.toString()).to.equal(payer.publicKey.toString());
+    expect(drawn.count).to.equal(1);
+    const solmapNum = drawn.start;
+
+    const pdaMint = findSolmapMintKey(program.programId, solmapNum);
+    await program.methods
+      .finalizeSolmap(solmapNum)
+      .preInstructions([computeBudgetIx])
+      .accounts({
+        owner: payer.publicKey,
+        config,
+        reservation,
+        season,
+        slotIndex,
+        mint: pdaMint,
+        solmapRecord: findSolmapRecordKey(program.programId, solmapNum),
+        tokenAccount: PublicKey.findProgramAddressSync(
+          [
+            payer.publicKey.toBuffer(),
+            TOKEN_PROGRAM_ID.toBuffer(),
+            pdaMint.toBuffer()
+          ],
+          ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+        )[0],
+        metadata: findMetadataKey(pdaMint),
+        masterEdition: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("metadata"),
+            TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            pdaMint.toBuffer(),
+            Buffer.from("edition")
+          ],
+          TOKEN_METADATA_PROGRAM_ID
+        )[0],
+        fvca,
+        mcc: mcc.publicKey,
+        collectionMetadata: mccMetadata,
+        collectionMasterEdition: mccMasterEdition,
+        inscriptionV3: findInscriptionV3Key(pdaMint),
+        inscriptionData: findInscriptionDataKey(pdaMint),
+        inscriptionSummary,
+        inscriptionsProgram: INSCRIPTION_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID
+      })
+      .signers([payer])
+      .rpc({ skipPreflight: true });
+
+    const record = await program.account.solmapRecord.fetch(
+      findSolmapRecordKey(program.programId, solmapNum)
+    );
+    expect(record.mint.toString()).to.equal(pdaMint.toString());
+    expect(record.owner.toString()).to.equal(payer.publicKey.toString());
+
+    // Finalizing closes the draw, so the owner can draw again.
+    expect(await connection.getAccountInfo(reservation)).to.be.null;
+  });
+
+  it("tracks mints in the slot index page header", async () => {
+    const page = await program.account.slotIndex.fetch(slotIndex);
+
+    // Solmaps #0 to #14, #17 and a random one were minted or reserved above. #15 was
+    // auctioned but its winner hasn't claimed it yet.
+    expect(page.version).to.equal(2);
+    expect(page.page).to.equal(0);
+    expect(page.minted).to.equal(17);
+    expect(page.capacity).to.equal(16384);
+    expect(page.lastMintedSlot.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("grows the slot index to fit a solmap number", async () => {
+    // The legacy index covers pages 0 to 4, so page 5 starts out empty. The first solmap
+    // tracked by page 5 is #81920.
+    const pageFive = findSlotIndexPageKey(program.programId, 5);
+    const grow = (solmapNum: number) =>
+      program.methods
+        .growIndex(new anchor.BN(solmapNum))
+        .accounts({
+          admin: payer.publicKey,
+          payer: payer.publicKey,
+          config,
+          slotIndex: pageFive,
+          legacySlotIndex,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc();
+
+    await grow(81920 + 100);
+
+    let page = await program.account.slotIndex.fetch(pageFive);
+    expect(page.page).to.equal(5);
+    expect(page.minted).to.equal(0);
+    expect(page.capacity).to.equal(104);
+
+    await grow(81920 + 1000);
+
+    page = await program.account.slotIndex.fetch(pageFive);
+    expect(page.capacity).to.equal(1008);
+  });
+
+  it("carries legacy mints over to a page created by grow_index", async () => {
+    // #16391 was minted through the legacy index, see the fixture in Anchor.toml.
+    const pageOne = findSlotIndexPageKey(program.programId, 1);
+    await program.methods
+      .growIndex(new anchor.BN(16391))
+      .accounts({
+        admin: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        slotIndex: pageOne,
+        legacySlotIndex,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    // The page is made large enough for the whole legacy slice it tracks.
+    const page = await program.account.slotIndex.fetch(pageOne);
+    expect(page.minted).to.equal(1);
+    expect(page.capacity).to.equal(16384);
+
+    // Its slot doesn't exist yet, so take it out of the public mint instead.
+    const start = new anchor.BN(16391);
+    try {
+      await program.methods
+        .holdSolmaps(start, 1, payer.publicKey)
+        .accounts({
+          admin: payer.publicKey,
+          payer: payer.publicKey,
+          config,
+          season,
+          slotIndex: pageOne,
+          reservation: findReservationKey(program.programId, start),
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6002);
+    }
+  });
+
+  it("backfills the record of a Solmap minted before records existed", async () => {
+    // The page tracking #16391 was created from the legacy index above.
+    await backfillRecord(16391, legacySolmapMints[16391]);
+
+    const record = await program.account.solmapRecord.fetch(
+      findSolmapRecordKey(program.programId, new anchor.BN(16391))
+    );
+    expect(record.solmapNumber.toNumber()).to.equal(16391);
+    expect(record.mint.toString()).to.equal(legacySolmapMints[16391].toString());
+    expect(record.owner.toString()).to.equal(payer.publicKey.toString());
+    expect(record.slot.toNumber()).to.equal(16_400_000);
+    expect(record.inscription.toString()).to.equal(
+      findInscriptionV3Key(legacySolmapMints[16391]).toString()
+    );
+  });
+
+  it("cannot backfill a Solmap the slot index doesn't mark as minted", async () => {
+    try {
+      await backfillRecord(16392, legacySolmapMints[16392]);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6011);
+    }
+  });
+
+  it("cannot backfill an NFT named after another Solmap", async () => {
+    try {
+      await backfillRecord(16392, legacySolmapMints[16391]);
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6003);
+    }
+  });
+
+  it("cannot mint a Solmap whose slot index page doesn't exist", async () => {
+    // Page 2 tracks #32768 on and was never created.
+    const solmapNum = new anchor.BN(32768);
+    try {
+      await program.methods
+        .reserveSolmap(solmapNum)
+        .accounts({
+          owner: payer.publicKey,
+          config,
+          season,
+          slotIndex: findSlotIndexPageKey(program.programId, 2),
+          feeVault: findFeeVaultKey(program.programId, solmapNum),
+          walletMints,
+          reservation: findReservationKey(program.programId, solmapNum),
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6010);
+    }
+  });
+
+  it("rotates the admin in two steps after the timelock", async () => {
+    const newAdmin = anchor.web3.Keypair.generate();
+
+    const updateTimelock = (seconds: number) =>
+      program.methods
+        .updateConfig({
+          treasury: null,
+          mcc: null,
+          adminTimelock: new anchor.BN(seconds),
+          reservationExpirySlots: null,
+          revealDelaySlots: null,
+          commitRevealOnly: null,
+          referralBps: null
+        })
+        .accounts({ admin: payer.publicKey, config })
+        .signers([payer])
+        .rpc();
+    const propose = (admin: Keypair, pending: PublicKey) =>
+      program.methods
+        .proposeAuthority(pending)
+        .accounts({ admin: admin.publicKey, config })
+        .signers([admin])
+        .rpc();
+    const accept = (pending: Keypair) =>
+      program.methods
+        .acceptAuthority()
+        .accounts({ newAdmin: pending.publicKey, config })
+        .signers([pending])
+        .rpc({ skipPreflight: true });
+
+    // Without a timelock a new one takes effect right away.
+    await updateTimelock(5);
+    let configAccount = await program.account.config.fetch(config);
+    expect(configAccount.adminTimelock.toNumber()).to.equal(5);
+    expect(configAccount.pendingAdminTimelock).to.be.null;
+
+    // The new admin has to wait out the timelock.
+    await propose(payer, newAdmin.publicKey);
+    const tryAccept = async () => {
+      try {
+        await accept(newAdmin);
+        assert.fail();
+      } catch (_err) {
+        assert.isTrue(_err instanceof ProgramError);
+        expect((_err as ProgramError).code).to.equal(6021);
+      }
+    };
+    await tryAccept();
+
+    // Lowering the timelock waits behind the current one too, so it can't be used to
+    // rotate the admin right away.
+    await updateTimelock(0);
+    configAccount = await program.account.config.fetch(config);
+    expect(configAccount.adminTimelock.toNumber()).to.equal(5);
+    expect(configAccount.pendingAdminTimelock.toNumber()).to.equal(0);
+
+    await propose(payer, newAdmin.publicKey);
+    await tryAccept();
+
+    const { pendingAdminEta } = await program.account.config.fetch(config);
+    while ((await chainTime()) < pendingAdminEta.toNumber()) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+    await accept(newAdmin);
+
+    configAccount = await program.account.config.fetch(config);
+    expect(configAccount.admin.toString()).to.equal(
+      newAdmin.publicKey.toString()
+    );
+    expect(configAccount.pendingAdmin).to.be.null;
+
+    // Hand the role back for the following tests, once the timelock is back to 0.
+    await propose(newAdmin, payer.publicKey);
+    await accept(payer);
+
+    configAccount = await program.account.config.fetch(config);
+    expect(configAccount.admin.toString()).to.equal(payer.publicKey.toString());
+  });
+
+  it("rotates the collection update authority away from the FVCA", async () => {
+    await program.methods
+      .setCollectionUpdateAuthority(new PublicKey(umi.payer.publicKey))
+      .accounts({
+        admin: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        mcc: mcc.publicKey,
+        collectionMetadata: mccMetadata,
+        collectionMasterEdition: mccMasterEdition,
+        fvca,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        sysvarInstructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID
+      })
+      .signers([payer])
+      .rpc();
+
+    const mccNft = await fetchMetadata(umi, publicKey(mccMetadata));
+    expect(mccNft.updateAuthority.toString()).to.equal(
+      umi.payer.publicKey.toString()
+    );
+
+    // Hand the collection back to the FVCA for the following tests.
+    await updateV1(umi, {
+      authority: umi.payer,
+      delegateRecord: null,
+      token: null,
+      mint: mccKeypair.publicKey,
+      metadata: publicKey(mccMetadata),
+      edition: publicKey(mccMasterEdition),
+      payer: umi.payer,
+      systemProgram: publicKey(anchor.web3.SystemProgram.programId),
+      sysvarInstructions: publicKey(anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY),
+      authorizationRulesProgram: null,
+      authorizationRules: null,
+      newUpdateAuthority: publicKey(fvca)
+    }).sendAndConfirm(umi);
+  });
+
+  it("runs admin instructions through an M-of-N governance", async () => {
+    const member = anchor.web3.Keypair.generate();
+    const governance = PublicKey.findProgramAddressSync(
+      [Buffer.from("governance")],
+      program.programId
+    )[0];
+
+    await program.methods
+      .createGovernance({
+        members: [payer.publicKey, member.publicKey],
+        threshold: 2
+      })
+      .accounts({
+        admin: payer.publicKey,
+        payer: payer.publicKey,
+        config,
+        governance,
+        systemProgram: anchor.web3.SystemProgram.programId
+      })
+      .signers([payer])
+      .rpc();
+
+    // The governance is only proposed as admin, behind the timelock.
+    let configAccount = await program.account.config.fetch(config);
+    expect(configAccount.admin.toString()).to.equal(payer.publicKey.toString());
+    expect(configAccount.pendingAdmin.toString()).to.equal(governance.toString());
+
+    // Governance signs inside the program, never in the transaction.
+    const propose = async (id: number, ix: TransactionInstruction) => {
+      const proposal = findProposalKey(program.programId, id);
+      await program.methods
+        .createProposal({
+          accounts: ix.keys.map((key) => ({
+            pubkey: key.pubkey,
+            isSigner: key.isSigner,
+            isWritable: key.isWritable
+          })),
+          data: ix.data
+        })
+        .accounts({
+          proposer: payer.publicKey,
+          governance,
+          proposal,
+          systemProgram: anchor.web3.SystemProgram.programId
+        })
+        .signers([payer])
+        .rpc();
+
+      const execute = () =>
+        program.methods
+          .executeProposal()
+          .accounts({
+            executor: payer.publicKey,
+            governance,
+            proposal,
+            solmapProgram: program.programId
+          })
+          .remainingAccounts(
+            ix.keys.map((key) => ({ ...key, isSigner: false }))
+          )
+          .signers([payer])
+          .rpc({ skipPreflight: true });
+      const approve = () =>
+        program.methods
+          .approveProposal()
+          .accounts({
+            member: member.publicKey,
+            governance,
+            proposal
+          })
+          .signers([member])
+          .rpc();
+
+      return { proposal, execute, approve };
+    };
+
+    // Members accept the role through a proposal.
+    const acceptIx = await program.methods
+      .acceptAuthority()
+      .accounts({ newAdmin: governance, config })
+      .instruction();
+    const accept = await propose(0, acceptIx);
+    await accept.approve();
+    await accept.execute();
+
+    configAccount = await program.account.config.fetch(config);
+    expect(configAccount.admin.toString()).to.equal(governance.toString());
+
+    // The old admin key can no longer act alone.
+    try {
+      await program.methods
+        .setPublicMint(false)
+        .accounts({ admin: payer.publicKey, config })
+        .signers([payer])
+        .rpc({ skipPreflight: true });
+
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6005);
+    }
+
+    const ix = await program.methods
+      .setPublicMint(false)
+      .accounts({ admin: governance, config })
+      .instruction();
+    const { proposal, execute, approve } = await propose(1, ix);
+
+    // Only the proposer approved so far.
+    try {
+      await execute();
+      assert.fail();
+    } catch (_err) {
+      assert.isTrue(_err instanceof ProgramError);
+      expect((_err as ProgramError).code).to.equal(6018);
+    }
+
+    await approve();
+    await execute();
+
+    configAccount = await program.account.config.fetch(config);
+    expect(configAccount.publicMintEnabled).to.equal(false);
+
+    const proposalAccount = await program.account.proposal.fetch(proposal);
+    expect(proposalAccount.executed).to.equal(true);
+  });
+});
+
+function findProposalKey(programId: PublicKey, id: number): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("proposal"), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+}
+
+function findSlotIndexPageKey(programId: PublicKey, page: number): PublicKey {
+  const pageBytes = Buffer.alloc(4);
+  pageBytes.writeUInt32LE(page);
+
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("slot_index"), pageBytes],
+    programId
+  )[0];
+}
+
+function findFeeVaultKey(programId: PublicKey, solmapNum: anchor.BN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_vault"), Buffer.from([solmapNum.modn(FEE_VAULT_SHARDS)])],
+    programId
+  )[0];
+}
+
+function findSolmapRecordKey(
+  programId: PublicKey,
+  solmapNum: anchor.BN
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("solmap"), solmapNum.toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+}
+
+function findReservationKey(
+  programId: PublicKey,
+  start: anchor.BN
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reservation"), start.toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+}
+
+function findAuctionKey(programId: PublicKey, solmapNum: anchor.BN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("auction"), solmapNum.toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+}
+
+function findMetadataKey(mint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("metadata"),
+      TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      mint.toBuffer()
+    ],
+    TOKEN_METADATA_PROGRAM_ID
+  )[0];
+}
+
+function findSolmapMintKey(
+  programId: PublicKey,
+  solmapNum: anchor.BN
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("solmap_mint"), solmapNum.toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+}
 
 function findInscriptionSummaryKey(): PublicKey {
   return PublicKey.findProgramAddressSync(